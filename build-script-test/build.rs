@@ -0,0 +1,14 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    let spec: g2p_gen::FieldSpec = "GF256, 8, modulus: 0x11d".parse().expect("Valid field arguments");
+    let out = Path::new(&env::var_os("OUT_DIR").expect("Cargo sets OUT_DIR")).join("gf256.rs");
+    fs::write(out, g2p_gen::generate(spec)).expect("OUT_DIR is writable");
+    println!("cargo:rerun-if-changed=build.rs");
+}
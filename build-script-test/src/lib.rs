@@ -0,0 +1,17 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A field generated by the build script with `g2p_gen::generate`, without the macro
+
+#![deny(warnings, missing_docs, unused, nonstandard_style, rust_2018_idioms)]
+
+// Clippy checks included code like handwritten code, unlike the output of the macro
+#[allow(clippy::all)]
+mod gf256 {
+    include!(concat!(env!("OUT_DIR"), "/gf256.rs"));
+}
+
+pub use gf256::{NonZeroGF256, GF256};
@@ -0,0 +1,52 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{GaloisField, G2Poly};
+use g2p_build_script_test::GF256;
+
+g2p::g2p!(MacroGF256, 8, modulus: 0x11d);
+
+#[test]
+fn test_matches_macro() {
+    assert_eq!(GF256::MODULUS, G2Poly(0x11d));
+    assert_eq!(GF256::GENERATOR.0, MacroGF256::GENERATOR.0);
+    for a in 0..=255 {
+        assert_eq!(GF256(a).trace(), MacroGF256(a).trace());
+        for b in 0..=255 {
+            assert_eq!((GF256(a) + GF256(b)).0, (MacroGF256(a) + MacroGF256(b)).0);
+            assert_eq!((GF256(a) * GF256(b)).0, (MacroGF256(a) * MacroGF256(b)).0);
+            if b != 0 {
+                assert_eq!((GF256(a) / GF256(b)).0, (MacroGF256(a) / MacroGF256(b)).0);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_field_axioms() {
+    let elements: Vec<GF256> = (0..=255).map(GF256).collect();
+    for &a in &elements {
+        assert_eq!(a + GF256::ZERO, a);
+        assert_eq!(a * GF256::ONE, a);
+        assert_eq!(a + a, GF256::ZERO);
+        if a != GF256::ZERO {
+            assert_eq!(a * a.inverse(), GF256::ONE);
+        }
+        for &b in elements.iter().step_by(7) {
+            assert_eq!(a * b, b * a);
+            for &c in elements.iter().step_by(31) {
+                assert_eq!(a * (b + c), a * b + a * c);
+                assert_eq!((a * b) * c, a * (b * c));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_formatting_and_parsing() {
+    assert_eq!(format!("{:?}", GF256(5)), "5_GF256");
+    assert_eq!("5".parse(), Ok(GF256(5)));
+}
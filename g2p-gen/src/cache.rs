@@ -0,0 +1,235 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Optional on-disk cache of the tables computed by the macro
+//!
+//! The inverse table takes one extended gcd per element, which adds up for large fields and is
+//! repeated by every `cargo check`. If the environment variable `G2P_TABLE_CACHE` names a
+//! directory, the tables are stored there and read back on the next expansion. Any problem with
+//! the cache, like a missing directory or a file from another version, falls back to computing
+//! the table, so the cache never changes the generated code.
+//!
+//! A table is stored under the full set of parameters it is generated for: the degree, the
+//! modulus, the generator and the backend. The inverse table only depends on the modulus today,
+//! but keying on all of them means tables that do depend on the generator, like exp/log tables,
+//! can be added without changing the format again.
+
+use std::{
+    convert::TryInto,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use g2poly::G2Poly;
+
+/// Environment variable naming the cache directory
+const ENV_VAR: &str = "G2P_TABLE_CACHE";
+
+/// Start of every cache file
+const MAGIC: &[u8; 4] = b"G2PT";
+
+/// Version of the file format, part of the file name and the header
+const VERSION: u32 = 2;
+
+/// Size of the header: magic, version, degree, modulus, generator, backend and number of entries
+const HEADER_LEN: usize = 4 + 4 + 8 + 8 + 8 + 8 + 8;
+
+/// The parameters a cached table is generated for
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TableKey {
+    /// Name of the table, like `inv`
+    pub kind: &'static str,
+    pub modulus: G2Poly,
+    pub generator: G2Poly,
+    /// Name of the backend using the table, at most 8 bytes
+    pub backend: &'static str,
+}
+
+impl TableKey {
+    /// The backend name zero-padded to 8 bytes, as stored in the header
+    fn backend_bytes(&self) -> [u8; 8] {
+        let mut bytes = [0; 8];
+        bytes[..self.backend.len()].copy_from_slice(self.backend.as_bytes());
+        bytes
+    }
+}
+
+/// The cache directory, if enabled
+pub(crate) fn cache_dir() -> Option<PathBuf> {
+    env::var_os(ENV_VAR).filter(|v| !v.is_empty()).map(PathBuf::from)
+}
+
+/// Read the table for `key` from `dir`, or compute and store it
+///
+/// The entries must fit into the field, so they are stored as `u32`.
+pub(crate) fn cached_table(dir: Option<&Path>, key: &TableKey, compute: impl FnOnce() -> Vec<u64>) -> Vec<u64> {
+    let dir = match dir {
+        Some(dir) => dir,
+        None => return compute(),
+    };
+    let path = dir.join(file_name(key));
+    if let Some(table) = fs::read(&path).ok().and_then(|bytes| decode(&bytes, key)) {
+        return table;
+    }
+    let table = compute();
+    // Writing is best effort, the next expansion just computes the table again
+    let _ = store(&path, &encode(&table, key));
+    table
+}
+
+fn file_name(key: &TableKey) -> String {
+    let p = key.modulus.degree().unwrap_or(0);
+    format!("{}-v{}-p{}-{:x}-g{:x}-{}.bin", key.kind, VERSION, p, key.modulus.0, key.generator.0, key.backend)
+}
+
+fn encode(table: &[u64], key: &TableKey) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(HEADER_LEN + 4 * table.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&VERSION.to_le_bytes());
+    bytes.extend_from_slice(&key.modulus.degree().unwrap_or(0).to_le_bytes());
+    bytes.extend_from_slice(&key.modulus.0.to_le_bytes());
+    bytes.extend_from_slice(&key.generator.0.to_le_bytes());
+    bytes.extend_from_slice(&key.backend_bytes());
+    bytes.extend_from_slice(&(table.len() as u64).to_le_bytes());
+    for &v in table {
+        bytes.extend_from_slice(&(v as u32).to_le_bytes());
+    }
+    bytes
+}
+
+/// Decode a cache file, checking that it belongs to these parameters and has only valid entries
+fn decode(bytes: &[u8], key: &TableKey) -> Option<Vec<u64>> {
+    let p = key.modulus.degree()?;
+    let size = 1_u64 << p;
+    let (header, entries) = (bytes.get(..HEADER_LEN)?, bytes.get(HEADER_LEN..)?);
+    let u64_at = |i: usize| u64::from_le_bytes(header[i..i + 8].try_into().expect("8 bytes"));
+    if &header[..4] != MAGIC
+        || u32::from_le_bytes(header[4..8].try_into().expect("4 bytes")) != VERSION
+        || u64_at(8) != p
+        || u64_at(16) != key.modulus.0
+        || u64_at(24) != key.generator.0
+        || header[32..40] != key.backend_bytes()
+        || u64_at(40) != size
+        || entries.len() as u64 != 4 * size
+    {
+        return None;
+    }
+    entries
+        .chunks_exact(4)
+        .map(|c| u64::from(u32::from_le_bytes(c.try_into().expect("4 bytes"))))
+        .map(|v| if v < size { Some(v) } else { None })
+        .collect()
+}
+
+/// Write the file under a temporary name first, so concurrent builds never read half a file
+fn store(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+    fs::write(&tmp, bytes)?;
+    fs::rename(&tmp, path).map_err(|e| {
+        let _ = fs::remove_file(&tmp);
+        e
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh directory for one test, removed when dropped
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = env::temp_dir().join(format!("g2p-gen-cache-{}-{}", std::process::id(), name));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    const KEY: TableKey = TableKey { kind: "inv", modulus: G2Poly(0b1_0011), generator: G2Poly(0b10), backend: "full" };
+
+    fn table() -> Vec<u64> {
+        (0..16).rev().collect()
+    }
+
+    #[test]
+    fn test_miss_then_hit() {
+        let dir = TempDir::new("hit");
+        assert_eq!(cached_table(Some(&dir.0), &KEY, table), table());
+        assert!(dir.0.join(file_name(&KEY)).is_file());
+        let hit = cached_table(Some(&dir.0), &KEY, || panic!("The table should be cached"));
+        assert_eq!(hit, table());
+    }
+
+    #[test]
+    fn test_disabled() {
+        let mut calls = 0;
+        for _ in 0..2 {
+            cached_table(None, &KEY, || {
+                calls += 1;
+                table()
+            });
+        }
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_parameters_change() {
+        let dir = TempDir::new("params");
+        cached_table(Some(&dir.0), &KEY, table);
+        // Another modulus of the same degree, generator, backend or kind of table miss the cache
+        let changed = [
+            TableKey { modulus: G2Poly(0b1_1001), ..KEY },
+            TableKey { generator: G2Poly(0b11), ..KEY },
+            TableKey { backend: "none", ..KEY },
+            TableKey { kind: "other", ..KEY },
+        ];
+        for (i, key) in changed.iter().enumerate() {
+            let marker = vec![i as u64; 16];
+            assert_eq!(cached_table(Some(&dir.0), key, || marker.clone()), marker);
+        }
+        assert_eq!(cached_table(Some(&dir.0), &KEY, || unreachable!()), table());
+
+        // A file that was renamed or belongs to other parameters is not used
+        for key in &changed[..3] {
+            fs::copy(dir.0.join(file_name(key)), dir.0.join(file_name(&KEY))).unwrap();
+            assert_eq!(cached_table(Some(&dir.0), &KEY, table), table());
+        }
+        assert_eq!(fs::read(dir.0.join(file_name(&KEY))).unwrap(), encode(&table(), &KEY));
+    }
+
+    #[test]
+    fn test_invalid_files_are_recomputed() {
+        let dir = TempDir::new("invalid");
+        let path = dir.0.join(file_name(&KEY));
+        let valid = encode(&table(), &KEY);
+
+        let mut old_version = valid.clone();
+        old_version[4] = 0;
+        let mut out_of_range = valid.clone();
+        *out_of_range.last_mut().unwrap() = 0xff;
+        for contents in [&valid[..valid.len() - 1], &valid[..10], &[][..], &old_version, &out_of_range] {
+            fs::write(&path, contents).unwrap();
+            assert_eq!(cached_table(Some(&dir.0), &KEY, table), table());
+            assert_eq!(fs::read(&path).unwrap(), valid);
+        }
+    }
+
+    #[test]
+    fn test_missing_directory() {
+        let dir = TempDir::new("missing");
+        let missing = dir.0.join("does-not-exist");
+        assert_eq!(cached_table(Some(&missing), &KEY, table), table());
+        assert!(!missing.exists());
+    }
+}
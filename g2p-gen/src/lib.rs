@@ -0,0 +1,3262 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Code generation for finite field types
+//!
+//! This crate implements the `g2p!` macro of [g2p](https://docs.rs/g2p), whose documentation
+//! describes the arguments and the generated code. The procedural macro in `g2gen` is a thin
+//! wrapper around [`expand`]. Build scripts can use [`generate`] instead, which renders the same
+//! code as a string, e.g. for builds that don't allow procedural macros:
+//!
+//! ```no_run
+//! // build.rs
+//! let spec: g2p_gen::FieldSpec = "GF256, 8, modulus: 0x11d".parse().expect("Valid arguments");
+//! let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("gf256.rs");
+//! std::fs::write(out, g2p_gen::generate(spec)).unwrap();
+//! ```
+//!
+//! The crate, which has to depend on g2p, then includes the file. Clippy checks included code
+//! like handwritten code, so it is best put into a module that allows its lints:
+//!
+//! ```ignore
+//! #[allow(clippy::all)]
+//! mod gf256 {
+//!     include!(concat!(env!("OUT_DIR"), "/gf256.rs"));
+//! }
+//! pub use gf256::GF256;
+//! ```
+//!
+//! The optional trait implementations selected by default follow the features of this crate,
+//! which g2p enables on `g2gen` for the macro. A build dependency on this crate should enable
+//! the same features as the dependency on g2p, or select the implementations with `impls`.
+
+#![recursion_limit = "128"]
+
+mod cache;
+
+use std::str::FromStr;
+
+use proc_macro2::{TokenStream as P2TokenStream, Ident, Span};
+
+use g2poly::{G2Poly, extended_gcd};
+use quote::quote;
+use syn::{
+    parse::{
+        Parse,
+        ParseStream,
+    },
+    spanned::Spanned,
+    Token,
+};
+
+/// The arguments of a `g2p!` invocation, for [`generate`]
+///
+/// It is parsed from the same syntax as the arguments of the macro, e.g.
+/// `"GF256, 8, modulus: 0x11d"`.
+pub struct FieldSpec {
+    input: ParsedInput,
+}
+
+impl FromStr for FieldSpec {
+    type Err = syn::Error;
+
+    fn from_str(s: &str) -> syn::Result<Self> {
+        syn::parse_str(s).map(|input| FieldSpec { input })
+    }
+}
+
+/// Render the items of the field as Rust source, to be written to a file and included
+///
+/// Invalid arguments that are only detected during generation, like a reducible modulus, are
+/// rendered as a `compile_error!`, just like the macro does.
+pub fn generate(field: FieldSpec) -> String {
+    expand_parsed(field.input).to_string()
+}
+
+/// Expand the arguments of `g2p!` into the items of the field
+///
+/// This is the implementation of the procedural macro. Invalid arguments expand to a
+/// `compile_error!`.
+pub fn expand(input: P2TokenStream) -> P2TokenStream {
+    match syn::parse2(input) {
+        Ok(args) => expand_parsed(args),
+        Err(e) => e.to_compile_error(),
+    }
+}
+
+fn expand_parsed(args: ParsedInput) -> P2TokenStream {
+    let input_module = args.module.clone();
+    let contains = args.contains.clone();
+    let allow_lints = if args.allow_lints.is_empty() {
+        quote![]
+    } else {
+        let lints = &args.allow_lints;
+        quote![#[allow(#(#lints),*)]]
+    };
+    let settings = match Settings::from_input(args) {
+        Ok(settings) => settings,
+        Err(e) => return e.to_compile_error(),
+    };
+    let ident = settings.ident;
+    let ident_name = settings.ident_name;
+    let modulus = settings.modulus;
+    let generator = settings.generator;
+    let p = settings.p_val;
+    let field_size = 1_usize << p;
+    let mask = (1_u64 << p).wrapping_sub(1);
+
+    let ty = base_type(p);
+
+    let mod_name = Ident::new(&format!("{}_mod", ident_name), Span::call_site());
+
+    let struct_doc = struct_doc(&ident_name, p, modulus, generator, &ty.to_string());
+    let hash = if settings.impls.hash {
+        quote![#[derive(::core::hash::Hash)]]
+    } else {
+        quote![]
+    };
+    let struct_def = quote![
+        #[doc = #struct_doc]
+        #allow_lints
+        #[derive(::core::clone::Clone, ::core::marker::Copy, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::cmp::PartialOrd, ::core::cmp::Ord)]
+        #hash
+        #[repr(transparent)]
+        pub struct #ident(pub #ty);
+    ];
+
+    let gen = generator.0;
+    let modulus_val = modulus.0;
+    let modulus_str = modulus.to_string();
+    let mask_doc = format!("Mask of the {} bits used by the elements of the field", p);
+    let modulus_doc = format!("The modulus `{}` used to construct the field", modulus_str);
+    let modulus_str_doc = format!("The modulus `{}` in polynomial notation, e.g. for logging", modulus_str);
+    let generator_doc = format!("The generator `{}` of the multiplicative group", generator);
+    let bits = p as u32;
+    let bits_usize = p as usize;
+    let bytes = ((p + 7) / 8) as usize;
+    let struct_impl = quote![
+        impl #ident {
+            #[doc = #mask_doc]
+            pub const MASK: #ty = #mask as #ty;
+            #[doc = #modulus_doc]
+            pub const MODULUS: ::g2p::G2Poly = ::g2p::G2Poly(#modulus_val);
+            #[doc = #modulus_str_doc]
+            pub const MODULUS_POLY_STR: &'static str = #modulus_str;
+            #[doc = #generator_doc]
+            pub const GENERATOR: Self = Self(#gen as #ty);
+
+            /// The characteristic of the field
+            pub const CHAR: u32 = 2;
+            /// The number of bits p of an element
+            pub const BITS: u32 = #bits;
+            /// The number of bytes needed to store an element
+            pub const BYTES: usize = #bytes;
+
+            /// The value of the element as the base type
+            pub const fn value(self) -> #ty {
+                self.0
+            }
+
+            /// Parse the value of an element in the given radix, like `u32::from_str_radix`
+            ///
+            /// A `0x` prefix is accepted for radix 16 and a `0b` prefix for radix 2. Values above
+            /// `MASK` are rejected instead of being masked.
+            ///
+            /// # Panics
+            /// Panics if `radix` is not in the range 2 to 36.
+            pub fn from_str_radix(s: &str, radix: u32) -> ::core::result::Result<Self, ::g2p::FieldError> {
+                ::g2p::__private::parse_radix(s, radix, #mask).map(|v| #ident(v as #ty))
+            }
+
+            /// The coefficient of `x^i` when viewing the element as a polynomial over GF(2)
+            ///
+            /// Returns `false` for `i >= p` instead of panicking, as all higher coefficients are
+            /// zero.
+            pub const fn coefficient(self, i: u32) -> bool {
+                i < Self::BITS && (self.0 >> i) & 1 != 0
+            }
+
+            /// Iterate over the exponents with a set coefficient in increasing order
+            pub fn support(self) -> impl ::core::iter::Iterator<Item = u32> {
+                (0..Self::BITS).filter(move |&i| self.coefficient(i))
+            }
+
+            /// Iterate over all p coefficients, starting with the constant term
+            pub fn iter_bits(self) -> impl ::core::iter::DoubleEndedIterator<Item = bool> + ::core::iter::ExactSizeIterator {
+                (0..Self::BITS).map(move |i| self.coefficient(i))
+            }
+
+            /// Build an element from its coefficients over GF(2), where the index is the exponent
+            ///
+            /// Returns `None` if a coefficient at an index of p or above is set. Unset coefficients
+            /// beyond p are accepted.
+            pub fn from_coefficients(bits: &[bool]) -> ::core::option::Option<Self> {
+                let mut value = 0;
+                for (i, &bit) in bits.iter().enumerate() {
+                    if bit {
+                        if i >= Self::BITS as usize {
+                            return ::core::option::Option::None;
+                        }
+                        value |= 1 << i;
+                    }
+                }
+                ::core::option::Option::Some(Self(value))
+            }
+
+            /// Build an element from exactly p coefficients over GF(2)
+            pub const fn from_coefficient_array(bits: [bool; #bits_usize]) -> Self {
+                let mut value = 0;
+                let mut i = 0;
+                while i < #bits_usize {
+                    if bits[i] {
+                        value |= 1 << i;
+                    }
+                    i += 1;
+                }
+                Self(value)
+            }
+
+            /// The value of the element as `usize`, e.g. to index a table
+            pub const fn as_usize(self) -> usize {
+                (self.0 & Self::MASK) as usize
+            }
+
+            /// The position of the highest set bit, i.e. the degree plus one, or 0 for zero
+            pub const fn bits(self) -> u32 {
+                (8 * ::core::mem::size_of::<#ty>()) as u32 - (self.0 & Self::MASK).leading_zeros()
+            }
+
+            /// The number of set bits, i.e. the number of nonzero coefficients
+            pub const fn hamming_weight(self) -> u32 {
+                (self.0 & Self::MASK).count_ones()
+            }
+
+            /// The degree of the element as a polynomial, or `None` for zero
+            pub const fn leading_coefficient_degree(self) -> ::core::option::Option<u32> {
+                match self.bits() {
+                    0 => ::core::option::Option::None,
+                    bits => ::core::option::Option::Some(bits - 1),
+                }
+            }
+
+            /// Divide by `rhs`, returning `None` if `rhs` is zero
+            pub fn checked_div(self, rhs: Self) -> ::core::option::Option<Self> {
+                self.try_div(rhs).ok()
+            }
+
+            /// Divide by `rhs`, returning an error if `rhs` is zero
+            pub fn try_div(self, rhs: Self) -> ::core::result::Result<Self, ::g2p::FieldError> {
+                if rhs.0 & Self::MASK == 0 {
+                    ::core::result::Result::Err(::g2p::FieldError::DivisionByZero)
+                } else {
+                    ::core::result::Result::Ok(self / rhs)
+                }
+            }
+        }
+    ];
+
+    let frobenius = generate_frobenius_impl(&ident, p);
+    let trace = generate_trace_impl(&ident, &ty, modulus, p);
+    let normal_basis = if settings.impls.normal_basis {
+        generate_normal_basis_impl(&ident, &ty, modulus, p)
+    } else {
+        quote![]
+    };
+    let dual_basis = if settings.impls.dual_basis {
+        generate_dual_basis_impl(&ident, &ty, modulus, p)
+    } else {
+        quote![]
+    };
+    let bit_matrix = generate_bit_matrix_impl(&ident, &ty, modulus, p);
+    let xtime = generate_xtime_impl(&ident, modulus, p);
+    let index = generate_index_impl(&ident, field_size);
+    let nonzero_ident = Ident::new(&format!("NonZero{}", ident_name), Span::call_site());
+    let (nonzero_def, nonzero_impl) = generate_nonzero(&ident, &nonzero_ident, &ident_name, settings.impls.hash);
+    let byte_conversions = generate_byte_conversions(&ident, &ty, p);
+
+    let inline = settings.inline.attribute();
+
+    // The widening conversions of the standard library, which can't be chained through `#ty`
+    let wider: &[&str] = match p {
+        0..=8 => &["u16", "u32", "u64", "u128", "usize"],
+        9..=16 => &["u32", "u64", "u128", "usize"],
+        _ => &["u64", "u128"],
+    };
+    let wider = wider.iter().map(|name| Ident::new(name, Span::call_site()));
+    let from = quote![
+        impl ::core::convert::From<#ident> for #ty {
+            #inline
+            fn from(v: #ident) -> #ty {
+                v.0 & #ident::MASK
+            }
+        }
+        #(
+            impl ::core::convert::From<#ident> for #wider {
+                #inline
+                fn from(v: #ident) -> #wider {
+                    ::core::convert::From::from(v.0 & #ident::MASK)
+                }
+            }
+        )*
+        impl ::core::convert::AsRef<#ty> for #ident {
+            fn as_ref(&self) -> &#ty {
+                &self.0
+            }
+        }
+        // The derived `Hash` and `PartialEq` only look at the stored value, so they agree with
+        // those of the base type as `Borrow` requires
+        impl ::core::borrow::Borrow<#ty> for #ident {
+            fn borrow(&self) -> &#ty {
+                &self.0
+            }
+        }
+    ];
+
+    // Bytes always fit into fields with more than 8 bits, so they need no masking
+    let from_u8 = if p > 8 {
+        quote![
+            impl ::core::convert::From<u8> for #ident {
+                fn from(v: u8) -> #ident {
+                    #ident(::core::convert::From::from(v))
+                }
+            }
+        ]
+    } else {
+        quote![]
+    };
+    let into = quote![
+        impl ::core::convert::From<#ty> for #ident {
+            #inline
+            fn from(v: #ty) -> #ident {
+                #ident(v & #ident::MASK)
+            }
+        }
+        #from_u8
+        impl ::core::convert::TryFrom<usize> for #ident {
+            type Error = ::g2p::FieldError;
+
+            fn try_from(v: usize) -> ::core::result::Result<Self, Self::Error> {
+                if v as u64 > #mask {
+                    ::core::result::Result::Err(::g2p::FieldError::OutOfRange { value: v as u64, mask: #mask })
+                } else {
+                    ::core::result::Result::Ok(#ident(v as #ty))
+                }
+            }
+        }
+        impl ::core::convert::From<bool> for #ident {
+            fn from(v: bool) -> #ident {
+                #ident(::core::convert::From::from(v))
+            }
+        }
+    ];
+
+    // Compare the stored value as is: an integer above MASK is not masked, so it never equals an
+    // element, just like `GF16(19) != GF16(3)` for the derived `PartialEq`.
+    let eq_base = quote![
+        impl ::core::cmp::PartialEq<#ty> for #ident {
+            #inline
+            fn eq(&self, other: &#ty) -> bool {
+                self.0 == *other
+            }
+        }
+        impl ::core::cmp::PartialEq<#ident> for #ty {
+            #inline
+            fn eq(&self, other: &#ident) -> bool {
+                *self == other.0
+            }
+        }
+    ];
+
+    // The alternate form is zero-padded hex with one digit per 4 bits of the field, so the
+    // columns of dumped tables line up. Otherwise the value is formatted by the base type, which
+    // honors `{:x?}`, followed by the type name.
+    let suffix = format!("_{}", ident_name);
+    let hex_width = 2 + ((p + 3) / 4) as usize;
+    let debug = quote![
+        impl ::core::fmt::Debug for #ident {
+            fn fmt<'a>(&self, f: &mut ::core::fmt::Formatter<'a>) -> ::core::fmt::Result {
+                if f.alternate() {
+                    ::core::write!(f, "{:#0width$x}", self.0, width = #hex_width)
+                } else {
+                    ::core::fmt::Debug::fmt(&self.0, f)?;
+                    f.write_str(#suffix)
+                }
+            }
+        }
+        impl ::core::fmt::LowerHex for #ident {
+            fn fmt<'a>(&self, f: &mut ::core::fmt::Formatter<'a>) -> ::core::fmt::Result {
+                ::core::fmt::LowerHex::fmt(&self.0, f)
+            }
+        }
+        impl ::core::fmt::UpperHex for #ident {
+            fn fmt<'a>(&self, f: &mut ::core::fmt::Formatter<'a>) -> ::core::fmt::Result {
+                ::core::fmt::UpperHex::fmt(&self.0, f)
+            }
+        }
+    ];
+    let from_str = quote![
+        impl ::core::str::FromStr for #ident {
+            type Err = ::g2p::FieldError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                ::g2p::__private::parse_element(s, #ident_name, #mask).map(|v| #ident(v as #ty))
+            }
+        }
+    ];
+    let display_body = match settings.display_fmt {
+        (display_fmt, true) => quote![::core::write!(f, #display_fmt, self.0)],
+        (display_fmt, false) => quote![::core::write!(f, #display_fmt)],
+    };
+    let display = quote![
+        impl ::core::fmt::Display for #ident {
+            fn fmt<'a>(&self, f: &mut ::core::fmt::Formatter<'a>) -> ::core::fmt::Result {
+                if f.alternate() {
+                    return ::core::write!(f, "{}", ::g2p::G2Poly(u64::from(self.0)));
+                }
+                #display_body
+            }
+        }
+    ];
+    let add = quote![
+        impl ::core::ops::Add for #ident {
+            type Output = Self;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #inline
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 ^ rhs.0)
+            }
+        }
+        impl ::core::ops::AddAssign for #ident {
+            #inline
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+    ];
+    let sum = quote![
+        impl ::core::iter::Sum for #ident {
+            fn sum<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(<Self as ::g2p::GaloisField>::ZERO, ::core::ops::Add::add)
+            }
+        }
+        impl<'a> ::core::iter::Sum<&'a #ident> for #ident {
+            fn sum<I: ::core::iter::Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+    ];
+    let sub = quote![
+        impl ::core::ops::Sub for #ident {
+            type Output = Self;
+
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #inline
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 ^ rhs.0)
+            }
+        }
+        impl ::core::ops::SubAssign for #ident {
+            #inline
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+        impl ::core::ops::Neg for #ident {
+            type Output = Self;
+
+            #inline
+            fn neg(self) -> Self::Output {
+                self
+            }
+        }
+    ];
+    // Byte fields scale slices with the nibble table kernel of `g2p::simd`
+    let scale_slice = if p <= 8 {
+        quote![
+            fn scale_slice(c: Self, data: &mut [Self]) {
+                ::g2p::__private::scale_slice_bytes(c, data)
+            }
+        ]
+    } else {
+        quote![]
+    };
+    let galois_trait_impl = quote![
+        impl ::g2p::GaloisField for #ident {
+            const SIZE: usize = #field_size;
+            const MODULUS: ::g2p::G2Poly = #ident::MODULUS;
+            const ZERO: Self = Self(0);
+            const ONE: Self = Self(1);
+            const GENERATOR: Self = #ident::GENERATOR;
+            const BITS: u32 = #ident::BITS;
+            const BYTES: usize = #ident::BYTES;
+
+            #inline
+            fn bits(self) -> u32 {
+                #ident::bits(self)
+            }
+
+            #inline
+            fn as_usize(self) -> usize {
+                #ident::as_usize(self)
+            }
+
+            #inline
+            fn hamming_weight(self) -> u32 {
+                #ident::hamming_weight(self)
+            }
+
+            #inline
+            fn leading_coefficient_degree(self) -> ::core::option::Option<u32> {
+                #ident::leading_coefficient_degree(self)
+            }
+
+            fn pow2k(self, k: u32) -> Self {
+                #ident::pow2k(self, k)
+            }
+
+            fn trace(self) -> bool {
+                #ident::trace(self)
+            }
+
+            fn solve_artin_schreier(a: Self) -> ::core::option::Option<(Self, Self)> {
+                #ident::solve_artin_schreier(a)
+            }
+
+            #scale_slice
+        }
+    ];
+
+    let (tables, mul, div) = match settings.backend {
+        Backend::Tables => generate_mul_impl(
+            ident.clone(),
+            &inline,
+            &ident_name,
+            modulus,
+            settings.generator,
+            ty.clone(),
+            field_size,
+            mask,
+        ),
+        Backend::TableFree => generate_table_free_mul_impl(
+            ident.clone(),
+            &inline,
+            &ident_name,
+            modulus,
+            ty.clone(),
+            mask,
+        ),
+    };
+    let product = quote![
+        impl ::core::iter::Product for #ident {
+            fn product<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(<Self as ::g2p::GaloisField>::ONE, ::core::ops::Mul::mul)
+            }
+        }
+        impl<'a> ::core::iter::Product<&'a #ident> for #ident {
+            fn product<I: ::core::iter::Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().product()
+            }
+        }
+    ];
+
+    let ref_assign = quote![
+        impl<'a> ::core::ops::AddAssign<&'a #ident> for #ident {
+            fn add_assign(&mut self, rhs: &'a Self) {
+                *self += *rhs;
+            }
+        }
+        impl<'a> ::core::ops::SubAssign<&'a #ident> for #ident {
+            fn sub_assign(&mut self, rhs: &'a Self) {
+                *self -= *rhs;
+            }
+        }
+        impl<'a> ::core::ops::MulAssign<&'a #ident> for #ident {
+            fn mul_assign(&mut self, rhs: &'a Self) {
+                *self *= *rhs;
+            }
+        }
+        impl<'a> ::core::ops::DivAssign<&'a #ident> for #ident {
+            #[track_caller]
+            fn div_assign(&mut self, rhs: &'a Self) {
+                *self /= *rhs;
+            }
+        }
+    ];
+
+    let optional_impls = generate_optional_impls(&ident, &ident_name, &ty, p, settings.impls, settings.serde_repr);
+
+    let self_test = if settings.self_test {
+        let test_mod = generate_self_test(&ident, &ident_name, &ty, p);
+        quote![
+            #allow_lints
+            #test_mod
+        ]
+    } else {
+        quote![]
+    };
+
+    let items = quote![
+        #struct_def
+        #nonzero_def
+
+        // The name of the module is derived from the type name, and only some tables might be used
+        #[allow(non_snake_case, dead_code)]
+        #[doc(hidden)]
+        #allow_lints
+        mod #mod_name {
+            use super::{#ident, #nonzero_ident};
+            #struct_impl
+            #frobenius
+            #trace
+            #normal_basis
+            #dual_basis
+            #bit_matrix
+            #xtime
+            #index
+            #nonzero_impl
+            #byte_conversions
+            #tables
+            #from
+            #into
+            #eq_base
+            #debug
+            #display
+            #from_str
+            #add
+            #sum
+            #sub
+            #mul
+            #product
+            #ref_assign
+            #div
+            #galois_trait_impl
+            #optional_impls
+        }
+
+        #self_test
+    ];
+
+    // The subfield impls are emitted at the invocation site, where the path of the subfield is
+    // valid as written
+    let subfield = match contains {
+        Some(sub) => {
+            let field = match &input_module {
+                Some((_, module)) => quote![#module::#ident],
+                None => quote![#ident],
+            };
+            let impls = generate_subfield_impls(&field, &sub, modulus, generator);
+            quote![
+                #allow_lints
+                #impls
+            ]
+        }
+        None => quote![],
+    };
+
+    match input_module {
+        Some((vis, module)) => {
+            let module_doc = format!("The finite field type [`{}`] generated by `g2p!`", ident_name);
+            quote![
+                #[doc = #module_doc]
+                #allow_lints
+                #vis mod #module {
+                    #items
+                }
+                #subfield
+            ]
+        }
+        None => quote![
+            #items
+            #subfield
+        ],
+    }
+}
+
+/// Generate the conversions from the subfield `sub` into `field` and back
+///
+/// The macro only sees the name of the subfield, so the embedding is calculated by constant
+/// evaluation from the modulus of the subfield. This fails to compile if the degree of the
+/// subfield doesn't divide `p`.
+fn generate_subfield_impls(field: &P2TokenStream, sub: &syn::Path, modulus: G2Poly, generator: G2Poly) -> P2TokenStream {
+    let (modulus_val, gen) = (modulus.0, generator.0);
+    quote![
+        const _: () = {
+            const EMBEDDING: ::g2p::__private::Embedding = ::g2p::__private::Embedding::new(#modulus_val, #gen, #sub::MODULUS.0);
+
+            impl ::core::convert::From<#sub> for #field {
+                /// Embed the subfield element, preserving addition and multiplication
+                fn from(v: #sub) -> #field {
+                    #field(EMBEDDING.embed(::core::convert::From::from(v)) as _)
+                }
+            }
+            impl ::core::convert::TryFrom<#field> for #sub {
+                type Error = ::g2p::FieldError;
+
+                /// Find the subfield element embedded as `v`, if there is one
+                fn try_from(v: #field) -> ::core::result::Result<#sub, Self::Error> {
+                    let v = u64::from(v);
+                    match EMBEDDING.project(v) {
+                        ::core::option::Option::Some(x) => ::core::result::Result::Ok(#sub(x as _)),
+                        ::core::option::Option::None => ::core::result::Result::Err(::g2p::FieldError::NotInSubfield),
+                    }
+                }
+            }
+        };
+    ]
+}
+
+struct ParsedInput {
+    ident: syn::Ident,
+    /// The power of the field size, can be left out if it follows from the modulus
+    p: Option<syn::LitInt>,
+    /// The field size 2^p, as an alternative to the power
+    size: Option<syn::LitInt>,
+    modulus: Option<ModulusArg>,
+    /// The generator of the multiplicative group, found automatically if left out
+    generator: Option<syn::Expr>,
+    tables: Option<Backend>,
+    inline: Option<Inline>,
+    serde_repr: Option<SerdeRepr>,
+    self_test: Option<bool>,
+    /// Visibility and name of the module wrapping all generated items
+    module: Option<(syn::Visibility, syn::Ident)>,
+    impls: Option<Impls>,
+    /// Additional lints to allow on all generated items
+    allow_lints: Vec<syn::Path>,
+    /// Template for the `Display` implementation
+    display: Option<syn::LitStr>,
+    /// Path of a subfield type to convert from and to
+    contains: Option<syn::Path>,
+}
+
+/// Optional trait implementations and APIs, selected by the `impls` option
+///
+/// The implementations of foreign traits are only available if the corresponding feature of
+/// g2p is enabled, which makes g2p re-export the crate for the generated code. The basis
+/// conversions are only generated if requested, as finding the bases costs compile time.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Impls {
+    hash: bool,
+    rand: bool,
+    serde: bool,
+    num_traits: bool,
+    subtle: bool,
+    zeroize: bool,
+    defmt: bool,
+    reed_solomon_erasure: bool,
+    normal_basis: bool,
+    dual_basis: bool,
+}
+
+impl Impls {
+    const NONE: Impls = Impls {
+        hash: false,
+        rand: false,
+        serde: false,
+        num_traits: false,
+        subtle: false,
+        zeroize: false,
+        defmt: false,
+        reed_solomon_erasure: false,
+        normal_basis: false,
+        dual_basis: false,
+    };
+
+    /// Used if no `impls` option is given: all trait impls the enabled features allow
+    const DEFAULT: Impls = Impls {
+        hash: true,
+        rand: cfg!(feature = "rand"),
+        serde: cfg!(feature = "serde"),
+        num_traits: cfg!(feature = "num-traits"),
+        subtle: cfg!(feature = "subtle"),
+        zeroize: cfg!(feature = "zeroize"),
+        defmt: cfg!(feature = "defmt"),
+        reed_solomon_erasure: cfg!(feature = "reed-solomon-erasure"),
+        normal_basis: false,
+        dual_basis: false,
+    };
+
+    fn enable(&mut self, name: &syn::Ident) -> syn::Result<()> {
+        let (available, feature, selected) = match name.to_string().as_str() {
+            "hash" => (true, "", &mut self.hash),
+            "rand" => (cfg!(feature = "rand"), "rand", &mut self.rand),
+            "serde" => (cfg!(feature = "serde"), "serde", &mut self.serde),
+            "num_traits" => (cfg!(feature = "num-traits"), "num-traits", &mut self.num_traits),
+            "subtle" => (cfg!(feature = "subtle"), "subtle", &mut self.subtle),
+            "zeroize" => (cfg!(feature = "zeroize"), "zeroize", &mut self.zeroize),
+            "defmt" => (cfg!(feature = "defmt"), "defmt", &mut self.defmt),
+            "reed_solomon_erasure" => (cfg!(feature = "reed-solomon-erasure"), "reed-solomon-erasure", &mut self.reed_solomon_erasure),
+            "normal_basis" => (true, "", &mut self.normal_basis),
+            "dual_basis" => (true, "", &mut self.dual_basis),
+            _ => Err(syn::Error::new(name.span(), format!(
+                "Unknown impl '{}', expected one of: hash, rand, serde, num_traits, subtle, zeroize, defmt, reed_solomon_erasure, normal_basis, dual_basis",
+                name,
+            )))?,
+        };
+        if !available {
+            Err(syn::Error::new(name.span(), format!(
+                "Implementing '{}' requires the '{}' feature of g2p",
+                name, feature,
+            )))?;
+        }
+        if *selected {
+            Err(syn::Error::new(name.span(), format!("Double declaration of '{}'", name)))?;
+        }
+        *selected = true;
+        Ok(())
+    }
+}
+
+/// How multiplication and division are implemented
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Backend {
+    /// Precomputed multiplication and inversion tables, selected by `tables: full`
+    Tables,
+    /// Shift-and-reduce loops without any data, selected by `tables: none`
+    TableFree,
+}
+
+impl Backend {
+    /// The value of the `tables` option selecting this backend, part of the table cache key
+    fn option_value(self) -> &'static str {
+        match self {
+            Backend::Tables => "full",
+            Backend::TableFree => "none",
+        }
+    }
+}
+
+/// The inlining hint on small generated methods, selected by the `inline` option
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Inline {
+    /// `#[inline(always)]`
+    Always,
+    /// `#[inline]`, which allows inlining across crates without LTO
+    Default,
+    /// `#[inline(never)]`
+    Never,
+}
+
+impl Inline {
+    fn attribute(self) -> P2TokenStream {
+        match self {
+            Inline::Always => quote![#[inline(always)]],
+            Inline::Default => quote![#[inline]],
+            Inline::Never => quote![#[inline(never)]],
+        }
+    }
+}
+
+/// The representation of elements with `serde`, selected by the `serde_repr` option
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum SerdeRepr {
+    /// A string for human-readable formats and an integer otherwise, the default
+    Auto,
+    /// Always the integer value
+    Number,
+    /// Always the decimal value as a string
+    String,
+}
+
+/// The value given for the `modulus` option
+enum ModulusArg {
+    /// The bits of the polynomial, given as an integer expression
+    Integer(syn::Expr),
+    /// The polynomial in `x^k` notation, given as a string literal
+    Polynomial(syn::LitStr),
+    /// The `conway` keyword, selecting the Conway polynomial of the field degree
+    Conway(syn::Ident),
+    /// The exponents of the terms, given as a list like `[8, 4, 3, 2, 0]`
+    Exponents(syn::token::Bracket, Vec<syn::LitInt>),
+}
+
+impl Parse for ModulusArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Ident) && input.fork().parse::<syn::Ident>()? == "conway" {
+            Ok(ModulusArg::Conway(input.parse()?))
+        } else if input.peek(syn::LitStr) {
+            Ok(ModulusArg::Polynomial(input.parse()?))
+        } else if input.peek(syn::token::Bracket) {
+            let content;
+            let bracket = syn::bracketed!(content in input);
+            let exponents = content.parse_terminated(syn::LitInt::parse, Token![,])?;
+            Ok(ModulusArg::Exponents(bracket, exponents.into_iter().collect()))
+        } else {
+            Ok(ModulusArg::Integer(input.parse()?))
+        }
+    }
+}
+
+impl Parse for ParsedInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // The named form starts with a key, the positional form with the type name
+        let named = input.peek(syn::Ident) && input.peek2(Token![:]);
+        let (mut name, mut p) = if named {
+            (None, None)
+        } else {
+            let ident = input.parse()?;
+            let _sep: Token![,] = input.parse()?;
+            let p = if input.peek(syn::LitInt) {
+                Some(input.parse()?)
+            } else {
+                None
+            };
+            (Some(ident), p)
+        };
+
+        let mut size: Option<syn::LitInt> = None;
+        let mut modulus = None;
+        let mut generator = None;
+        let mut tables = None;
+        let mut inline = None;
+        let mut serde_repr = None;
+        let mut self_test = None;
+        let mut module = None;
+        let mut impls = None;
+        let mut allow_lints = None;
+        let mut display = None;
+        let mut contains = None;
+
+        let mut first = named || p.is_none();
+        loop {
+            if !first {
+                let sep: Option<Token![,]> = input.parse()?;
+                if sep.is_none() {
+                    break;
+                }
+            }
+            first = false;
+            if input.is_empty() {
+                break;
+            }
+            if named && !(input.peek(syn::Ident) && input.peek2(Token![:])) {
+                Err(input.error("Positional arguments can't be mixed with the named form, use 'name: ...' and 'degree: ...'"))?
+            }
+            let ident: syn::Ident = input.parse()?;
+            let ident_name = ident.to_string();
+            let _sep: Token![:] = input.parse()?;
+            match ident_name.as_str() {
+                "name" | "degree" if !named => {
+                    Err(syn::parse::Error::new(ident.span(), format!(
+                        "'{}' is only allowed in the named form, which gives the type name as 'name: ...'. \
+                         In the positional form, the type name and the power come first",
+                        ident_name,
+                    )))?
+                }
+                "name" => {
+                    if name.is_some() {
+                        Err(syn::parse::Error::new(ident.span(), "Double declaration of 'name'"))?
+                    }
+                    name = Some(input.parse()?);
+                }
+                "degree" => {
+                    if p.is_some() {
+                        Err(syn::parse::Error::new(ident.span(), "Double declaration of 'degree'"))?
+                    }
+                    p = Some(input.parse()?);
+                }
+                "size" => {
+                    if size.is_some() {
+                        Err(syn::parse::Error::new(ident.span(), "Double declaration of 'size'"))?
+                    }
+                    size = Some(input.parse()?);
+                }
+                "generator" => {
+                    if generator.is_some() {
+                        Err(syn::parse::Error::new(ident.span(), "Double declaration of 'generator'"))?
+                    }
+                    generator = Some(input.parse()?);
+                }
+                "modulus" => {
+                    if modulus.is_some() {
+                        Err(syn::parse::Error::new(ident.span(), "Double declaration of 'modulus'"))?
+                    }
+                    modulus = Some(input.parse()?);
+                }
+                "module" => {
+                    if module.is_some() {
+                        Err(syn::parse::Error::new(ident.span(), "Double declaration of 'module'"))?
+                    }
+                    module = Some((input.parse()?, input.parse()?));
+                }
+                "impls" => {
+                    if impls.is_some() {
+                        Err(syn::parse::Error::new(ident.span(), "Double declaration of 'impls'"))?
+                    }
+                    let content;
+                    syn::bracketed!(content in input);
+                    let mut selected = Impls::NONE;
+                    for name in content.parse_terminated(syn::Ident::parse, Token![,])? {
+                        selected.enable(&name)?;
+                    }
+                    impls = Some(selected);
+                }
+                "allow_lints" => {
+                    if allow_lints.is_some() {
+                        Err(syn::parse::Error::new(ident.span(), "Double declaration of 'allow_lints'"))?
+                    }
+                    let content;
+                    syn::bracketed!(content in input);
+                    allow_lints = Some(content.parse_terminated(syn::Path::parse, Token![,])?.into_iter().collect());
+                }
+                "contains" => {
+                    if contains.is_some() {
+                        Err(syn::parse::Error::new(ident.span(), "Double declaration of 'contains'"))?
+                    }
+                    contains = Some(input.parse()?);
+                }
+                "display" => {
+                    if display.is_some() {
+                        Err(syn::parse::Error::new(ident.span(), "Double declaration of 'display'"))?
+                    }
+                    display = Some(input.parse()?);
+                }
+                "self_test" => {
+                    if self_test.is_some() {
+                        Err(syn::parse::Error::new(ident.span(), "Double declaration of 'self_test'"))?
+                    }
+                    let value: syn::LitBool = input.parse()?;
+                    self_test = Some(value.value);
+                }
+                "tables" => {
+                    if tables.is_some() {
+                        Err(syn::parse::Error::new(ident.span(), "Double declaration of 'tables'"))?
+                    }
+                    let value: syn::Ident = input.parse()?;
+                    tables = Some(match value.to_string().as_str() {
+                        "full" => Backend::Tables,
+                        "none" => Backend::TableFree,
+                        _ => Err(syn::parse::Error::new(value.span(), "Expected 'full' or 'none'"))?,
+                    });
+                }
+                "inline" => {
+                    if inline.is_some() {
+                        Err(syn::parse::Error::new(ident.span(), "Double declaration of 'inline'"))?
+                    }
+                    let value: syn::Ident = input.parse()?;
+                    inline = Some(match value.to_string().as_str() {
+                        "always" => Inline::Always,
+                        "default" => Inline::Default,
+                        "never" => Inline::Never,
+                        _ => Err(syn::parse::Error::new(value.span(), "Expected 'always', 'default' or 'never'"))?,
+                    });
+                }
+                "serde_repr" => {
+                    if serde_repr.is_some() {
+                        Err(syn::parse::Error::new(ident.span(), "Double declaration of 'serde_repr'"))?
+                    }
+                    let value: syn::Ident = input.parse()?;
+                    serde_repr = Some(match value.to_string().as_str() {
+                        "number" => SerdeRepr::Number,
+                        "string" => SerdeRepr::String,
+                        _ => Err(syn::parse::Error::new(value.span(), "Expected 'number' or 'string'"))?,
+                    });
+                }
+                _ => {
+                    Err(syn::parse::Error::new(
+                        ident.span(),
+                        "Expected 'name', 'degree', 'size', 'modulus', 'generator', 'tables', 'inline', 'impls', 'serde_repr', 'display', 'contains', 'self_test', 'module' or 'allow_lints'",
+                    ))?
+                }
+            }
+        }
+
+        let ident = name.ok_or_else(|| syn::Error::new(Span::call_site(), "Expected 'name: ...' in the named form"))?;
+        if let (Some(_), Some(size)) = (&p, &size) {
+            Err(syn::Error::new(size.span(), "'size' can't be combined with the power p, give only one of them"))?
+        }
+
+        Ok(ParsedInput {
+            ident,
+            p,
+            size,
+            modulus,
+            generator,
+            tables,
+            inline,
+            serde_repr,
+            self_test,
+            module,
+            impls,
+            allow_lints: allow_lints.unwrap_or_default(),
+            display,
+            contains,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Settings {
+    ident: syn::Ident,
+    ident_name: String,
+    p_val: u64,
+    modulus: G2Poly,
+    generator: G2Poly,
+    backend: Backend,
+    inline: Inline,
+    serde_repr: SerdeRepr,
+    self_test: bool,
+    impls: Impls,
+    /// Format string for `write!` implementing `Display`, and whether it uses the value
+    display_fmt: (String, bool),
+}
+
+/// Generate the documentation of the struct
+///
+/// The documentation states the parameters of the field, and contains an example with values
+/// calculated for this field. As the path of the generated type is not known, the example is
+/// not run as a doctest.
+fn struct_doc(ident_name: &str, p: u64, modulus: G2Poly, generator: G2Poly, ty: &str) -> String {
+    let mask = (1_u64 << p) - 1;
+    let (a, b) = (generator, G2Poly(mask));
+    let sum = G2Poly(a.0 ^ b.0);
+    let product = a.mul_mod(b, modulus);
+    let (_gcd, b_inv, _y) = extended_gcd(b, modulus);
+    let quotient = a.mul_mod(b_inv, modulus);
+
+    format!(
+        "An element of the finite field GF(2^{p}) with {size} elements\n\
+        \n\
+        Elements are polynomials over GF(2) of degree less than {p}, with the coefficients stored \
+        as the bits of a `{ty}`. Products are reduced modulo `{modulus}` (`{modulus:#x}`). The \
+        multiplicative group is generated by `{generator}` (`{generator:#x}`).\n\
+        \n\
+        Generated by the `g2p!` macro.\n\
+        \n\
+        # Example\n\
+        ```ignore\n\
+        let a = {name}({a});\n\
+        let b = {name}({b});\n\
+        assert_eq!(a + b, {name}({sum}));\n\
+        assert_eq!(a * b, {name}({product}));\n\
+        assert_eq!(a / b, {name}({quotient}));\n\
+        ```",
+        p = p,
+        size = 1_u64 << p,
+        ty = ty,
+        modulus = modulus,
+        generator = generator,
+        name = ident_name,
+        a = a.0,
+        b = b.0,
+        sum = sum.0,
+        product = product.0,
+        quotient = quotient.0,
+    )
+}
+
+/// Template of the `Display` implementation if the `display` option is not given
+const DEFAULT_DISPLAY: &str = "{value}";
+
+/// Convert a `display` template into a format string for `write!`
+///
+/// The template may contain `{value}` for the decimal value, `{hex}` for the value in lowercase
+/// hex and `{name}` for the type name. Literal braces are written as `{{` and `}}`. Returns the
+/// format string, and whether it refers to the value.
+fn display_format(template: &str, name: &str) -> Result<(String, bool), String> {
+    let mut res = String::with_capacity(template.len());
+    let mut uses_value = false;
+    let mut rest = template;
+    while let Some(i) = rest.find(['{', '}']) {
+        res.push_str(&rest[..i]);
+        rest = &rest[i..];
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            res.push_str(&rest[..2]);
+            rest = &rest[2..];
+            continue;
+        }
+        if rest.starts_with('}') {
+            return Err("Unmatched '}' in display template, use '}}' for a literal brace".to_string());
+        }
+        let end = rest.find('}').ok_or("Unclosed '{' in display template, use '{{' for a literal brace")?;
+        match &rest[1..end] {
+            "value" => {
+                res.push_str("{0}");
+                uses_value = true;
+            }
+            "hex" => {
+                res.push_str("{0:x}");
+                uses_value = true;
+            }
+            "name" => res.push_str(&name.replace('{', "{{").replace('}', "}}")),
+            other => return Err(format!(
+                "Unknown placeholder '{{{}}}' in display template, expected one of: {{value}}, {{hex}}, {{name}}",
+                other,
+            )),
+        }
+        rest = &rest[end + 1..];
+    }
+    res.push_str(rest);
+    Ok((res, uses_value))
+}
+
+/// Largest supported value of `p`
+///
+/// The underlying type is at most u32, so `p` can't exceed 32. The inversion table has 2^p
+/// entries, so the limit for fields with tables is lower still.
+const MAX_P: u64 = 32;
+
+/// Largest `p` for which the tables are generated
+///
+/// Beyond this, the tables take many megabytes and minutes to compile.
+const MAX_TABLE_P: u64 = 20;
+
+/// Check that the field with size 2^p is supported
+///
+/// Every `p` accepted here must be handled by [`base_type`] and the table generation.
+fn check_p(p: u64, span: Span, backend: Backend) -> syn::Result<()> {
+    let max = match backend {
+        Backend::Tables => MAX_TABLE_P,
+        Backend::TableFree => MAX_P,
+    };
+    let range = format!("the allowed range is 1 to {}", max);
+    match p {
+        0 => Err(syn::Error::new(span, format!("p must be at least 1, {}", range))),
+        p if p <= max => Ok(()),
+        p if p <= MAX_P => Err(syn::Error::new(span, format!(
+            "p = {} would need tables with 2^{} entries, {}; use `tables: none` for larger fields",
+            p, p, range,
+        ))),
+        p => Err(syn::Error::new(span, format!(
+            "p = {} exceeds the largest element size of {} bits, {}", p, MAX_P, range,
+        ))),
+    }
+}
+
+/// A hint for a power `p` that is out of range, but looks like the field size was meant
+///
+/// Sizes of 16 and below are also valid powers, so they are taken as given.
+fn size_hint(p: u64, backend: Backend) -> Option<String> {
+    if p <= 16 || !p.is_power_of_two() {
+        return None;
+    }
+    let log = u64::from(p.trailing_zeros());
+    check_p(log, Span::call_site(), backend).ok()?;
+    Some(format!("Did you mean p = {} or size: {}?", log, p))
+}
+
+/// The integer type holding the elements of a field of size 2^p
+fn base_type(p: u64) -> P2TokenStream {
+    match p {
+        1..=8 => quote!(u8),
+        9..=16 => quote!(u16),
+        17..=MAX_P => quote!(u32),
+        _ => unreachable!("p = {} is rejected by check_p", p),
+    }
+}
+
+/// Build a modulus from the exponents of its terms, e.g. `[8, 4, 3, 2, 0]` for `0x11d`
+///
+/// The exponents may come in any order, but each at most once.
+fn exponents_modulus(bracket: &syn::token::Bracket, exponents: &[syn::LitInt]) -> syn::Result<G2Poly> {
+    if exponents.is_empty() {
+        Err(syn::Error::new(bracket.span.join(), "Expected at least one exponent in the modulus"))?;
+    }
+    let mut bits = 0_u64;
+    for lit in exponents {
+        let exponent: u64 = lit.base10_parse()?;
+        if exponent >= 64 {
+            Err(syn::Error::new(lit.span(), format!("Exponent {} is too large, the modulus must have degree below 64", exponent)))?;
+        }
+        if bits & (1 << exponent) != 0 {
+            Err(syn::Error::new(lit.span(), format!("Duplicate exponent {} in the modulus", exponent)))?;
+        }
+        bits |= 1 << exponent;
+    }
+    Ok(G2Poly(bits))
+}
+
+/// Evaluate an integer expression at macro expansion time
+///
+/// Supports integer literals, parentheses and the integer operators. A proc macro only sees
+/// tokens, so named constants can't be evaluated.
+fn eval_int_expr(expr: &syn::Expr) -> syn::Result<u64> {
+    use syn::{BinOp, Expr, UnOp};
+
+    let overflow = || syn::Error::new_spanned(expr, "Overflow while evaluating the expression");
+    match expr {
+        Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => lit.base10_parse(),
+        Expr::Paren(e) => eval_int_expr(&e.expr),
+        Expr::Group(e) => eval_int_expr(&e.expr),
+        Expr::Unary(e) if matches!(e.op, UnOp::Not(_)) => Ok(!eval_int_expr(&e.expr)?),
+        Expr::Binary(e) => {
+            let l = eval_int_expr(&e.left)?;
+            let r = eval_int_expr(&e.right)?;
+            match e.op {
+                BinOp::BitOr(_) => Ok(l | r),
+                BinOp::BitAnd(_) => Ok(l & r),
+                BinOp::BitXor(_) => Ok(l ^ r),
+                BinOp::Add(_) => l.checked_add(r).ok_or_else(overflow),
+                BinOp::Sub(_) => l.checked_sub(r).ok_or_else(overflow),
+                BinOp::Mul(_) => l.checked_mul(r).ok_or_else(overflow),
+                BinOp::Shl(_) => match r {
+                    0..=63 if (l << r) >> r == l => Ok(l << r),
+                    _ => Err(overflow()),
+                },
+                BinOp::Shr(_) => match r {
+                    0..=63 => Ok(l >> r),
+                    _ => Err(overflow()),
+                },
+                _ => Err(syn::Error::new_spanned(e.op, "Unsupported operator in integer expression")),
+            }
+        }
+        Expr::Path(_) => Err(syn::Error::new_spanned(
+            expr,
+            "Named constants can't be evaluated by the macro, use an integer expression or a polynomial string instead",
+        )),
+        _ => Err(syn::Error::new_spanned(expr, "Expected an integer expression")),
+    }
+}
+
+/// Find the default modulus for a field of size 2^p
+///
+/// This is the smallest primitive polynomial of degree `p`, i.e. the one with the fewest high
+/// coefficients set. Changing this rule changes the tables of every field declared without a
+/// modulus, so it must stay stable.
+fn find_modulus_poly(p: u64) -> G2Poly {
+    assert!(p < 64);
+
+    let start = (1 << p) + 1;
+    let end = (1_u64 << (p + 1)).wrapping_sub(1);
+
+    for m in start..=end {
+        let p = G2Poly(m);
+        if p.is_primitive() {
+            return p;
+        }
+    }
+
+    unreachable!("There are primitive polynomials for any degree!")
+}
+
+/// Find the smallest generator of the multiplicative group modulo `m`
+fn find_generator(m: G2Poly) -> G2Poly {
+    let max = m.degree().expect("Modulus must have positive degree");
+
+    for g in 1..(2 << max) {
+        let g = G2Poly(g);
+        if g.is_generator(m) {
+            return g;
+        }
+    }
+
+    unreachable!("There must be a generator element")
+}
+
+/// Calculate the log base 256, rounded up
+///
+/// Given a number n, calculate the log base 256, rounded up. This can be though of as the number
+/// of bytes needed to represent this number.
+fn ceil_log256(mut n: usize) -> usize {
+    if n == 0 {
+        return 0;
+    }
+
+    let mut c = 1;
+    while n > 256 {
+        c += 1;
+        // NB: This is the rounding up part. If n is a proper power of 256, adding 255 will not
+        // change the result. In the other cases, this ensures that we round up in the division.
+        n = (n + 255) >> 8;
+    }
+    c
+}
+
+/// The inverse table as an array literal, read from the table cache if it is enabled
+fn generate_inv_table_string(modulus: G2Poly, generator: G2Poly) -> String {
+    let key = cache::TableKey { kind: "inv", modulus, generator, backend: Backend::Tables.option_value() };
+    let inv_table = cache::cached_table(cache::cache_dir().as_deref(), &key, || generate_inv_table(modulus));
+
+    use std::fmt::Write;
+    let mut res = String::with_capacity(3 * inv_table.len());
+    write!(&mut res, "[").unwrap();
+    for v in inv_table {
+        write!(&mut res, "{},", v).unwrap();
+    }
+    write!(&mut res, "]").unwrap();
+    res
+}
+
+fn generate_inv_table(modulus: G2Poly) -> Vec<u64> {
+    assert!(modulus.is_irreducible());
+
+    let field_size = 1 << modulus.degree().expect("Irreducible polynomial has positive degree");
+    let mut inv_table = vec![0; field_size as usize];
+    // Inverse table is small enough to compute directly
+    for i in 1..field_size {
+        if inv_table[i as usize] != 0 {
+            // Already computed inverse
+            continue;
+        }
+
+        let a = G2Poly(i);
+
+        // Returns (gcd, x, y) such that gcd(a, m) == a * x + y * m
+        // Since we know that gcd(a, m) == 1 and that we operate modulo m, y * m === 0 mod m
+        // So we have 1 === a * x mod m
+
+        let (_gcd, x, _y) = extended_gcd(a, modulus);
+        inv_table[i as usize] = x.0;
+        inv_table[x.0 as usize] = i;
+    }
+    inv_table
+}
+
+#[allow(clippy::too_many_arguments)]
+fn generate_mul_impl(ident: syn::Ident, inline: &P2TokenStream, ident_name: &str, modulus: G2Poly, generator: G2Poly, ty: P2TokenStream, field_size: usize, mask: u64) -> (P2TokenStream, P2TokenStream, P2TokenStream) {
+    let inv_table = generate_inv_table_string(modulus, generator);
+
+    // Faster generation than using quote
+    let inv_table_string: proc_macro2::TokenStream = inv_table.parse().unwrap();
+
+    let nparts = ceil_log256(field_size);
+    let modulus_val = modulus.0;
+
+    // The product of the byte at position `left` with the byte at position `right` is the product
+    // of the bytes times x^(8 * (left + right)), so tables are only built for each sum of the
+    // positions. The tables are computed by constant evaluation instead of emitting all entries,
+    // which keeps the expansion small.
+    let build_table = Ident::new(&format!("mul_table_{}", ty), Span::call_site());
+    let table_names: Vec<Ident> = (0..2 * nparts - 1)
+        .map(|shift| Ident::new(&format!("MUL_TABLE_{}", shift), Span::call_site()))
+        .collect();
+    let table_consts = table_names.iter().enumerate().map(|(shift, name)| {
+        let shift = 8 * shift as u32;
+        quote![
+            const #name: [[#ty; 256]; 256] = ::g2p::__private::#build_table(#modulus_val, #shift);
+        ]
+    });
+    let table_rows = (0..nparts).map(|left| {
+        let row = &table_names[left..left + nparts];
+        quote![[#(#row),*]]
+    });
+
+    // NB: We generate static arrays, as they are guaranteed to have a fixed location in memory.
+    //     Using const would mean the compiler is free to create copies on the stack etc. Since
+    //     The arrays are quite large, this could lead to stack overflows.
+    let mut tables = quote! {
+        #[doc(hidden)]
+        pub static INV_TABLE: [#ty; #field_size] = #inv_table_string;
+    };
+
+    let mut mul_ops = Vec::with_capacity(nparts * nparts);
+    if field_size == 2 {
+        // Multiplication in GF(2) is the logical and, which needs no table of byte products
+        mul_ops.push(quote![#ident(self.0 & rhs.0 & 1)]);
+    } else {
+        tables.extend(quote! {
+            #(#table_consts)*
+            #[doc(hidden)]
+            pub static MUL_TABLE: [[[[#ty; 256]; 256]; #nparts]; #nparts] = [#(#table_rows),*];
+        });
+        for left in 0..nparts {
+            for right in 0..nparts {
+                mul_ops.push(quote![
+                    #ident(MUL_TABLE[#left][#right][(((self.0 & #mask as #ty) >> (8*#left)) & 255) as usize][(((rhs.0 & #mask as #ty) >> (8*#right)) & 255) as usize])
+                ]);
+            }
+        }
+    }
+
+    let mul = quote![
+        impl ::core::ops::Mul for #ident {
+            type Output = Self;
+
+            #inline
+            fn mul(self, rhs: Self) -> Self {
+                #(#mul_ops)+*
+            }
+        }
+        impl ::core::ops::MulAssign for #ident {
+            #inline
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+    ];
+
+    let inv_msg = format!("{}: attempted to invert 0", ident_name);
+
+    let div = quote![
+        impl #ident {
+            /// Calculate the multiplicative inverse, looked up in a table
+            ///
+            /// # Panics
+            /// Panics if the element is zero.
+            #[track_caller]
+            #inline
+            pub fn inverse(self) -> Self {
+                if (self.0 & #mask as #ty) == 0 {
+                    ::core::panic!(#inv_msg);
+                }
+                Self(INV_TABLE[(self.0 & #mask as #ty) as usize])
+            }
+        }
+        impl ::core::ops::Div for #ident {
+            type Output = Self;
+
+            #[track_caller]
+            #inline
+            fn div(self, rhs: Self) -> Self {
+                if (rhs.0 & #mask as #ty) == 0 {
+                    ::g2p::__private::div_by_zero(#ident_name, u64::from(self.0));
+                }
+                self * rhs.inverse()
+            }
+        }
+        impl ::core::ops::DivAssign for #ident {
+            #[track_caller]
+            #inline
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+    ];
+
+    (tables, mul, div)
+}
+
+impl Settings {
+    pub fn from_input(input: ParsedInput) -> syn::Result<Self> {
+        let ident = input.ident;
+        let ident_name = ident.to_string();
+        let backend = input.tables.unwrap_or(Backend::Tables);
+        let p_val = match (&input.p, &input.size) {
+            (Some(p), _) => {
+                let p_val = p.base10_parse()?;
+                check_p(p_val, p.span(), backend).map_err(|e| match size_hint(p_val, backend) {
+                    Some(hint) => syn::Error::new(p.span(), format!("{}. {}", e, hint)),
+                    None => e,
+                })?;
+                Some(p_val)
+            }
+            (None, Some(size)) => {
+                let size_val: u64 = size.base10_parse()?;
+                if size_val < 2 || !size_val.is_power_of_two() {
+                    Err(syn::Error::new(size.span(), format!(
+                        "The field size must be a power of two, like 256 for p = 8, not {}",
+                        size_val,
+                    )))?;
+                }
+                let p_val = size_val.trailing_zeros().into();
+                check_p(p_val, size.span(), backend)?;
+                Some(p_val)
+            }
+            (None, None) => None,
+        };
+        let modulus = match input.modulus {
+            Some(ModulusArg::Integer(expr)) => Some((G2Poly(eval_int_expr(&expr)?), expr.span())),
+            Some(ModulusArg::Polynomial(lit)) => Some((lit.value().parse().map_err(|e| syn::Error::new(
+                lit.span(),
+                format!("Invalid modulus: {}", e),
+            ))?, lit.span())),
+            Some(ModulusArg::Exponents(bracket, exponents)) => Some((exponents_modulus(&bracket, &exponents)?, bracket.span.join())),
+            Some(ModulusArg::Conway(ident)) => {
+                let p_val = p_val.ok_or_else(|| syn::Error::new(
+                    ident.span(),
+                    "The power p must be given to select a Conway polynomial",
+                ))?;
+                let modulus = g2poly::conway(p_val).ok_or_else(|| syn::Error::new(
+                    ident.span(),
+                    format!("No Conway polynomial of degree {} is known, the table covers degrees 1 to 40", p_val),
+                ))?;
+                Some((modulus, ident.span()))
+            }
+            None => None,
+        };
+
+        let (p_val, modulus) = match (p_val, modulus) {
+            (Some(p_val), Some((modulus, span))) => {
+                if modulus.degree() != Some(p_val) {
+                    Err(syn::Error::new(span, format!(
+                        "Modulus {} ({:#x}) does not have degree {}",
+                        modulus, modulus, p_val,
+                    )))?;
+                }
+                if !modulus.is_irreducible() {
+                    Err(syn::Error::new(span, format!("Modulus {} ({:#x}) is not irreducible", modulus, modulus)))?;
+                }
+                (p_val, modulus)
+            }
+            (None, Some((modulus, span))) => {
+                if !modulus.is_irreducible() {
+                    Err(syn::Error::new(span, format!("Modulus {} ({:#x}) is not irreducible", modulus, modulus)))?;
+                }
+                let p_val = modulus.degree().expect("Irreducible polynomial has positive degree");
+                check_p(p_val, span, backend)?;
+                (p_val, modulus)
+            }
+            (Some(p_val), None) => (p_val, find_modulus_poly(p_val)),
+            (None, None) => Err(syn::Error::new(ident.span(), "Expected the power p or a modulus"))?,
+        };
+
+        let generator = match &input.generator {
+            Some(expr) => {
+                let generator = G2Poly(eval_int_expr(expr)?);
+                // Elements of the field have a smaller degree than the modulus
+                if generator.degree().map_or(true, |d| d >= p_val) || !generator.is_generator(modulus) {
+                    Err(syn::Error::new(expr.span(), format!(
+                        "{} ({:#x}) is not a generator of the multiplicative group",
+                        generator, generator,
+                    )))?;
+                }
+                generator
+            }
+            None => find_generator(modulus),
+        };
+
+        let display_fmt = match input.display {
+            Some(lit) => display_format(&lit.value(), &ident_name).map_err(|e| syn::Error::new(lit.span(), e))?,
+            None => display_format(DEFAULT_DISPLAY, &ident_name).expect("Default template is valid"),
+        };
+
+        Ok(Settings {
+            ident,
+            ident_name,
+            p_val,
+            modulus,
+            generator,
+            backend,
+            inline: input.inline.unwrap_or(Inline::Default),
+            serde_repr: input.serde_repr.unwrap_or(SerdeRepr::Auto),
+            self_test: input.self_test.unwrap_or(false),
+            impls: input.impls.unwrap_or(Impls::DEFAULT),
+            display_fmt,
+        })
+    }
+}
+
+/// Generate conversion to and from little and big endian bytes
+///
+/// Elements use the smallest number of bytes that fits p bits, which can be less than the size
+/// of the base type, e.g. 3 bytes for p = 20.
+fn generate_byte_conversions(ident: &syn::Ident, ty: &P2TokenStream, p: u64) -> P2TokenStream {
+    let bytes = ((p + 7) / 8) as usize;
+    let ty_bytes = match p {
+        0..=8 => 1_usize,
+        9..=16 => 2,
+        _ => 4,
+    };
+    let be_offset = ty_bytes - bytes;
+    let length_check = if bytes > 1 {
+        quote![
+            if bytes.len() % #bytes != 0 {
+                return ::core::result::Result::Err(::g2p::DecodeError::InvalidLength(bytes.len()));
+            }
+        ]
+    } else {
+        quote![]
+    };
+
+    // If every value of the base type is an element, vectors convert without any checks. The
+    // cast itself lives in g2p, so the expansion has no unsafe code for crates that forbid it.
+    // Narrower fields only have the checked `read_slice`.
+    let zero_copy = if p == 8 * ty_bytes as u64 {
+        let (from_name, into_name, base) = if p == 8 {
+            ("vec_from_bytes".to_string(), "vec_into_bytes".to_string(), "bytes".to_string())
+        } else {
+            (format!("vec_from_{}", ty), format!("vec_into_{}", ty), format!("`{}` values", ty))
+        };
+        let from_ident = Ident::new(&from_name, Span::call_site());
+        let into_ident = Ident::new(&into_name, Span::call_site());
+        let from_doc = format!("Convert a vector of {} into elements without copying", base);
+        let into_doc = format!("Convert the elements into a vector of {} without copying", base);
+        quote![
+            #[doc = #from_doc]
+            ///
+            /// Every value of the base type is an element of this field, so nothing has to be
+            /// checked. The allocation is reused, the length and capacity stay the same.
+            pub fn #from_ident(v: ::g2p::__private::Vec<#ty>) -> ::g2p::__private::Vec<Self> {
+                ::g2p::__private::cast_vec(v)
+            }
+
+            #[doc = #into_doc]
+            ///
+            /// The allocation is reused, the length and capacity stay the same.
+            pub fn #into_ident(v: ::g2p::__private::Vec<Self>) -> ::g2p::__private::Vec<#ty> {
+                ::g2p::__private::cast_vec(v)
+            }
+        ]
+    } else {
+        quote![]
+    };
+
+    // Only with an allocator, so `no_std` users without `alloc` don't need `Vec`
+    let slice_helpers = if cfg!(feature = "alloc") {
+        quote![
+            /// Append the elements to `out` as little endian bytes
+            pub fn write_slice(elements: &[Self], out: &mut ::g2p::__private::Vec<u8>) {
+                out.reserve(elements.len() * #bytes);
+                for e in elements {
+                    out.extend_from_slice(&e.to_le_bytes());
+                }
+            }
+
+            /// Decode elements written by `write_slice`
+            pub fn read_slice(bytes: &[u8]) -> ::core::result::Result<::g2p::__private::Vec<Self>, ::g2p::DecodeError> {
+                #length_check
+                bytes.chunks_exact(#bytes).enumerate().map(|(i, chunk)| {
+                    let mut buf = [0; #bytes];
+                    buf.copy_from_slice(chunk);
+                    Self::from_le_bytes(buf).map_err(|_| ::g2p::DecodeError::OutOfRange(i))
+                }).collect()
+            }
+
+            #zero_copy
+        ]
+    } else {
+        quote![]
+    };
+
+    quote![
+        impl #ident {
+            /// Encode the element as little endian bytes
+            pub const fn to_le_bytes(self) -> [u8; #bytes] {
+                let buf = (self.0 & Self::MASK).to_le_bytes();
+                let mut out = [0; #bytes];
+                let mut i = 0;
+                while i < #bytes {
+                    out[i] = buf[i];
+                    i += 1;
+                }
+                out
+            }
+
+            /// Encode the element as big endian bytes
+            pub const fn to_be_bytes(self) -> [u8; #bytes] {
+                let buf = (self.0 & Self::MASK).to_be_bytes();
+                let mut out = [0; #bytes];
+                let mut i = 0;
+                while i < #bytes {
+                    out[i] = buf[#be_offset + i];
+                    i += 1;
+                }
+                out
+            }
+
+            /// Decode an element from little endian bytes, or an `OutOfRange` error if the value
+            /// exceeds the mask
+            pub const fn from_le_bytes(bytes: [u8; #bytes]) -> ::core::result::Result<Self, ::g2p::FieldError> {
+                let mut buf = [0; #ty_bytes];
+                let mut i = 0;
+                while i < #bytes {
+                    buf[i] = bytes[i];
+                    i += 1;
+                }
+                let v = #ty::from_le_bytes(buf);
+                if v > Self::MASK {
+                    ::core::result::Result::Err(::g2p::FieldError::OutOfRange { value: v as u64, mask: Self::MASK as u64 })
+                } else {
+                    ::core::result::Result::Ok(Self(v))
+                }
+            }
+
+            /// Decode an element from big endian bytes, or an `OutOfRange` error if the value
+            /// exceeds the mask
+            pub const fn from_be_bytes(bytes: [u8; #bytes]) -> ::core::result::Result<Self, ::g2p::FieldError> {
+                let mut buf = [0; #ty_bytes];
+                let mut i = 0;
+                while i < #bytes {
+                    buf[#be_offset + i] = bytes[i];
+                    i += 1;
+                }
+                let v = #ty::from_be_bytes(buf);
+                if v > Self::MASK {
+                    ::core::result::Result::Err(::g2p::FieldError::OutOfRange { value: v as u64, mask: Self::MASK as u64 })
+                } else {
+                    ::core::result::Result::Ok(Self(v))
+                }
+            }
+
+            #slice_helpers
+        }
+    ]
+}
+
+/// Generate squaring, repeated squaring and the Itoh–Tsujii inversion
+///
+/// Itoh–Tsujii computes `x^-1 = (x^(2^(p-1) - 1))^2`. With `b(k) = x^(2^k - 1)` the exponent is
+/// built along the binary expansion of `p - 1`, using `b(2k) = b(k)^(2^k) * b(k)` and
+/// `b(k + 1) = b(k)^2 * x`. This needs O(log p) multiplications instead of the p of
+/// square-and-multiply.
+fn generate_frobenius_impl(ident: &syn::Ident, p: u64) -> P2TokenStream {
+    let p32 = p as u32;
+    let n = p - 1;
+    let mut steps = Vec::new();
+    if n > 0 {
+        let mut k = 1_u32;
+        for bit in (0..63 - n.leading_zeros()).rev() {
+            steps.push(quote![beta = beta.pow2k(#k) * beta;]);
+            k *= 2;
+            if n >> bit & 1 != 0 {
+                steps.push(quote![beta = beta.square() * self;]);
+                k += 1;
+            }
+        }
+        debug_assert_eq!(u64::from(k), n);
+    }
+    let pow2k_body = if p == 1 {
+        // Squaring is the identity on GF(2)
+        quote![
+            let _ = k;
+            self
+        ]
+    } else {
+        // x^(2^p) = x for all elements, so only k mod p squarings are needed
+        quote![
+            let mut x = self;
+            for _ in 0..k % #p32 {
+                x = x.square();
+            }
+            x
+        ]
+    };
+    let inverse_body = if n == 0 {
+        // The only non-zero element of GF(2) is its own inverse
+        quote![self]
+    } else if steps.is_empty() {
+        quote![self.square()]
+    } else {
+        quote![
+            let mut beta = self;
+            #(#steps)*
+            beta.square()
+        ]
+    };
+
+    quote![
+        impl #ident {
+            /// Calculate the square of the element
+            pub fn square(self) -> Self {
+                self * self
+            }
+
+            /// Calculate `self^(2^k)` by repeated squaring
+            pub fn pow2k(self, k: u32) -> Self {
+                #pow2k_body
+            }
+
+            /// Calculate the multiplicative inverse with the Itoh–Tsujii algorithm
+            ///
+            /// Returns zero for zero. Unlike `inverse` this never uses the inversion table.
+            pub fn inverse_itoh_tsujii(self) -> Self {
+                #inverse_body
+            }
+
+            /// Iterate over the powers `1, x, x^2, ...` of the element
+            ///
+            /// The iterator is infinite, each step is a single multiplication.
+            pub fn powers(self) -> impl ::core::iter::Iterator<Item = Self> {
+                self.powers_from(0)
+            }
+
+            /// Iterate over the powers `x^start_exp, x^(start_exp + 1), ...` of the element
+            pub fn powers_from(self, start_exp: u64) -> impl ::core::iter::Iterator<Item = Self> {
+                let mut start = Self(1);
+                let mut base = self;
+                let mut exp = start_exp;
+                while exp != 0 {
+                    if exp & 1 != 0 {
+                        start *= base;
+                    }
+                    base = base.square();
+                    exp >>= 1;
+                }
+                ::core::iter::successors(::core::option::Option::Some(start), move |&x| ::core::option::Option::Some(x * self))
+            }
+
+            /// Iterate over the conjugates `x, x^2, x^4, ...` of the element without duplicates
+            ///
+            /// This is the orbit of the element under the Frobenius map, which stops once it
+            /// cycles. Its length divides `p`.
+            pub fn conjugates(self) -> impl ::core::iter::Iterator<Item = Self> {
+                let start = Self(self.0 & Self::MASK);
+                ::core::iter::successors(::core::option::Option::Some(start), move |x| {
+                    let next = x.square();
+                    if next == start {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(next)
+                    }
+                })
+            }
+
+            /// The number of distinct conjugates of the element
+            ///
+            /// This is the degree of the smallest subfield containing the element.
+            pub fn conjugate_count(self) -> u32 {
+                self.conjugates().count() as u32
+            }
+        }
+    ]
+}
+
+/// The absolute trace `v + v^2 + ... + v^(2^(p-1))` of the element `v`, which is 0 or 1
+fn absolute_trace(v: G2Poly, modulus: G2Poly, p: u64) -> u64 {
+    let mut sum = v.0;
+    let mut x = v;
+    for _ in 1..p {
+        x = x.mul_mod(x, modulus);
+        sum ^= x.0;
+    }
+    debug_assert!(sum <= 1, "Trace must lie in GF(2)");
+    sum
+}
+
+/// The bits whose parity is the trace of an element, i.e. the traces of `1, x, ..., x^(p-1)`
+fn trace_mask(modulus: G2Poly, p: u64) -> u64 {
+    (0..p).fold(0, |acc, i| acc | absolute_trace(G2Poly(1 << i), modulus, p) << i)
+}
+
+/// Generate the trace and the solution of `x^2 + x = a`
+///
+/// The trace is linear, so it is the parity of the bits selected by the traces of the basis
+/// elements. For odd `p` the half-trace solves the equation, for even `p` the solution is
+/// `sum_i c_i a^(2^i)` with `c_i = sum_(j > i) d^(2^j)` for some `d` of trace one, where the
+/// constants `c_i` are precalculated here.
+fn generate_trace_impl(ident: &syn::Ident, ty: &P2TokenStream, modulus: G2Poly, p: u64) -> P2TokenStream {
+    let trace_mask = trace_mask(modulus, p);
+    let half_trace_steps = ((p - 1) / 2) as u32;
+
+    let (half_trace, solve_body) = if p % 2 == 1 {
+        let half_trace_body = if half_trace_steps == 0 {
+            // On GF(2) the half-trace is the identity
+            quote![Self(self.0 & Self::MASK)]
+        } else {
+            quote![
+                let mut x = Self(self.0 & Self::MASK);
+                let mut sum = x;
+                for _ in 0..#half_trace_steps {
+                    x = x.square().square();
+                    sum += x;
+                }
+                sum
+            ]
+        };
+        let half_trace = quote![
+            /// Calculate the half-trace `x + x^4 + x^16 + ... + x^(4^((p-1)/2))`
+            ///
+            /// Only available for odd `p`. For an element `a` of trace zero, the half-trace `h`
+            /// satisfies `h^2 + h = a`.
+            pub fn half_trace(self) -> Self {
+                #half_trace_body
+            }
+        ];
+        (half_trace, quote![a.half_trace()])
+    } else {
+        let delta = (1..1_u64 << p).find(|&v| (v & trace_mask).count_ones() % 2 == 1).expect("Trace is not identically zero");
+        let conjugates: Vec<u64> = core::iter::successors(Some(G2Poly(delta)), |x| Some(x.mul_mod(*x, modulus)))
+            .take(p as usize)
+            .map(|x| x.0)
+            .collect();
+        let coefficients: Vec<u64> = (0..p as usize - 1).map(|i| conjugates[i + 1..].iter().fold(0, |acc, c| acc ^ c)).collect();
+        let count = coefficients.len();
+        let solve_body = quote![
+            const COEFFICIENTS: [#ty; #count] = [#(#coefficients as #ty),*];
+            let mut power = Self(a.0 & Self::MASK);
+            let mut x = Self(0);
+            for &c in COEFFICIENTS.iter() {
+                x += Self(c) * power;
+                power = power.square();
+            }
+            x
+        ];
+        (quote![], solve_body)
+    };
+
+    quote![
+        impl #ident {
+            /// Calculate the absolute trace `x + x^2 + x^4 + ... + x^(2^(p-1))`
+            ///
+            /// The trace is always zero or one, it is returned as `true` for one.
+            pub const fn trace(self) -> bool {
+                (self.0 & (#trace_mask as #ty)).count_ones() % 2 == 1
+            }
+
+            #half_trace
+
+            /// Solve `x^2 + x = a`, returning the two solutions `x` and `x + 1`
+            ///
+            /// The equation is solvable exactly when the trace of `a` is zero, otherwise this
+            /// returns `None`.
+            pub fn solve_artin_schreier(a: Self) -> ::core::option::Option<(Self, Self)> {
+                if a.trace() {
+                    return ::core::option::Option::None;
+                }
+                let x = { #solve_body };
+                ::core::option::Option::Some((x, x + Self(1)))
+            }
+        }
+    ]
+}
+
+/// Invert a square matrix over GF(2), given by its columns as bit masks
+///
+/// Returns the columns of the inverse, or `None` if the matrix is singular.
+fn invert_bit_matrix(columns: &[u64]) -> Option<Vec<u64>> {
+    let n = columns.len();
+    // Reduced columns indexed by their highest bit, with the combination of the original
+    // columns that sums to them
+    let mut pivots: Vec<Option<(u64, u64)>> = vec![None; n];
+    for (j, &column) in columns.iter().enumerate() {
+        let (mut image, mut combination) = (column, 1 << j);
+        loop {
+            if image == 0 {
+                return None;
+            }
+            let top = 63 - image.leading_zeros() as usize;
+            match *pivots.get(top)? {
+                Some((pivot_image, pivot_combination)) => {
+                    image ^= pivot_image;
+                    combination ^= pivot_combination;
+                }
+                None => {
+                    pivots[top] = Some((image, combination));
+                    break;
+                }
+            }
+        }
+    }
+    // Clear the lower bits of the pivots, so that each one maps to a single unit vector
+    let mut inverse: Vec<u64> = Vec::with_capacity(n);
+    for pivot in pivots {
+        let (image, mut combination) = pivot.expect("n independent columns have n pivots");
+        for (lower, &unit) in inverse.iter().enumerate() {
+            if image >> lower & 1 != 0 {
+                combination ^= unit;
+            }
+        }
+        inverse.push(combination);
+    }
+    Some(inverse)
+}
+
+/// Generate the conversion to and from a normal basis `b, b^2, b^4, ..., b^(2^(p-1))`
+///
+/// The normal element `b` is the smallest element whose conjugates are linearly independent.
+/// The change of basis matrices are stored by columns and applied with `apply_bit_matrix`.
+fn generate_normal_basis_impl(ident: &syn::Ident, ty: &P2TokenStream, modulus: G2Poly, p: u64) -> P2TokenStream {
+    // The conjugates of an element of trace zero sum to zero, so only the others can be normal.
+    // Checking this first skips the long runs of trace zero elements for sparse moduli.
+    let trace_mask = trace_mask(modulus, p);
+    let (normal_element, from_normal, to_normal) = (1..1_u64 << p)
+        .filter(|v| (v & trace_mask).count_ones() % 2 == 1)
+        .find_map(|v| {
+            let conjugates: Vec<u64> = core::iter::successors(Some(G2Poly(v)), |x| Some(x.mul_mod(*x, modulus)))
+                .take(p as usize)
+                .map(|x| x.0)
+                .collect();
+            let inverse = invert_bit_matrix(&conjugates)?;
+            Some((v, conjugates, inverse))
+        })
+        .expect("Every finite field has a normal basis");
+    let bits = p as usize;
+    let square_body = if matches!(p, 8 | 16 | 32) {
+        quote![repr.rotate_left(1)]
+    } else {
+        let top_shift = (p - 1) as u32;
+        quote![
+            let repr = repr & Self::MASK;
+            (repr << 1 | repr >> #top_shift) & Self::MASK
+        ]
+    };
+    let normal_doc = format!("The normal element `b = {}` whose conjugates form the normal basis", normal_element);
+
+    quote![
+        impl #ident {
+            #[doc = #normal_doc]
+            pub const NORMAL_ELEMENT: Self = Self(#normal_element as #ty);
+
+            /// Convert the element to the normal basis `b, b^2, b^4, ..., b^(2^(p-1))`
+            ///
+            /// Bit `i` of the result is the coefficient of `b^(2^i)`, where `b` is
+            /// `NORMAL_ELEMENT`.
+            pub const fn to_normal_basis(self) -> #ty {
+                const COLUMNS: [#ty; #bits] = [#(#to_normal as #ty),*];
+                Self::apply_bit_matrix(&COLUMNS, self).0
+            }
+
+            /// Convert an element given in the normal basis back to the polynomial basis
+            pub const fn from_normal_basis(repr: #ty) -> Self {
+                const COLUMNS: [#ty; #bits] = [#(#from_normal as #ty),*];
+                Self::apply_bit_matrix(&COLUMNS, Self(repr))
+            }
+
+            /// Square an element given in the normal basis, which is a rotation of its bits
+            pub const fn square_normal(repr: #ty) -> #ty {
+                #square_body
+            }
+        }
+    ]
+}
+
+/// Generate the conversion to and from the dual basis of the polynomial basis
+///
+/// The dual basis `d_0, ..., d_(p-1)` satisfies `Tr(x^i d_j) = 1` if `i = j` and 0 otherwise, so
+/// the dual coordinates of `a` are `Tr(a x^j)`. The image of `x^i` thus has the bits
+/// `Tr(x^(i+j))`, and inverting that matrix gives the dual basis itself.
+fn generate_dual_basis_impl(ident: &syn::Ident, ty: &P2TokenStream, modulus: G2Poly, p: u64) -> P2TokenStream {
+    let traces: Vec<u64> = (0..2 * p - 1)
+        .map(|k| absolute_trace(G2Poly(2).pow_mod(k, modulus), modulus, p))
+        .collect();
+    let to_dual: Vec<u64> = (0..p as usize)
+        .map(|i| (0..p as usize).fold(0, |acc, j| acc | traces[i + j] << j))
+        .collect();
+    let from_dual = invert_bit_matrix(&to_dual).expect("The trace form is non-degenerate");
+    let bits = p as usize;
+
+    quote![
+        impl #ident {
+            /// The basis `d_0, ..., d_(p-1)` dual to `1, x, ..., x^(p-1)` under the trace
+            ///
+            /// `(x^i * d_j).trace()` is `true` exactly if `i == j`.
+            pub const DUAL_BASIS: [Self; #bits] = [#(Self(#from_dual as #ty)),*];
+
+            /// The matrix converting from the polynomial basis to the dual basis, by columns
+            ///
+            /// Column `i` holds the dual coordinates of `x^i`, so bit `j` of column `i` is the
+            /// entry in row `j`. Converting an element XORs the columns of its set bits.
+            pub const TO_DUAL_MATRIX: [#ty; #bits] = [#(#to_dual as #ty),*];
+
+            /// The matrix converting from the dual basis to the polynomial basis, by columns
+            ///
+            /// Column `j` is the dual basis element `d_j`, this is the inverse of
+            /// `TO_DUAL_MATRIX`.
+            pub const FROM_DUAL_MATRIX: [#ty; #bits] = [#(#from_dual as #ty),*];
+
+            /// Convert the element to the dual basis, bit `j` is the coefficient of `d_j`
+            ///
+            /// That coefficient is the trace of `self * x^j`.
+            pub const fn to_dual(self) -> #ty {
+                Self::apply_bit_matrix(&Self::TO_DUAL_MATRIX, self).0
+            }
+
+            /// Convert an element given in the dual basis back to the polynomial basis
+            pub const fn from_dual(repr: #ty) -> Self {
+                Self::apply_bit_matrix(&Self::FROM_DUAL_MATRIX, Self(repr))
+            }
+        }
+    ]
+}
+
+/// Generate the bit matrices of multiplication by a constant
+///
+/// Multiplying by `c` is GF(2)-linear, its matrix has the columns `c, c x, c x^2, ...`, which
+/// are calculated by shifting and reducing like the table free multiplication.
+fn generate_bit_matrix_impl(ident: &syn::Ident, ty: &P2TokenStream, modulus: G2Poly, p: u64) -> P2TokenStream {
+    let bits = p as usize;
+    let top_shift = (p - 1) as u32;
+    // x^p mod m, which replaces the bit shifted out at the top
+    let reduction = modulus.0 & ((1 << p) - 1);
+
+    quote![
+        impl #ident {
+            /// The p x p matrix over GF(2) of the multiplication by `self`, by columns
+            ///
+            /// Column `i` is `self * x^i`, so bit `j` of column `i` is the entry in row `j`.
+            /// [`apply_bit_matrix`](Self::apply_bit_matrix) with it multiplies by `self`.
+            pub const fn mul_matrix(self) -> [#ty; #bits] {
+                let mut columns = [0; #bits];
+                let mut column = self.0 & Self::MASK;
+                let mut i = 0;
+                while i < #bits {
+                    columns[i] = column;
+                    let carry = column >> #top_shift & 1;
+                    column = (column << 1) & Self::MASK;
+                    if carry != 0 {
+                        column ^= #reduction as #ty;
+                    }
+                    i += 1;
+                }
+                columns
+            }
+
+            /// Apply a p x p matrix over GF(2), given by columns, to the bits of `x`
+            ///
+            /// The result is the XOR of the columns selected by the bits of `x`.
+            pub const fn apply_bit_matrix(m: &[#ty; #bits], x: Self) -> Self {
+                let mut res = 0;
+                let mut i = 0;
+                while i < #bits {
+                    if x.0 >> i & 1 != 0 {
+                        res ^= m[i];
+                    }
+                    i += 1;
+                }
+                Self(res)
+            }
+
+            /// The product `a * b` of two matrices given by columns
+            ///
+            /// Applying the result is the same as applying `b` and then `a`, so
+            /// `compose_bit_matrices(&c.mul_matrix(), &d.mul_matrix())` is `(c * d).mul_matrix()`.
+            pub const fn compose_bit_matrices(a: &[#ty; #bits], b: &[#ty; #bits]) -> [#ty; #bits] {
+                let mut columns = [0; #bits];
+                let mut i = 0;
+                while i < #bits {
+                    columns[i] = Self::apply_bit_matrix(a, Self(b[i])).0;
+                    i += 1;
+                }
+                columns
+            }
+        }
+    ]
+}
+
+/// Generate `xtime`, `mul2` and `mul3` for fields of size 256, as used by AES
+///
+/// Multiplying by `x` shifts the value and, if the top bit falls out, adds the low bits of the
+/// modulus. The mask is derived from the sign of the top bit, so there is no branch.
+fn generate_xtime_impl(ident: &syn::Ident, modulus: G2Poly, p: u64) -> P2TokenStream {
+    if p != 8 {
+        return quote![];
+    }
+    let low = (modulus.0 & 0xff) as u8;
+    quote![
+        impl #ident {
+            /// Multiply by `x`, i.e. by 2, with a shift and a conditional xor of the modulus
+            pub const fn xtime(self) -> Self {
+                Self((self.0 << 1) ^ (0_u8.wrapping_sub(self.0 >> 7) & #low))
+            }
+
+            /// Multiply by 2, the same as [`xtime`](Self::xtime)
+            pub const fn mul2(self) -> Self {
+                self.xtime()
+            }
+
+            /// Multiply by 3, calculated as `xtime(a) + a`
+            pub const fn mul3(self) -> Self {
+                Self(self.xtime().0 ^ self.0)
+            }
+        }
+    ]
+}
+
+/// Generate `Index` and `IndexMut` for arrays with one entry per element, e.g. to count symbols
+fn generate_index_impl(ident: &syn::Ident, field_size: usize) -> P2TokenStream {
+    quote![
+        impl<T> ::core::ops::Index<#ident> for [T; #field_size] {
+            type Output = T;
+
+            #[inline]
+            fn index(&self, index: #ident) -> &T {
+                &self[#ident::as_usize(index)]
+            }
+        }
+        impl<T> ::core::ops::IndexMut<#ident> for [T; #field_size] {
+            #[inline]
+            fn index_mut(&mut self, index: #ident) -> &mut T {
+                &mut self[#ident::as_usize(index)]
+            }
+        }
+    ]
+}
+
+/// Generate multiplication and division without any tables
+///
+/// Multiplication is done by shift-and-xor, reducing by the modulus after every shift. Division
+/// multiplies with the inverse calculated by the Itoh–Tsujii algorithm.
+fn generate_table_free_mul_impl(ident: syn::Ident, inline: &P2TokenStream, ident_name: &str, modulus: G2Poly, ty: P2TokenStream, mask: u64) -> (P2TokenStream, P2TokenStream, P2TokenStream) {
+    let p = modulus.degree().expect("Irreducible polynomial has positive degree");
+    let p32 = p as u32;
+    let top_shift = (p - 1) as u32;
+    // x^p mod m, which replaces the bit shifted out at the top
+    let reduction = modulus.0 & mask;
+
+    // The loop always runs p times and selects with masks instead of branching on the bits, as
+    // those branches are unpredictable on random data.
+    let mul = quote![
+        impl ::core::ops::Mul for #ident {
+            type Output = Self;
+
+            #inline
+            fn mul(self, rhs: Self) -> Self {
+                let mut a = self.0 & #mask as #ty;
+                let mut b = rhs.0 & #mask as #ty;
+                let mut result = 0;
+                for _ in 0..#p32 {
+                    result ^= a & (b & 1).wrapping_neg();
+                    b >>= 1;
+                    let carry = (a >> #top_shift) & 1;
+                    a = ((a << 1) & #mask as #ty) ^ (#reduction as #ty & carry.wrapping_neg());
+                }
+                Self(result)
+            }
+        }
+        impl ::core::ops::MulAssign for #ident {
+            #inline
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+    ];
+
+    let inv_msg = format!("{}: attempted to invert 0", ident_name);
+
+    let div = quote![
+        impl #ident {
+            /// Calculate the multiplicative inverse with the Itoh–Tsujii algorithm
+            ///
+            /// # Panics
+            /// Panics if the element is zero.
+            #[track_caller]
+            #inline
+            pub fn inverse(self) -> Self {
+                if (self.0 & #mask as #ty) == 0 {
+                    ::core::panic!(#inv_msg);
+                }
+                self.inverse_itoh_tsujii()
+            }
+        }
+        impl ::core::ops::Div for #ident {
+            type Output = Self;
+
+            #[track_caller]
+            #inline
+            fn div(self, rhs: Self) -> Self {
+                if (rhs.0 & #mask as #ty) == 0 {
+                    ::g2p::__private::div_by_zero(#ident_name, u64::from(self.0));
+                }
+                self * rhs.inverse()
+            }
+        }
+        impl ::core::ops::DivAssign for #ident {
+            #[track_caller]
+            #inline
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+    ];
+
+    (quote![], mul, div)
+}
+
+/// Generate the wrapper type `NonZero<name>` for elements that are known to be invertible
+///
+/// The wrapper is closed under multiplication, division and inversion, so none of these can
+/// panic.
+fn generate_nonzero(ident: &syn::Ident, nonzero_ident: &syn::Ident, ident_name: &str, hash: bool) -> (P2TokenStream, P2TokenStream) {
+    let struct_doc = format!(
+        "A non-zero element of [`{name}`], like `NonZeroU8` for `u8`\n\n\
+        Dividing by a `{nonzero}` and inverting it never panics.",
+        name = ident_name,
+        nonzero = nonzero_ident,
+    );
+    let hash = if hash {
+        quote![#[derive(::core::hash::Hash)]]
+    } else {
+        quote![]
+    };
+    let struct_def = quote![
+        #[doc = #struct_doc]
+        #[derive(::core::clone::Clone, ::core::marker::Copy, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::cmp::PartialOrd, ::core::cmp::Ord)]
+        #hash
+        pub struct #nonzero_ident(#ident);
+    ];
+
+    let tmpl = format!("{{:?}}_{}", nonzero_ident);
+    let unchecked_msg = format!("{}::new_unchecked called with zero", nonzero_ident);
+    let impls = quote![
+        impl #nonzero_ident {
+            /// Wrap `v` if it is not zero
+            pub const fn new(v: #ident) -> ::core::option::Option<Self> {
+                if v.0 & #ident::MASK == 0 {
+                    ::core::option::Option::None
+                } else {
+                    ::core::option::Option::Some(#nonzero_ident(#ident(v.0 & #ident::MASK)))
+                }
+            }
+
+            /// Wrap `v` without checking that it is not zero in release builds
+            ///
+            /// Passing zero is not undefined behaviour, but the wrapper then breaks its promises:
+            /// inverting it or dividing by it panics, and products with it are zero.
+            ///
+            /// # Panics
+            /// Panics if `v` is zero and debug assertions are enabled.
+            #[track_caller]
+            pub const fn new_unchecked(v: #ident) -> Self {
+                if ::core::cfg!(debug_assertions) && v.0 & #ident::MASK == 0 {
+                    ::core::panic!(#unchecked_msg);
+                }
+                #nonzero_ident(v)
+            }
+
+            /// The wrapped element
+            pub const fn get(self) -> #ident {
+                self.0
+            }
+
+            /// Calculate the multiplicative inverse, which is never zero
+            pub fn inverse(self) -> Self {
+                #nonzero_ident(self.0.inverse())
+            }
+        }
+        impl ::core::convert::From<#nonzero_ident> for #ident {
+            fn from(v: #nonzero_ident) -> #ident {
+                v.0
+            }
+        }
+        impl ::core::convert::TryFrom<#ident> for #nonzero_ident {
+            type Error = ::g2p::FieldError;
+
+            fn try_from(v: #ident) -> ::core::result::Result<Self, Self::Error> {
+                #nonzero_ident::new(v).ok_or(::g2p::FieldError::ZeroElement)
+            }
+        }
+        impl ::core::fmt::Debug for #nonzero_ident {
+            fn fmt<'a>(&self, f: &mut ::core::fmt::Formatter<'a>) -> ::core::fmt::Result {
+                ::core::write!(f, #tmpl, self.0 .0)
+            }
+        }
+        impl ::core::fmt::Display for #nonzero_ident {
+            fn fmt<'a>(&self, f: &mut ::core::fmt::Formatter<'a>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+        impl ::core::ops::Mul for #nonzero_ident {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                #nonzero_ident(self.0 * rhs.0)
+            }
+        }
+        impl ::core::ops::MulAssign for #nonzero_ident {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+        impl ::core::ops::Div for #nonzero_ident {
+            type Output = Self;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: Self) -> Self {
+                self * rhs.inverse()
+            }
+        }
+        impl ::core::ops::DivAssign for #nonzero_ident {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+        impl ::core::ops::Div<#nonzero_ident> for #ident {
+            type Output = Self;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: #nonzero_ident) -> Self {
+                self * rhs.inverse().0
+            }
+        }
+        impl ::core::ops::DivAssign<#nonzero_ident> for #ident {
+            fn div_assign(&mut self, rhs: #nonzero_ident) {
+                *self = *self / rhs;
+            }
+        }
+    ];
+
+    (struct_def, impls)
+}
+
+/// Generate the implementations of foreign traits selected by the `impls` option
+fn generate_optional_impls(ident: &syn::Ident, ident_name: &str, ty: &P2TokenStream, p: u64, impls: Impls, serde_repr: SerdeRepr) -> P2TokenStream {
+    let mut res = P2TokenStream::new();
+
+    if impls.rand {
+        res.extend(quote![
+            impl ::g2p::__private::rand::distributions::Distribution<#ident> for ::g2p::__private::rand::distributions::Standard {
+                fn sample<R: ::g2p::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> #ident {
+                    // All bit patterns are equally likely, so masking keeps the distribution uniform
+                    #ident(rng.gen::<#ty>() & #ident::MASK)
+                }
+            }
+            impl #ident {
+                /// Fill `dest` with uniformly random elements
+                ///
+                /// The random bits are generated in bulk for the base type. Masking keeps the
+                /// distribution uniform, as all bit patterns are equally likely.
+                pub fn fill_random<R: ::g2p::__private::rand::Rng + ?Sized>(dest: &mut [Self], rng: &mut R) {
+                    let mut buf = [0 as #ty; 64];
+                    for chunk in dest.chunks_mut(64) {
+                        let buf = &mut buf[..chunk.len()];
+                        rng.fill(buf);
+                        for (d, &v) in chunk.iter_mut().zip(buf.iter()) {
+                            *d = #ident(v & #ident::MASK);
+                        }
+                    }
+                }
+            }
+            impl ::g2p::__private::rand::distributions::uniform::SampleUniform for #ident {
+                type Sampler = ::g2p::UniformElement<#ident, #ty>;
+            }
+            impl ::g2p::__private::rand::distributions::Distribution<#ident> for ::g2p::NonZeroStandard {
+                fn sample<R: ::g2p::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> #ident {
+                    // Rejecting zero keeps the remaining values equally likely
+                    loop {
+                        let v = rng.gen::<#ty>() & #ident::MASK;
+                        if v != 0 {
+                            return #ident(v);
+                        }
+                    }
+                }
+            }
+        ]);
+    }
+
+    if impls.num_traits {
+        res.extend(quote![
+            impl ::g2p::__private::num_traits::Inv for #ident {
+                type Output = Self;
+
+                /// Calculate the multiplicative inverse
+                ///
+                /// # Panics
+                /// Panics if the element is zero, like division by zero.
+                #[track_caller]
+                fn inv(self) -> Self {
+                    self.inverse()
+                }
+            }
+            impl<'a> ::g2p::__private::num_traits::Inv for &'a #ident {
+                type Output = #ident;
+
+                #[track_caller]
+                fn inv(self) -> #ident {
+                    self.inverse()
+                }
+            }
+            impl ::g2p::__private::num_traits::Pow<u32> for #ident {
+                type Output = Self;
+
+                fn pow(self, exp: u32) -> Self {
+                    ::g2p::__private::pow_reduced(self, exp as u64)
+                }
+            }
+            impl<'a> ::g2p::__private::num_traits::Pow<u32> for &'a #ident {
+                type Output = #ident;
+
+                fn pow(self, exp: u32) -> #ident {
+                    ::g2p::__private::pow_reduced(*self, exp as u64)
+                }
+            }
+            impl ::g2p::__private::num_traits::Pow<u64> for #ident {
+                type Output = Self;
+
+                fn pow(self, exp: u64) -> Self {
+                    ::g2p::__private::pow_reduced(self, exp as u64)
+                }
+            }
+            impl<'a> ::g2p::__private::num_traits::Pow<u64> for &'a #ident {
+                type Output = #ident;
+
+                fn pow(self, exp: u64) -> #ident {
+                    ::g2p::__private::pow_reduced(*self, exp as u64)
+                }
+            }
+            impl ::g2p::__private::num_traits::Pow<usize> for #ident {
+                type Output = Self;
+
+                fn pow(self, exp: usize) -> Self {
+                    ::g2p::__private::pow_reduced(self, exp as u64)
+                }
+            }
+            impl<'a> ::g2p::__private::num_traits::Pow<usize> for &'a #ident {
+                type Output = #ident;
+
+                fn pow(self, exp: usize) -> #ident {
+                    ::g2p::__private::pow_reduced(*self, exp as u64)
+                }
+            }
+            impl ::g2p::__private::num_traits::CheckedDiv for #ident {
+                fn checked_div(&self, v: &Self) -> ::core::option::Option<Self> {
+                    #ident::checked_div(*self, *v)
+                }
+            }
+            // Addition, subtraction and multiplication can't fail in a field
+            impl ::g2p::__private::num_traits::CheckedAdd for #ident {
+                fn checked_add(&self, v: &Self) -> ::core::option::Option<Self> {
+                    ::core::option::Option::Some(*self + *v)
+                }
+            }
+            impl ::g2p::__private::num_traits::CheckedSub for #ident {
+                fn checked_sub(&self, v: &Self) -> ::core::option::Option<Self> {
+                    ::core::option::Option::Some(*self - *v)
+                }
+            }
+            impl ::g2p::__private::num_traits::CheckedMul for #ident {
+                fn checked_mul(&self, v: &Self) -> ::core::option::Option<Self> {
+                    ::core::option::Option::Some(*self * *v)
+                }
+            }
+        ]);
+    }
+
+    if impls.subtle {
+        res.extend(quote![
+            impl ::g2p::__private::subtle::ConstantTimeEq for #ident {
+                /// Compare the stored values like `==`, without branching on them
+                fn ct_eq(&self, other: &Self) -> ::g2p::__private::subtle::Choice {
+                    ::g2p::__private::subtle::ConstantTimeEq::ct_eq(&self.0, &other.0)
+                }
+            }
+            impl ::g2p::__private::subtle::ConditionallySelectable for #ident {
+                /// Select `a` if `choice` is 0 and `b` if it is 1 by masking the stored values
+                fn conditional_select(a: &Self, b: &Self, choice: ::g2p::__private::subtle::Choice) -> Self {
+                    #ident(::g2p::__private::subtle::ConditionallySelectable::conditional_select(&a.0, &b.0, choice))
+                }
+            }
+        ]);
+    }
+
+    if impls.zeroize {
+        res.extend(quote![
+            impl ::g2p::__private::zeroize::Zeroize for #ident {
+                /// Set the element to zero in a way the compiler won't optimize away
+                fn zeroize(&mut self) {
+                    ::g2p::__private::zeroize::Zeroize::zeroize(&mut self.0);
+                }
+            }
+        ]);
+    }
+
+    if impls.defmt {
+        // The name is part of the interned format string, so it costs nothing on the wire
+        let format = syn::LitStr::new(&format!("{{={}}}_{}", ty, ident_name), Span::call_site());
+        res.extend(quote![
+            impl ::g2p::__private::defmt::Format for #ident {
+                /// Log the element like `Debug`, e.g. `5_GF256`
+                fn format(&self, f: ::g2p::__private::defmt::Formatter<'_>) {
+                    // The macros of defmt refer to the crate by the relative path `defmt`
+                    use ::g2p::__private::defmt;
+                    defmt::write!(f, #format, self.0)
+                }
+            }
+        ]);
+    }
+
+    if impls.reed_solomon_erasure {
+        // Byte fields use the vectorized kernels of `g2p::simd`
+        let (mul_slice, mul_add_slice) = if p <= 8 {
+            (quote![mul_slice_bytes], quote![mul_add_slice_bytes])
+        } else {
+            (quote![mul_slice_base], quote![mul_add_slice_base])
+        };
+        res.extend(quote![
+            /// The field for `reed_solomon_erasure::ReedSolomon`, with shards of the base type
+            impl ::g2p::__private::reed_solomon_erasure::Field for #ident {
+                const ORDER: usize = <#ident as ::g2p::GaloisField>::SIZE;
+                type Elem = #ty;
+
+                fn add(a: #ty, b: #ty) -> #ty {
+                    (#ident(a) + #ident(b)).0
+                }
+
+                fn mul(a: #ty, b: #ty) -> #ty {
+                    (#ident(a) * #ident(b)).0
+                }
+
+                #[track_caller]
+                fn div(a: #ty, b: #ty) -> #ty {
+                    (#ident(a) / #ident(b)).0
+                }
+
+                fn exp(a: #ty, n: usize) -> #ty {
+                    ::g2p::GaloisField::pow(#ident(a), n).0
+                }
+
+                fn zero() -> #ty {
+                    0
+                }
+
+                fn one() -> #ty {
+                    1
+                }
+
+                fn nth_internal(n: usize) -> #ty {
+                    n as #ty
+                }
+
+                /// Delegates to the slice kernels of `g2p`, multiplying by zero or one doesn't look at
+                /// the input
+                #[track_caller]
+                fn mul_slice(elem: #ty, input: &[#ty], out: &mut [#ty]) {
+                    ::g2p::__private::#mul_slice(#ident::from(elem), input, out)
+                }
+
+                #[track_caller]
+                fn mul_slice_add(elem: #ty, input: &[#ty], out: &mut [#ty]) {
+                    ::g2p::__private::#mul_add_slice(#ident::from(elem), input, out)
+                }
+            }
+        ]);
+    }
+
+    if impls.serde {
+        let err_msg = format!("{{}} is out of range for {}", ident_name);
+        let expecting = format!("an element of {} as an integer or a decimal string", ident_name);
+        let deserialize_int = syn::Ident::new(&format!("deserialize_{}", ty), Span::call_site());
+        let serialize = match serde_repr {
+            SerdeRepr::Auto => quote![
+                if serializer.is_human_readable() {
+                    serializer.collect_str(&v)
+                } else {
+                    ::g2p::__private::serde::Serialize::serialize(&v, serializer)
+                }
+            ],
+            SerdeRepr::Number => quote![::g2p::__private::serde::Serialize::serialize(&v, serializer)],
+            SerdeRepr::String => quote![serializer.collect_str(&v)],
+        };
+        // Human-readable formats also accept integers, which is how elements were written before
+        // strings became the default
+        let deserialize = match serde_repr {
+            SerdeRepr::Auto => quote![
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_any(Visitor)
+                } else {
+                    deserializer.#deserialize_int(Visitor)
+                }
+            ],
+            SerdeRepr::Number => quote![deserializer.#deserialize_int(Visitor)],
+            SerdeRepr::String => quote![deserializer.deserialize_str(Visitor)],
+        };
+        res.extend(quote![
+            impl ::g2p::__private::serde::Serialize for #ident {
+                fn serialize<S: ::g2p::__private::serde::Serializer>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error> {
+                    let v = self.0 & #ident::MASK;
+                    #serialize
+                }
+            }
+            impl<'de> ::g2p::__private::serde::Deserialize<'de> for #ident {
+                fn deserialize<D: ::g2p::__private::serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self, D::Error> {
+                    struct Visitor;
+
+                    impl<'de> ::g2p::__private::serde::de::Visitor<'de> for Visitor {
+                        type Value = #ident;
+
+                        fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                            f.write_str(#expecting)
+                        }
+
+                        fn visit_u64<E: ::g2p::__private::serde::de::Error>(self, v: u64) -> ::core::result::Result<#ident, E> {
+                            if v & !(#ident::MASK as u64) != 0 {
+                                return ::core::result::Result::Err(E::custom(::core::format_args!(#err_msg, v)));
+                            }
+                            ::core::result::Result::Ok(#ident(v as #ty))
+                        }
+
+                        fn visit_str<E: ::g2p::__private::serde::de::Error>(self, v: &str) -> ::core::result::Result<#ident, E> {
+                            match v.parse::<u64>() {
+                                ::core::result::Result::Ok(v) => self.visit_u64(v),
+                                ::core::result::Result::Err(_) => ::core::result::Result::Err(E::invalid_value(::g2p::__private::serde::de::Unexpected::Str(v), &self)),
+                            }
+                        }
+                    }
+
+                    #deserialize
+                }
+            }
+        ]);
+    }
+
+    res
+}
+
+/// Largest `p` for which the self test checks all elements instead of a sample
+const MAX_EXHAUSTIVE_TEST_P: u64 = 12;
+
+/// Generate a test module checking the field axioms for the generated type
+///
+/// The generated module `<name>_axioms` is only compiled for tests of the crate invoking the
+/// macro. Properties of single elements are checked for all elements of fields up to
+/// 2^12 elements, and for a fixed pseudo-random sample otherwise. Properties involving three
+/// elements are always checked on a sample.
+fn generate_self_test(ident: &syn::Ident, ident_name: &str, ty: &P2TokenStream, p: u64) -> P2TokenStream {
+    let test_mod = Ident::new(&format!("{}_axioms", ident_name.to_lowercase()), Span::call_site());
+    let exhaustive = p <= MAX_EXHAUSTIVE_TEST_P;
+    let mask = (1_u64 << p).wrapping_sub(1);
+    let order = (1_u64 << p) - 1;
+
+    quote![
+        #[cfg(test)]
+        mod #test_mod {
+            // The invoking crate may be `no_std`, but tests always have `std` available
+            extern crate std;
+
+            use super::#ident;
+            use ::g2p::GaloisField;
+
+            /// Up to `n` elements of the field, always including 0 and 1
+            fn sample(n: u64) -> std::vec::Vec<#ident> {
+                if n > #mask {
+                    return (0..=#mask).map(|v| #ident(v as #ty)).collect();
+                }
+                // xorshift64, so the sample is the same on every run
+                let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+                let mut res = std::vec![#ident::ZERO, #ident::ONE];
+                while (res.len() as u64) < n {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    res.push(#ident((state & #mask) as #ty));
+                }
+                res
+            }
+
+            fn elements() -> std::vec::Vec<#ident> {
+                if #exhaustive {
+                    sample(u64::MAX)
+                } else {
+                    sample(1 << 12)
+                }
+            }
+
+            #[test]
+            fn test_addition() {
+                let s = sample(32);
+                for &a in &s {
+                    ::core::assert_eq!(a + #ident::ZERO, a);
+                    ::core::assert_eq!(a + a, #ident::ZERO);
+                    ::core::assert_eq!(a + -a, #ident::ZERO);
+                    for &b in &s {
+                        ::core::assert_eq!(a + b, b + a);
+                        ::core::assert_eq!(a - b, a + -b);
+                        for &c in &s {
+                            ::core::assert_eq!((a + b) + c, a + (b + c));
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn test_multiplication() {
+                let s = sample(32);
+                for &a in &s {
+                    ::core::assert_eq!(a * #ident::ONE, a);
+                    ::core::assert_eq!(a * #ident::ZERO, #ident::ZERO);
+                    for &b in &s {
+                        ::core::assert_eq!(a * b, b * a);
+                        for &c in &s {
+                            ::core::assert_eq!((a * b) * c, a * (b * c));
+                            ::core::assert_eq!(a * (b + c), a * b + a * c);
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn test_inverse() {
+                for a in elements() {
+                    if a == #ident::ZERO {
+                        continue;
+                    }
+                    let inv = #ident::ONE / a;
+                    ::core::assert_eq!(a * inv, #ident::ONE);
+                    ::core::assert_eq!(a / a, #ident::ONE);
+                }
+            }
+
+            #[test]
+            fn test_generator() {
+                let g = #ident::GENERATOR;
+                ::core::assert_eq!(g.pow(#order as usize), #ident::ONE);
+                if #exhaustive {
+                    // The powers of the generator enumerate all non-zero elements
+                    let mut seen = std::vec![false; (#mask + 1) as usize];
+                    let mut x = #ident::ONE;
+                    for _ in 0..#order {
+                        ::core::assert!(!seen[x.0 as usize]);
+                        seen[x.0 as usize] = true;
+                        x *= g;
+                    }
+                    ::core::assert_eq!(x, #ident::ONE);
+                    ::core::assert!(!seen[0]);
+                } else {
+                    let s = sample(64);
+                    for &a in &s {
+                        for &b in &s {
+                            let (i, j) = (a.0 as usize, b.0 as usize);
+                            ::core::assert_eq!(g.pow(i) * g.pow(j), g.pow(i + j));
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn test_conversions() {
+                for a in elements() {
+                    ::core::assert_eq!(<#ident as ::core::convert::From<#ty>>::from(<#ty as ::core::convert::From<#ident>>::from(a)), a);
+                }
+                ::core::assert_eq!(<#ident as ::core::convert::From<#ty>>::from(#mask as #ty).0, #mask as #ty);
+                ::core::assert_eq!(<#ident as ::core::convert::From<#ty>>::from(<#ty>::MAX).0, #mask as #ty);
+            }
+        }
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parse and check the arguments of `g2p!`
+    fn settings(input: &str) -> syn::Result<Settings> {
+        Settings::from_input(syn::parse_str(input)?)
+    }
+
+    /// The message for invalid arguments, whether parsing or checking them failed
+    fn settings_err(input: &str) -> String {
+        match settings(input) {
+            Ok(_) => panic!("{} should be rejected", input),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_settings_parser() {
+        let span = Span::call_site();
+        assert_eq!(settings("foo, 3").unwrap(), Settings {
+            ident: syn::Ident::new("foo", span),
+            ident_name: "foo".to_string(),
+            p_val: 3,
+            modulus: G2Poly(0b1011),
+            generator: G2Poly(0b10),
+            backend: Backend::Tables,
+            inline: Inline::Default,
+            serde_repr: SerdeRepr::Auto,
+            self_test: false,
+            impls: Impls::DEFAULT,
+            display_fmt: ("{0}".to_string(), true),
+        });
+    }
+
+    #[test]
+    fn test_default_modulus() {
+        // Changing any of these changes the tables of existing fields
+        let s = settings("foo, 3").unwrap();
+        assert_eq!((s.modulus, s.generator), (G2Poly(0b1011), G2Poly(0b10)));
+        let s = settings("foo, 4").unwrap();
+        assert_eq!((s.modulus, s.generator), (G2Poly(0b1_0011), G2Poly(0b10)));
+        let s = settings("foo, 8").unwrap();
+        assert_eq!((s.modulus, s.generator), (G2Poly(0b1_0001_1101), G2Poly(0b10)));
+
+        // The smallest irreducible polynomial 0x11b is not primitive
+        assert!(G2Poly(0x11b).is_irreducible());
+        assert!(!G2Poly(0x11b).is_primitive());
+    }
+
+    #[test]
+    fn test_settings_infer_p() {
+        let s = settings("foo, modulus: 0x11d").unwrap();
+        assert_eq!((s.p_val, s.modulus), (8, G2Poly(0x11d)));
+
+        assert_eq!(settings_err("name: foo"), "Expected the power p or a modulus");
+        assert_eq!(settings_err("foo, 7, modulus: 0x11d"), "Modulus x^8 + x^4 + x^3 + x^2 + 1 (0x11d) does not have degree 7");
+        assert!(settings("foo, modulus: conway").is_err());
+    }
+
+    #[test]
+    fn test_input_parser() {
+        let input: ParsedInput = syn::parse_str("GF256, modulus: 0x11d").unwrap();
+        assert!(input.p.is_none());
+        assert!(input.modulus.is_some());
+
+        let input: ParsedInput = syn::parse_str("GF256, 8, modulus: 0x11d,").unwrap();
+        assert!(input.p.is_some());
+        assert!(input.modulus.is_some());
+
+        let input: ParsedInput = syn::parse_str("GF256, 8").unwrap();
+        assert!(input.p.is_some());
+        assert!(input.modulus.is_none());
+
+        let input: ParsedInput = syn::parse_str("GF256, 8, module: pub(crate) gf256").unwrap();
+        let (vis, module) = input.module.unwrap();
+        assert!(matches!(vis, syn::Visibility::Restricted(_)));
+        assert_eq!(module, "gf256");
+        let input: ParsedInput = syn::parse_str("GF256, 8, module: gf256").unwrap();
+        assert!(matches!(input.module, Some((syn::Visibility::Inherited, _))));
+
+        let input: ParsedInput = syn::parse_str("GF256, 8, impls: [hash]").unwrap();
+        assert_eq!(input.impls, Some(Impls { hash: true, ..Impls::NONE }));
+        let input: ParsedInput = syn::parse_str("GF256, 8, impls: []").unwrap();
+        assert_eq!(input.impls, Some(Impls::NONE));
+        let input: ParsedInput = syn::parse_str("GF256, 8, impls: [normal_basis, dual_basis]").unwrap();
+        assert_eq!(input.impls, Some(Impls { normal_basis: true, dual_basis: true, ..Impls::NONE }));
+        let err = syn::parse_str::<ParsedInput>("GF256, 8, impls: [hash, foo]").err().unwrap();
+        assert_eq!(err.to_string(), "Unknown impl 'foo', expected one of: hash, rand, serde, num_traits, subtle, zeroize, defmt, reed_solomon_erasure, normal_basis, dual_basis");
+        assert!(syn::parse_str::<ParsedInput>("GF256, 8, impls: [hash, hash]").is_err());
+
+        let input: ParsedInput = syn::parse_str("GF256, 8, allow_lints: [non_camel_case_types, clippy::all]").unwrap();
+        assert_eq!(input.allow_lints.len(), 2);
+        assert!(input.allow_lints[0].is_ident("non_camel_case_types"));
+
+        let input: ParsedInput = syn::parse_str("GF256, 8, self_test: true").unwrap();
+        assert_eq!(input.self_test, Some(true));
+        assert!(syn::parse_str::<ParsedInput>("GF256, 8, self_test: 1").is_err());
+
+        let input: ParsedInput = syn::parse_str("GF256, 8, tables: none").unwrap();
+        assert_eq!(input.tables, Some(Backend::TableFree));
+        let input: ParsedInput = syn::parse_str("GF256, 8, tables: full, modulus: 0x11d").unwrap();
+        assert_eq!(input.tables, Some(Backend::Tables));
+
+        assert!(syn::parse_str::<ParsedInput>("GF256, 8 modulus: 0x11d").is_err());
+        assert!(syn::parse_str::<ParsedInput>("GF256, 8, tables: some").is_err());
+        assert!(syn::parse_str::<ParsedInput>("GF256, 8, tables: none, tables: full").is_err());
+
+        let input: ParsedInput = syn::parse_str("GF256, 8, inline: never").unwrap();
+        assert_eq!(input.inline, Some(Inline::Never));
+        let input: ParsedInput = syn::parse_str("GF256, 8, inline: always").unwrap();
+        assert_eq!(input.inline, Some(Inline::Always));
+        assert!(syn::parse_str::<ParsedInput>("GF256, 8, inline: sometimes").is_err());
+        assert!(syn::parse_str::<ParsedInput>("GF256, 8, inline: default, inline: never").is_err());
+
+        let input: ParsedInput = syn::parse_str("GF256, 8, serde_repr: string").unwrap();
+        assert_eq!(input.serde_repr, Some(SerdeRepr::String));
+        let input: ParsedInput = syn::parse_str("GF256, 8, serde_repr: number").unwrap();
+        assert_eq!(input.serde_repr, Some(SerdeRepr::Number));
+        assert!(syn::parse_str::<ParsedInput>("GF256, 8, serde_repr: auto").is_err());
+        assert!(syn::parse_str::<ParsedInput>("GF256, 8, serde_repr: number, serde_repr: string").is_err());
+
+        let input: ParsedInput = syn::parse_str("GF256, 8, contains: GF16").unwrap();
+        assert_eq!(input.contains.unwrap().segments.len(), 1);
+        let input: ParsedInput = syn::parse_str("GF256, 8, contains: crate::fields::GF16, tables: none").unwrap();
+        assert_eq!(input.contains.unwrap().segments.len(), 3);
+        assert!(syn::parse_str::<ParsedInput>("GF256, 8, contains: 4").is_err());
+        assert!(syn::parse_str::<ParsedInput>("GF256, 8, contains: GF16, contains: GF4").is_err());
+    }
+
+    #[test]
+    fn test_named_input_parser() {
+        let parse_err = |s: &str| match syn::parse_str::<ParsedInput>(s) {
+            Ok(_) => panic!("{} should not parse", s),
+            Err(e) => e.to_string(),
+        };
+
+        let input: ParsedInput = syn::parse_str("name: GF256, degree: 8, modulus: 0x11d, generator: 0x02").unwrap();
+        assert_eq!(input.ident.to_string(), "GF256");
+        assert_eq!(input.p.unwrap().base10_digits(), "8");
+        assert!(input.modulus.is_some());
+        assert!(input.generator.is_some());
+
+        // Keys can come in any order, and the degree can follow from the modulus
+        let input: ParsedInput = syn::parse_str("modulus: 0x11d, tables: none, name: GF256,").unwrap();
+        assert_eq!(input.ident.to_string(), "GF256");
+        assert!(input.p.is_none());
+        assert_eq!(input.tables, Some(Backend::TableFree));
+
+        // The generator is also accepted in the positional form
+        let input: ParsedInput = syn::parse_str("GF256, 8, generator: 3").unwrap();
+        assert!(input.generator.is_some());
+
+        assert_eq!(parse_err("degree: 8"), "Expected 'name: ...' in the named form");
+        assert_eq!(parse_err("name: GF256, name: GF16"), "Double declaration of 'name'");
+        assert_eq!(parse_err("name: GF256, degree: 8, degree: 8"), "Double declaration of 'degree'");
+        assert!(parse_err("name: GF256, 8").starts_with("Positional arguments can't be mixed with the named form"));
+        assert!(parse_err("name: GF256, degree: 8, tables").starts_with("Positional arguments can't be mixed"));
+        assert!(parse_err("GF256, degree: 8").starts_with("'degree' is only allowed in the named form"));
+        assert!(parse_err("GF256, 8, name: GF16").starts_with("'name' is only allowed in the named form"));
+    }
+
+    #[test]
+    fn test_settings_generator() {
+        let s = settings("name: GF256, degree: 8, modulus: 0x11d, generator: 0x02").unwrap();
+        assert_eq!((s.p_val, s.modulus, s.generator), (8, G2Poly(0x11d), G2Poly(2)));
+        // 2 is not a generator for the AES modulus, but 3 is
+        let s = settings("GF256, 8, modulus: 0x11b").unwrap();
+        assert_eq!(s.generator, G2Poly(3));
+        let s = settings("GF256, 8, modulus: 0x11b, generator: 0x03").unwrap();
+        assert_eq!(s.generator, G2Poly(3));
+        // x^2 has order 15 in GF(16) as well
+        let s = settings("GF16, 4, generator: 0b100").unwrap();
+        assert_eq!(s.generator, G2Poly(0b100));
+
+        let err = settings_err;
+        assert_eq!(err("GF256, 8, modulus: 0x11b, generator: 2"), "x (0x2) is not a generator of the multiplicative group");
+        assert_eq!(err("GF16, 4, generator: 0"), "0 (0x0) is not a generator of the multiplicative group");
+        assert_eq!(err("GF16, 4, generator: 1"), "1 (0x1) is not a generator of the multiplicative group");
+        // Not an element of the field
+        assert_eq!(err("GF16, 4, generator: 0x12"), "x^4 + x (0x12) is not a generator of the multiplicative group");
+    }
+
+    #[test]
+    fn test_inline_expansion() {
+        let span = Span::call_site();
+        for inline in [Inline::Always, Inline::Default, Inline::Never] {
+            let attribute = inline.attribute();
+            let expected = attribute.to_string();
+            let (_, mul, div) = generate_table_free_mul_impl(Ident::new("GF16", span), &attribute, "GF16", G2Poly(0b10011), quote![u8], 0b1111);
+            // mul and mul_assign, then inverse, div and div_assign
+            assert_eq!(mul.to_string().matches(&expected).count(), 2);
+            assert_eq!(div.to_string().matches(&expected).count(), 3);
+            let (_, mul, div) = generate_mul_impl(Ident::new("GF16", span), &attribute, "GF16", G2Poly(0b10011), G2Poly(0b10), quote![u8], 16, 0b1111);
+            assert_eq!(mul.to_string().matches(&expected).count(), 2);
+            assert_eq!(div.to_string().matches(&expected).count(), 3);
+        }
+        assert_eq!(Inline::Default.attribute().to_string(), quote![#[inline]].to_string());
+        assert_eq!(Inline::Never.attribute().to_string(), quote![#[inline(never)]].to_string());
+    }
+
+    #[test]
+    fn test_settings_invalid_p() {
+        let err = settings_err;
+        assert_eq!(err("foo, 0"), "p must be at least 1, the allowed range is 1 to 20");
+        assert_eq!(err("foo, 24"), "p = 24 would need tables with 2^24 entries, the allowed range is 1 to 20; \
+            use `tables: none` for larger fields");
+        assert_eq!(err("foo, 99"), "p = 99 exceeds the largest element size of 32 bits, the allowed range is 1 to 20");
+        assert_eq!(err("foo, 0, modulus: 0b11"), "p must be at least 1, the allowed range is 1 to 20");
+        assert!(err("foo, modulus: 0x101_e6a9").starts_with("p = 24 would need tables with 2^24 entries"));
+    }
+
+    #[test]
+    fn test_settings_size() {
+        let err = settings_err;
+
+        let s = settings("GF256, size: 256, modulus: 0x11b").unwrap();
+        assert_eq!((s.p_val, s.modulus), (8, G2Poly(0x11b)));
+        assert_eq!(settings("GF16, size: 16").unwrap(), settings("GF16, 4").unwrap());
+        assert_eq!(settings("name: GF1024, size: 1024").unwrap().p_val, 10);
+        assert_eq!(settings("GF2, size: 2").unwrap().p_val, 1);
+        assert_eq!(settings("GF4G, size: 4294967296, tables: none").unwrap().p_val, 32);
+
+        assert_eq!(err("GF256, size: 255"), "The field size must be a power of two, like 256 for p = 8, not 255");
+        assert_eq!(err("GF1, size: 1"), "The field size must be a power of two, like 256 for p = 8, not 1");
+        assert!(err("GF0, size: 0").starts_with("The field size must be a power of two"));
+        assert!(err("GF16M, size: 16777216").starts_with("p = 24 would need tables with 2^24 entries"));
+        assert_eq!(err("GF256, 8, size: 256"), "'size' can't be combined with the power p, give only one of them");
+        assert_eq!(err("name: GF256, size: 256, degree: 8"), "'size' can't be combined with the power p, give only one of them");
+        assert_eq!(err("GF256, size: 256, size: 256"), "Double declaration of 'size'");
+    }
+
+    #[test]
+    fn test_settings_size_hint() {
+        let err = settings_err;
+
+        assert_eq!(
+            err("GF256, 256"),
+            "p = 256 exceeds the largest element size of 32 bits, the allowed range is 1 to 20. Did you mean p = 8 or size: 256?",
+        );
+        assert!(err("GF65536, 65536").ends_with(". Did you mean p = 16 or size: 65536?"));
+        assert!(err("name: GF1024, degree: 1024").ends_with(". Did you mean p = 10 or size: 1024?"));
+        // 32 is a valid power without tables, but too large for them
+        assert!(err("GF32, 32").starts_with("p = 32 would need tables with 2^32 entries"));
+        assert!(err("GF32, 32").ends_with(". Did you mean p = 5 or size: 32?"));
+        // No hint if the power isn't a plausible size, or the size would be out of range as well
+        assert_eq!(err("GF, 99"), "p = 99 exceeds the largest element size of 32 bits, the allowed range is 1 to 20");
+        assert!(!err("GF, 4194304").contains("Did you mean"));
+        assert!(err("GF, 4194304, tables: none").ends_with(". Did you mean p = 22 or size: 4194304?"));
+    }
+
+    #[test]
+    fn test_check_p_table_free() {
+        let span = Span::call_site();
+        assert!(check_p(24, span, Backend::TableFree).is_ok());
+        assert!(check_p(32, span, Backend::TableFree).is_ok());
+        assert_eq!(
+            check_p(33, span, Backend::TableFree).unwrap_err().to_string(),
+            "p = 33 exceeds the largest element size of 32 bits, the allowed range is 1 to 32",
+        );
+    }
+
+    #[test]
+    fn test_struct_doc() {
+        let doc = struct_doc("GF16", 4, G2Poly(0b1_0011), G2Poly(0b10), "u8");
+        assert!(doc.starts_with("An element of the finite field GF(2^4) with 16 elements\n"));
+        assert!(doc.contains("modulo `x^4 + x + 1` (`0x13`)"));
+        assert!(doc.contains("generated by `x` (`0x2`)"));
+        assert!(doc.contains("let b = GF16(15);"));
+        assert!(doc.contains("assert_eq!(a + b, GF16(13));"));
+        // b = x^3 + x^2 + x + 1 = x^12, so a * b = x^13 and a / b = x^-11 = x^4 = x + 1
+        assert!(doc.contains("assert_eq!(a * b, GF16(13));"));
+        assert!(doc.contains("assert_eq!(a / b, GF16(3));"));
+    }
+
+    #[test]
+    fn test_display_format() {
+        assert_eq!(display_format(DEFAULT_DISPLAY, "GF256"), Ok(("{0}".to_string(), true)));
+        assert_eq!(display_format("{value}_{name}", "GF256"), Ok(("{0}_GF256".to_string(), true)));
+        assert_eq!(display_format("{value}", "GF256"), Ok(("{0}".to_string(), true)));
+        assert_eq!(display_format("0x{hex} in {name}", "GF256"), Ok(("0x{0:x} in GF256".to_string(), true)));
+        assert_eq!(display_format("{{{name}}}", "GF256"), Ok(("{{GF256}}".to_string(), false)));
+        assert_eq!(display_format("element", "GF256"), Ok(("element".to_string(), false)));
+
+        assert!(display_format("{val}", "GF256").unwrap_err().starts_with("Unknown placeholder '{val}'"));
+        assert!(display_format("{value", "GF256").is_err());
+        assert!(display_format("value}", "GF256").is_err());
+    }
+
+    #[test]
+    fn test_eval_int_expr() {
+        let eval = |s: &str| eval_int_expr(&syn::parse_str(s).unwrap());
+
+        assert_eq!(eval("0x11d").unwrap(), 0x11d);
+        assert_eq!(eval("0x1_1D_u64").unwrap(), 0x11d);
+        assert_eq!(eval("0b1_0001_1101").unwrap(), 0x11d);
+        assert_eq!(eval("285u32").unwrap(), 0x11d);
+        assert_eq!(eval("0x100 | 0x1b").unwrap(), 0x11b);
+        assert_eq!(eval("(1 << 8) ^ 0b11101").unwrap(), 0x11d);
+        assert_eq!(eval("1 << 4 | 1 << 1 | 1").unwrap(), 0b1_0011);
+        assert_eq!(eval("!0 >> 60").unwrap(), 0b1111);
+
+        assert!(eval("1 << 64").is_err());
+        assert!(eval("0 - 1").is_err());
+        assert!(eval("0x11d / 2").is_err());
+        assert!(eval("AES_POLY").is_err());
+        assert!(eval("foo()").is_err());
+        assert!(eval("\"x\" as u64").is_err());
+    }
+
+    #[test]
+    fn test_settings_conway() {
+        assert_eq!(settings("foo, 8, modulus: conway").unwrap().modulus, G2Poly(0b1_0001_1101));
+        assert!(settings("foo, 41, modulus: conway").is_err());
+    }
+
+    #[test]
+    fn test_settings_reducible_modulus() {
+        assert_eq!(settings_err("foo, 8, modulus: 0x101"), "Modulus x^8 + 1 (0x101) is not irreducible");
+    }
+
+    #[test]
+    fn test_settings_polynomial_modulus() {
+        assert_eq!(settings(r#"foo, 8, modulus: "x^8 + x^4 + x^3 + x^2 + 1""#).unwrap().modulus, G2Poly(0b1_0001_1101));
+        assert_eq!(settings_err(r#"foo, 8, modulus: "x^8 + y""#), "Invalid modulus: invalid polynomial at byte 6: expected a term");
+    }
+
+    #[test]
+    fn test_settings_exponents_modulus() {
+        let err = settings_err;
+
+        assert_eq!(settings("GF256, 8, modulus: [8, 4, 3, 2, 0]").unwrap(), settings("GF256, 8, modulus: 0x11d").unwrap());
+        assert_eq!(settings("GF256, modulus: [0, 1, 3, 4, 8,]").unwrap().modulus, G2Poly(0x11b));
+        assert_eq!(settings("name: GF2, modulus: [1, 0]").unwrap().p_val, 1);
+
+        assert_eq!(err("GF256, 8, modulus: [8, 4, 4, 3, 2, 0]"), "Duplicate exponent 4 in the modulus");
+        assert_eq!(err("GF256, 8, modulus: [4, 3, 2, 0]"), "Modulus x^4 + x^3 + x^2 + 1 (0x1d) does not have degree 8");
+        assert_eq!(err("GF256, 8, modulus: [64, 0]"), "Exponent 64 is too large, the modulus must have degree below 64");
+        assert_eq!(err("GF256, 8, modulus: []"), "Expected at least one exponent in the modulus");
+        assert!(err("GF256, 8, modulus: [8, x]").starts_with("expected integer literal"));
+    }
+
+    #[test]
+    fn test_generate_inv_table_string() {
+        let m = G2Poly(0b1_0001_1011);
+
+        assert_eq!(include_str!("../tests/inv_table.txt").trim(), generate_inv_table_string(m, G2Poly(0b11)));
+    }
+
+    #[test]
+    fn test_ceil_log256() {
+        assert_eq!(0, ceil_log256(0));
+        assert_eq!(1, ceil_log256(1));
+        assert_eq!(1, ceil_log256(256));
+        assert_eq!(2, ceil_log256(257));
+        assert_eq!(2, ceil_log256(65536));
+        assert_eq!(3, ceil_log256(65537));
+        assert_eq!(3, ceil_log256(131072));
+        assert_eq!(3, ceil_log256(16777216));
+        assert_eq!(4, ceil_log256(16777217));
+    }
+}
@@ -1,19 +1,15 @@
 use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
-use galois_2p8;
 use galois_2p8::Field;
-use rand;
 use rand::{Rng, RngCore};
-use reed_solomon_erasure;
-
-use g2p;
 
 g2p::g2p!(GF256, 8);
+g2p::g2p!(GF256Loop, 8, tables: none);
 
 fn g2p_addition(a: &[u8], b: &[u8], dest: &mut [u8]) {
     assert_eq!(a.len(), b.len());
     assert_eq!(b.len(), dest.len());
 
-    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.into_iter(), b), dest) {
+    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.iter(), b), dest) {
         *d = (GF256::from(l) + GF256::from(r)).into()
     }
 }
@@ -30,7 +26,7 @@ fn reed_solomon_erasure_addition(a: &[u8], b: &[u8], dest: &mut [u8]) {
     assert_eq!(a.len(), b.len());
     assert_eq!(b.len(), dest.len());
 
-    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.into_iter(), b), dest) {
+    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.iter(), b), dest) {
         *d = reed_solomon_erasure::galois_8::add(l, r);
     }
 }
@@ -39,16 +35,25 @@ fn g2p_multiplication(a: &[u8], b: &[u8], dest: &mut [u8]) {
     assert_eq!(a.len(), b.len());
     assert_eq!(b.len(), dest.len());
 
-    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.into_iter(), b), dest) {
+    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.iter(), b), dest) {
         *d = (GF256::from(l) * GF256::from(r)).into()
     }
 }
 
+fn g2p_tables_none_multiplication(a: &[u8], b: &[u8], dest: &mut [u8]) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(b.len(), dest.len());
+
+    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.iter(), b), dest) {
+        *d = (GF256Loop::from(l) * GF256Loop::from(r)).into()
+    }
+}
+
 fn galois_2p8_multiplication(field: &galois_2p8::PrimitivePolynomialField, a: &[u8], b: &[u8], dest: &mut [u8]) {
     assert_eq!(a.len(), b.len());
     assert_eq!(b.len(), dest.len());
 
-    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.into_iter(), b), dest) {
+    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.iter(), b), dest) {
         *d = field.mult(l, r)
     }
 }
@@ -57,7 +62,7 @@ fn reed_solomon_erasure_multiplication(a: &[u8], b: &[u8], dest: &mut [u8]) {
     assert_eq!(a.len(), b.len());
     assert_eq!(b.len(), dest.len());
 
-    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.into_iter(), b), dest) {
+    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.iter(), b), dest) {
         *d = reed_solomon_erasure::galois_8::mul(l, r);
     }
 }
@@ -65,7 +70,7 @@ fn reed_solomon_erasure_multiplication(a: &[u8], b: &[u8], dest: &mut [u8]) {
 fn g2p_multiplication_const(a: &[u8], b: u8, dest: &mut [u8]) {
     assert_eq!(a.len(), dest.len());
 
-    for (&l, d) in Iterator::zip(a.into_iter(), dest) {
+    for (&l, d) in Iterator::zip(a.iter(), dest) {
         *d = (GF256::from(l) * GF256::from(b)).into()
     }
 }
@@ -83,11 +88,19 @@ fn reed_solomon_erasure_multiplication_const(a: &[u8], b: u8, dest: &mut [u8]) {
     reed_solomon_erasure::galois_8::mul_slice(b, a, dest);
 }
 
+fn g2p_scale_slice(data: &mut [GF256], c: u8) {
+    g2p::scale_slice(GF256(c), data);
+}
+
+fn galois_2p8_scale_slice(field: &galois_2p8::PrimitivePolynomialField, data: &mut [u8], c: u8) {
+    field.mult_multiword(data, c);
+}
+
 fn g2p_division(a: &[u8], b: &[u8], dest: &mut [u8]) {
     assert_eq!(a.len(), b.len());
     assert_eq!(b.len(), dest.len());
 
-    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.into_iter(), b), dest) {
+    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.iter(), b), dest) {
         *d = (GF256::from(l) / GF256::from(r)).into()
     }
 }
@@ -96,7 +109,7 @@ fn galois_2p8_division(field: &galois_2p8::PrimitivePolynomialField, a: &[u8], b
     assert_eq!(a.len(), b.len());
     assert_eq!(b.len(), dest.len());
 
-    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.into_iter(), b), dest) {
+    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.iter(), b), dest) {
         *d = field.div(l, r)
     }
 }
@@ -105,7 +118,7 @@ fn reed_solomon_erasure_division(a: &[u8], b: &[u8], dest: &mut [u8]) {
     assert_eq!(a.len(), b.len());
     assert_eq!(b.len(), dest.len());
 
-    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.into_iter(), b), dest) {
+    for ((&l, &r), d) in Iterator::zip(Iterator::zip(a.iter(), b), dest) {
         *d = reed_solomon_erasure::galois_8::div(l, r);
     }
 }
@@ -113,7 +126,7 @@ fn reed_solomon_erasure_division(a: &[u8], b: &[u8], dest: &mut [u8]) {
 fn g2p_division_const(a: &[u8], b: u8, dest: &mut [u8]) {
     assert_eq!(a.len(), dest.len());
 
-    for (&l, d) in Iterator::zip(a.into_iter(), dest) {
+    for (&l, d) in Iterator::zip(a.iter(), dest) {
         *d = (GF256::from(l) / GF256::from(b)).into()
     }
 }
@@ -128,7 +141,7 @@ fn galois_2p8_division_const(field: &galois_2p8::PrimitivePolynomialField, a: &[
 fn reed_solomon_erasure_division_const(a: &[u8], b: u8, dest: &mut [u8]) {
     assert_eq!(a.len(), dest.len());
 
-    for (&l, d) in Iterator::zip(a.into_iter(), dest) {
+    for (&l, d) in Iterator::zip(a.iter(), dest) {
         *d = reed_solomon_erasure::galois_8::div(l, b);
     }
 }
@@ -137,7 +150,7 @@ fn reed_solomon_erasure_division_const(a: &[u8], b: u8, dest: &mut [u8]) {
 fn g2p_inverse(a: &[u8], dest: &mut [u8]) {
     assert_eq!(a.len(), dest.len());
 
-    for (&inv, d) in Iterator::zip(a.into_iter(), dest) {
+    for (&inv, d) in Iterator::zip(a.iter(), dest) {
         *d = (GF256::from(1) / GF256::from(inv)).into()
     }
 }
@@ -145,7 +158,7 @@ fn g2p_inverse(a: &[u8], dest: &mut [u8]) {
 fn galois_2p8_inverse(field: &galois_2p8::PrimitivePolynomialField, a: &[u8], dest: &mut [u8]) {
     assert_eq!(a.len(), dest.len());
 
-    for (&inv, d) in Iterator::zip(a.into_iter(), dest) {
+    for (&inv, d) in Iterator::zip(a.iter(), dest) {
         *d = field.div(1, inv);
     }
 }
@@ -153,7 +166,7 @@ fn galois_2p8_inverse(field: &galois_2p8::PrimitivePolynomialField, a: &[u8], de
 fn reed_solomon_erasure_inverse(a: &[u8], dest: &mut [u8]) {
     assert_eq!(a.len(), dest.len());
 
-    for (&inv, d) in Iterator::zip(a.into_iter(), dest) {
+    for (&inv, d) in Iterator::zip(a.iter(), dest) {
         *d = reed_solomon_erasure::galois_8::div(1, inv);
     }
 }
@@ -232,6 +245,18 @@ fn all_benches(c: &mut Criterion) {
                     BatchSize::SmallInput,
                 )
             });
+        group.bench_function(
+            BenchmarkId::new("g2p_tables_none", i),
+            |bencher| {
+                bencher.iter_batched(
+                    || (a.clone(), b.clone(), dest.clone()),
+                    |(a, b, mut dest)| {
+                        g2p_tables_none_multiplication(&a, &b, &mut dest);
+                        dest
+                    },
+                    BatchSize::SmallInput,
+                )
+            });
         group.bench_function(
             BenchmarkId::new("galois_2p8", i),
             |bencher| {
@@ -305,6 +330,40 @@ fn all_benches(c: &mut Criterion) {
     }
     group.finish();
 
+    let mut group = c.benchmark_group("scale_slice");
+    for &i in input_sizes.iter() {
+        let mut a = vec![0; i];
+        let b = rng.gen();
+        rng.fill_bytes(&mut a[..]);
+        let elements: Vec<GF256> = a.iter().map(|&v| GF256(v)).collect();
+
+        group.bench_function(
+            BenchmarkId::new("g2p", i),
+            |bencher| {
+                bencher.iter_batched(
+                    || elements.clone(),
+                    |mut data| {
+                        g2p_scale_slice(&mut data, b);
+                        data
+                    },
+                    BatchSize::SmallInput,
+                )
+            });
+        group.bench_function(
+            BenchmarkId::new("galois_2p8", i),
+            |bencher| {
+                bencher.iter_batched(
+                    || a.clone(),
+                    |mut data| {
+                        galois_2p8_scale_slice(&galois_2p8_field, &mut data, b);
+                        data
+                    },
+                    BatchSize::SmallInput,
+                )
+            });
+    }
+    group.finish();
+
     let mut group = c.benchmark_group("inverse");
     for &i in input_sizes.iter() {
         let mut a = vec![0; i];
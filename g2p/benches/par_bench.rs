@@ -0,0 +1,28 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use rand::Rng;
+
+g2p::g2p!(GF256, 8);
+
+const LEN: usize = 64 << 20;
+
+fn mul_add_slice_threads(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let src: Vec<GF256> = (0..LEN).map(|_| GF256(rng.gen())).collect();
+    let mut dst: Vec<GF256> = (0..LEN).map(|_| GF256(rng.gen())).collect();
+    let factor = GF256(rng.gen_range(2..=255));
+
+    let mut group = c.benchmark_group("mul_add_slice");
+    group.throughput(Throughput::Bytes(LEN as u64));
+    group.sample_size(10);
+    group.bench_function("sequential", |b| b.iter(|| g2p::mul_add_slice(factor, &src, &mut dst)));
+    for &threads in [1, 2, 4, 8].iter() {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+        group.bench_with_input(BenchmarkId::new("parallel", threads), &threads, |b, _| {
+            b.iter(|| pool.install(|| g2p::par_mul_add_slice(factor, &src, &mut dst)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, mul_add_slice_threads);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Enables the `core::simd` kernels if the `portable-simd` feature is selected on nightly
+//!
+//! On stable, the feature is ignored and the scalar kernels are used, so that building with
+//! `--all-features` keeps working.
+
+use std::{env, process::Command};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(g2p_portable_simd)");
+
+    if env::var_os("CARGO_FEATURE_PORTABLE_SIMD").is_none() {
+        return;
+    }
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .map(|out| String::from_utf8_lossy(&out.stdout).into_owned())
+        .unwrap_or_default();
+    if version.contains("nightly") || version.contains("-dev") {
+        println!("cargo:rustc-cfg=g2p_portable_simd");
+    }
+}
@@ -0,0 +1,88 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Construction of the AES S-box
+//!
+//! The S-box of AES maps a byte to its inverse in GF(2^8) with the Rijndael modulus
+//! `x^8 + x^4 + x^3 + x + 1`, followed by an affine transformation over GF(2). Both steps are
+//! available here, so that other S-box-like constructions can reuse [`affine_transform`].
+//!
+//! ```rust
+//! use g2p::aes::aes_sbox;
+//!
+//! g2p::g2p!(GF256, 8, modulus: 0x11b);
+//! # fn main() {
+//! let (sbox, inv_sbox) = aes_sbox::<GF256>();
+//! assert_eq!(sbox[0x53], 0xed);
+//! assert_eq!(inv_sbox[0xed], 0x53);
+//! # }
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::GaloisField;
+
+/// The Rijndael modulus `x^8 + x^4 + x^3 + x + 1`
+const AES_MODULUS: u64 = 0x11b;
+
+/// Row `i` selects the bits `i, i + 4, i + 5, i + 6, i + 7` modulo 8, as in FIPS 197
+const AES_MATRIX: [u8; 8] = [0xf1, 0xe3, 0xc7, 0x8f, 0x1f, 0x3e, 0x7c, 0xf8];
+
+/// The constant added by the affine transformation of AES
+const AES_CONSTANT: u8 = 0x63;
+
+/// Fails to evaluate for fields other than GF(2^8) with the Rijndael modulus
+struct AssertAesField<F>(PhantomData<F>);
+
+impl<F: GaloisField> AssertAesField<F> {
+    const VALID: () = assert!(F::MODULUS.0 == AES_MODULUS, "The AES S-box requires the modulus 0x11b");
+}
+
+/// Apply the affine map `A x + c` over GF(2)
+///
+/// Bit `i` of the result is the parity of `matrix[i] & x`, plus bit `i` of `constant`.
+///
+/// ```rust
+/// use g2p::aes::affine_transform;
+///
+/// let identity = [1, 2, 4, 8, 16, 32, 64, 128];
+/// assert_eq!(affine_transform(0x5a, identity, 0), 0x5a);
+/// assert_eq!(affine_transform(0x5a, identity, 0xff), 0xa5);
+/// ```
+pub const fn affine_transform(x: u8, matrix: [u8; 8], constant: u8) -> u8 {
+    let mut res = constant;
+    let mut i = 0;
+    while i < 8 {
+        res ^= (((matrix[i] & x).count_ones() & 1) as u8) << i;
+        i += 1;
+    }
+    res
+}
+
+/// Calculate the forward and inverse S-box of AES
+///
+/// The forward S-box maps `x` to the affine transformation of `x^-1`, with `0` mapped to the
+/// transformation of `0`. The inverse S-box is calculated by inverting this permutation.
+///
+/// The field must have the modulus `0x11b`, other fields fail to compile.
+pub fn aes_sbox<F>() -> ([u8; 256], [u8; 256])
+where
+    F: GaloisField + From<u8> + Into<u8>,
+{
+    #[allow(clippy::let_unit_value)]
+    let () = AssertAesField::<F>::VALID;
+
+    let mut sbox = [0; 256];
+    let mut inv_sbox = [0; 256];
+    for x in 0..=255_u8 {
+        let e = F::from(x);
+        let inverse = if e == F::ZERO { 0 } else { (F::ONE / e).into() };
+        let y = affine_transform(inverse, AES_MATRIX, AES_CONSTANT);
+        sbox[usize::from(x)] = y;
+        inv_sbox[usize::from(y)] = x;
+    }
+    (sbox, inv_sbox)
+}
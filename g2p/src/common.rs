@@ -0,0 +1,30 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Ready-made fields with the usual moduli
+//!
+//! These types are generated once when g2p itself is compiled, so crates using them don't run
+//! the procedural macro. They are the same as the output of `g2p!`, so all traits and kernels of
+//! this crate apply. The optional trait implementations follow the enabled features of g2p.
+//!
+//! | Type         | Field    | Modulus   | Used by                                      |
+//! |--------------|----------|-----------|----------------------------------------------|
+//! | [`Gf16`]     | GF(2^4)  | `0x13`    | small examples and tests                     |
+//! | [`Gf256Aes`] | GF(2^8)  | `0x11b`   | AES, Shamir's secret sharing                 |
+//! | [`Gf256Rs`]  | GF(2^8)  | `0x11d`   | Reed-Solomon codes, e.g. QR codes and RAID 6 |
+//! | [`Gf65536`]  | GF(2^16) | `0x1100b` | Reed-Solomon codes with more than 255 shards |
+//!
+//! ```rust
+//! use g2p::{common::Gf256Rs, GaloisField};
+//!
+//! assert_eq!(Gf256Rs(0x80) * Gf256Rs(2), Gf256Rs(0x1d));
+//! assert_eq!(Gf256Rs::GENERATOR, Gf256Rs(2));
+//! ```
+
+crate::g2p!(Gf16, 4, modulus: 0b1_0011);
+crate::g2p!(Gf256Aes, 8, modulus: 0b1_0001_1011);
+crate::g2p!(Gf256Rs, 8, modulus: 0b1_0001_1101);
+crate::g2p!(Gf65536, 16, modulus: 0b1_0001_0000_0000_1011);
@@ -0,0 +1,328 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Fields with a modulus chosen at runtime
+
+use alloc::{vec, vec::Vec};
+use core::{fmt, hash::{Hash, Hasher}, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign}};
+
+use crate::{FieldError, G2Poly};
+
+/// Largest degree of a runtime field, the same limit as for the tables of `g2p!`
+const MAX_DEGREE: u32 = 20;
+
+/// A field GF(2^degree) whose modulus is only known at runtime
+///
+/// The field holds exp and log tables of the multiplicative group, so creating it takes time and
+/// memory linear in the field size. The elements are [`DynGfElem`]s borrowing the field, which
+/// support the same operators as the types generated by `g2p!`.
+///
+/// ```rust
+/// use g2p::DynGf;
+///
+/// g2p::g2p!(GF256, 8, modulus: 0x11d);
+/// # fn main() {
+/// let field = DynGf::new(8, 0x11d).expect("Valid modulus");
+/// let a = field.elem(0x53).unwrap();
+/// let b = field.elem(0xca).unwrap();
+/// assert_eq!((a * b).value(), (GF256(0x53) * GF256(0xca)).0.into());
+/// assert_eq!(a / a, field.one());
+/// assert!(DynGf::new(8, 0x100).is_err());
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct DynGf {
+    degree: u32,
+    modulus: G2Poly,
+    generator: u32,
+    /// `generator^i` for `0 <= i < 2 * (size - 1)`, so sums of two logarithms need no reduction
+    exp: Vec<u32>,
+    /// The logarithm of each non-zero element, with an unused entry for zero
+    log: Vec<u32>,
+}
+
+impl DynGf {
+    /// Create the field GF(2^degree) with the given modulus
+    ///
+    /// The degree must be in the range 1 to 20, and the modulus an irreducible polynomial of this
+    /// degree. The generator is the smallest element generating the multiplicative group, as for
+    /// `g2p!`.
+    pub fn new(degree: u32, modulus: u64) -> Result<Self, FieldError> {
+        if degree == 0 || degree > MAX_DEGREE {
+            return Err(FieldError::InvalidDegree { degree });
+        }
+        let modulus = G2Poly(modulus);
+        if modulus.degree() != Some(u64::from(degree)) {
+            return Err(FieldError::ModulusDegree { modulus: modulus.0, degree });
+        }
+        if !modulus.is_irreducible() {
+            return Err(FieldError::ReducibleModulus { modulus: modulus.0 });
+        }
+
+        let generator = (1..1_u64 << degree)
+            .find(|&g| G2Poly(g).is_generator(modulus))
+            .expect("The multiplicative group of a field is cyclic");
+        let order = (1_usize << degree) - 1;
+        let mut exp = Vec::with_capacity(2 * order);
+        let mut log = vec![0; order + 1];
+        let mut x = G2Poly(1);
+        for i in 0..order {
+            exp.push(x.0 as u32);
+            log[x.0 as usize] = i as u32;
+            x = x.mul_mod(G2Poly(generator), modulus);
+        }
+        exp.extend_from_within(..order);
+
+        Ok(DynGf { degree, modulus, generator: generator as u32, exp, log })
+    }
+
+    /// The degree `p` of the field GF(2^p)
+    pub fn degree(&self) -> u32 {
+        self.degree
+    }
+
+    /// The number of elements of the field
+    pub fn size(&self) -> usize {
+        1 << self.degree
+    }
+
+    /// Mask of the bits used by the elements
+    pub fn mask(&self) -> u32 {
+        (1 << self.degree) - 1
+    }
+
+    /// The modulus used to construct the field
+    pub fn modulus(&self) -> G2Poly {
+        self.modulus
+    }
+
+    /// The element with the given value, or an error if the value is out of range
+    pub fn elem(&self, value: u32) -> Result<DynGfElem<'_>, FieldError> {
+        if value & !self.mask() != 0 {
+            return Err(FieldError::OutOfRange { value: u64::from(value), mask: u64::from(self.mask()) });
+        }
+        Ok(DynGfElem { field: self, value })
+    }
+
+    /// The neutral element of addition
+    pub fn zero(&self) -> DynGfElem<'_> {
+        DynGfElem { field: self, value: 0 }
+    }
+
+    /// The neutral element of multiplication
+    pub fn one(&self) -> DynGfElem<'_> {
+        DynGfElem { field: self, value: 1 }
+    }
+
+    /// The generator of the multiplicative group
+    pub fn generator(&self) -> DynGfElem<'_> {
+        DynGfElem { field: self, value: self.generator }
+    }
+
+    /// Iterate over all elements, in order of their values
+    pub fn elements(&self) -> impl Iterator<Item = DynGfElem<'_>> {
+        (0..1 << self.degree).map(move |value| DynGfElem { field: self, value })
+    }
+
+    fn mul(&self, a: u32, b: u32) -> u32 {
+        if a == 0 || b == 0 {
+            return 0;
+        }
+        self.exp[(self.log[a as usize] + self.log[b as usize]) as usize]
+    }
+
+    fn inverse(&self, a: u32) -> u32 {
+        let order = self.mask();
+        self.exp[((order - self.log[a as usize]) % order) as usize]
+    }
+}
+
+impl fmt::Debug for DynGf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The tables are not interesting
+        f.debug_struct("DynGf")
+            .field("degree", &self.degree)
+            .field("modulus", &self.modulus)
+            .field("generator", &self.generator)
+            .finish()
+    }
+}
+
+/// An element of a [`DynGf`]
+///
+/// Combining elements of fields with different moduli panics.
+#[derive(Clone, Copy)]
+pub struct DynGfElem<'a> {
+    field: &'a DynGf,
+    value: u32,
+}
+
+impl<'a> DynGfElem<'a> {
+    /// The field the element belongs to
+    pub fn field(self) -> &'a DynGf {
+        self.field
+    }
+
+    /// The value of the element, with bit `i` the coefficient of `x^i`
+    pub fn value(self) -> u32 {
+        self.value
+    }
+
+    /// The multiplicative inverse
+    ///
+    /// # Panics
+    /// Panics if the element is zero.
+    #[track_caller]
+    pub fn inverse(self) -> Self {
+        assert!(self.value != 0, "GF(2^{}): attempted to invert 0", self.field.degree);
+        DynGfElem { field: self.field, value: self.field.inverse(self.value) }
+    }
+
+    /// Divide by `rhs`, or return `None` if it is zero
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.try_div(rhs).ok()
+    }
+
+    /// Divide by `rhs`, or return [`FieldError::DivisionByZero`] if it is zero
+    pub fn try_div(self, rhs: Self) -> Result<Self, FieldError> {
+        if rhs.value == 0 {
+            Err(FieldError::DivisionByZero)
+        } else {
+            Ok(self / rhs)
+        }
+    }
+
+    /// Calculate `self^exp`, where `0^0` is one
+    pub fn pow(self, exp: u64) -> Self {
+        let value = if self.value == 0 {
+            (exp == 0) as u32
+        } else {
+            let order = u64::from(self.field.mask());
+            let log = u64::from(self.field.log[self.value as usize]) * (exp % order) % order;
+            self.field.exp[log as usize]
+        };
+        DynGfElem { field: self.field, value }
+    }
+
+    #[track_caller]
+    fn check_field(self, rhs: Self) {
+        assert!(
+            self.field.modulus == rhs.field.modulus,
+            "elements of fields with the moduli {} and {} can't be combined",
+            self.field.modulus, rhs.field.modulus,
+        );
+    }
+}
+
+impl PartialEq for DynGfElem<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.field.modulus == other.field.modulus
+    }
+}
+
+impl Eq for DynGfElem<'_> {}
+
+impl Hash for DynGfElem<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl fmt::Debug for DynGfElem<'_> {
+    /// The value with the field size as suffix, like `5_GF256` for the generated types
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_GF{}", self.value, self.field.size())
+    }
+}
+
+impl fmt::Display for DynGfElem<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl Add for DynGfElem<'_> {
+    type Output = Self;
+
+    #[track_caller]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        self.check_field(rhs);
+        DynGfElem { field: self.field, value: self.value ^ rhs.value }
+    }
+}
+
+impl Sub for DynGfElem<'_> {
+    type Output = Self;
+
+    #[track_caller]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        self + rhs
+    }
+}
+
+impl Neg for DynGfElem<'_> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl Mul for DynGfElem<'_> {
+    type Output = Self;
+
+    #[track_caller]
+    fn mul(self, rhs: Self) -> Self {
+        self.check_field(rhs);
+        DynGfElem { field: self.field, value: self.field.mul(self.value, rhs.value) }
+    }
+}
+
+impl Div for DynGfElem<'_> {
+    type Output = Self;
+
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    #[track_caller]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self.check_field(rhs);
+        if rhs.value == 0 {
+            panic!("GF(2^{}): attempted {} / 0", self.field.degree, self.value);
+        }
+        self * rhs.inverse()
+    }
+}
+
+impl AddAssign for DynGfElem<'_> {
+    #[track_caller]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for DynGfElem<'_> {
+    #[track_caller]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for DynGfElem<'_> {
+    #[track_caller]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for DynGfElem<'_> {
+    #[track_caller]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
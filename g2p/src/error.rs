@@ -0,0 +1,158 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+
+/// Error returned by the generated `read_slice` when decoding elements from bytes fails
+///
+/// ```rust
+/// use g2p::DecodeError;
+///
+/// g2p::g2p!(GF4096, 12);
+/// # fn main() {
+/// assert_eq!(GF4096::read_slice(&[1, 0, 2]), Err(DecodeError::InvalidLength(3)));
+/// assert_eq!(GF4096::read_slice(&[1, 0, 0, 0x10]), Err(DecodeError::OutOfRange(1)));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DecodeError {
+    /// The input length is not a multiple of the element size
+    InvalidLength(usize),
+    /// The element at the given index exceeds the mask of the field
+    OutOfRange(usize),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt<'a>(&self, f: &mut fmt::Formatter<'a>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidLength(len) => write!(f, "input length {} is not a multiple of the element size", len),
+            DecodeError::OutOfRange(index) => write!(f, "element {} is out of range for the field", index),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// Error returned by fallible operations on fields
+///
+/// This is the error of the generated `TryFrom`, `FromStr`, `from_str_radix`, `from_le_bytes`,
+/// `from_be_bytes` and `try_div`, as well as of [`DynGf`](crate::DynGf).
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use g2p::{DynGf, FieldError};
+///
+/// g2p::g2p!(GF16, 4);
+/// # fn main() {
+/// assert_eq!(GF16::try_from(16_usize), Err(FieldError::OutOfRange { value: 16, mask: 0xf }));
+/// assert_eq!(GF16(3).try_div(GF16(0)), Err(FieldError::DivisionByZero));
+/// assert_eq!("3x".parse::<GF16>(), Err(FieldError::Parse { pos: 1 }));
+/// assert_eq!(DynGf::new(8, 0x100).unwrap_err(), FieldError::ReducibleModulus { modulus: 0x100 });
+/// assert_eq!(FieldError::DivisionByZero.to_string(), "attempted division by zero");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum FieldError {
+    /// The degree of a field is zero or too large
+    ///
+    /// The largest degree depends on the type, e.g. 20 for [`DynGf`](crate::DynGf).
+    InvalidDegree {
+        /// The requested degree
+        degree: u32,
+    },
+    /// The modulus doesn't have the degree of the field
+    ModulusDegree {
+        /// The modulus, with bit `i` the coefficient of `x^i`
+        modulus: u64,
+        /// The degree of the field
+        degree: u32,
+    },
+    /// The modulus is not irreducible, so the quotient ring is not a field
+    ReducibleModulus {
+        /// The modulus, with bit `i` the coefficient of `x^i`
+        modulus: u64,
+    },
+    /// A value has bits set outside the mask of the field
+    OutOfRange {
+        /// The value that could not be converted
+        value: u64,
+        /// The mask of the bits used by the field
+        mask: u64,
+    },
+    /// An element was divided by zero
+    DivisionByZero,
+    /// Zero was converted to a type of nonzero elements
+    ZeroElement,
+    /// A string is not a valid element
+    Parse {
+        /// Byte offset in the input where the error was detected
+        pos: usize,
+    },
+    /// An element of a field is not in the image of the subfield it was converted to
+    NotInSubfield,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt<'a>(&self, f: &mut fmt::Formatter<'a>) -> fmt::Result {
+        match self {
+            FieldError::InvalidDegree { degree } => write!(f, "degree {} is out of range", degree),
+            FieldError::ModulusDegree { modulus, degree } => write!(f, "modulus {:#x} does not have degree {}", modulus, degree),
+            FieldError::ReducibleModulus { modulus } => write!(f, "modulus {:#x} is not irreducible", modulus),
+            FieldError::OutOfRange { value, mask } => write!(f, "{} is out of range for the mask {:#x}", value, mask),
+            FieldError::DivisionByZero => f.write_str("attempted division by zero"),
+            FieldError::ZeroElement => f.write_str("element is zero"),
+            FieldError::Parse { pos } => write!(f, "invalid field element at byte {}", pos),
+            FieldError::NotInSubfield => f.write_str("element is not in the subfield"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldError {}
+
+/// Error returned by the Reed–Solomon functions
+///
+/// ```rust
+/// use g2p::{rs_generator_matrix, RsError};
+///
+/// g2p::g2p!(GF16, 4);
+/// # fn main() {
+/// let err = rs_generator_matrix::<GF16>(4, 17).unwrap_err();
+/// assert_eq!(err, RsError::InvalidParameters { k: 4, n: 17 });
+/// assert_eq!(err.to_string(), "invalid Reed-Solomon code parameters k = 4, n = 17");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum RsError {
+    /// The message length `k` is zero or exceeds the code length `n`, or `n` exceeds the size
+    /// of the field
+    InvalidParameters {
+        /// Number of message symbols
+        k: usize,
+        /// Number of codeword symbols
+        n: usize,
+    },
+    /// The point at the given index has the same position as an earlier one
+    DuplicatePoint(usize),
+    /// There are more errors than the code can correct
+    Uncorrectable,
+}
+
+impl fmt::Display for RsError {
+    fn fmt<'a>(&self, f: &mut fmt::Formatter<'a>) -> fmt::Result {
+        match self {
+            RsError::InvalidParameters { k, n } => write!(f, "invalid Reed-Solomon code parameters k = {}, n = {}", k, n),
+            RsError::DuplicatePoint(index) => write!(f, "point {} has the same position as an earlier point", index),
+            RsError::Uncorrectable => f.write_str("too many errors to decode"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RsError {}
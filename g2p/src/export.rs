@@ -0,0 +1,161 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Export the arithmetic tables of a field as source code for other languages
+//!
+//! The tables are calculated from the field arithmetic of this crate, so firmware using them
+//! agrees with host tooling built on the same field type. There are three tables:
+//!
+//! * `EXP_TABLE[i]` is `GENERATOR^i` for `0 <= i < SIZE - 1`
+//! * `LOG_TABLE[x]` is the `i` with `EXP_TABLE[i] = x`, and 0 for `x = 0`
+//! * `INV_TABLE[x]` is the inverse of `x`, and 0 for `x = 0`
+//!
+//! The entries use the smallest unsigned type that fits an element.
+//!
+//! ```rust
+//! use g2p::export::{export_tables, ExportFormat};
+//!
+//! g2p::g2p!(GF16, 4);
+//! # fn main() {
+//! let c = export_tables::<GF16>(ExportFormat::C);
+//! assert!(c.contains("static const uint8_t EXP_TABLE[15] = {"));
+//! # }
+//! ```
+
+use std::{fmt::Write as _, fs, io, path::Path};
+
+use crate::GaloisField;
+
+/// The language of the exported tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExportFormat {
+    /// A C header with `static const` arrays of `uint8_t`, `uint16_t` or `uint32_t`
+    C,
+    /// Rust `pub const` arrays of `u8`, `u16` or `u32`, which can be `include!`d
+    Rust,
+    /// A JSON object with the keys `bits`, `modulus`, `modulus_poly`, `generator`, `exp`, `log`
+    /// and `inv`
+    Json,
+}
+
+/// Number of entries per line in C and Rust arrays
+const ENTRIES_PER_LINE: usize = 16;
+
+struct Tables {
+    exp: Vec<usize>,
+    log: Vec<usize>,
+    inv: Vec<usize>,
+}
+
+impl Tables {
+    fn new<F: GaloisField>() -> Self {
+        let order = F::SIZE - 1;
+        let mut exp = Vec::with_capacity(order);
+        let mut log = vec![0; F::SIZE];
+        let mut x = F::ONE;
+        for i in 0..order {
+            exp.push(x.as_usize());
+            log[x.as_usize()] = i;
+            x *= F::GENERATOR;
+        }
+        let mut inv = vec![0; F::SIZE];
+        for (i, &x) in exp.iter().enumerate() {
+            inv[x] = exp[(order - i) % order];
+        }
+        Tables { exp, log, inv }
+    }
+
+    fn named(&self) -> [(&'static str, &[usize]); 3] {
+        [("EXP_TABLE", &self.exp), ("LOG_TABLE", &self.log), ("INV_TABLE", &self.inv)]
+    }
+}
+
+/// The lines of the header comment
+fn header<F: GaloisField>() -> [String; 5] {
+    [
+        format!("Tables for GF(2^{}) generated by g2p", F::BITS),
+        format!("modulus: {} ({:#x})", F::MODULUS, F::MODULUS.0),
+        format!("generator: {}", F::GENERATOR.as_usize()),
+        "EXP_TABLE[i] = generator^i, LOG_TABLE[EXP_TABLE[i]] = i, INV_TABLE[x] = 1 / x".into(),
+        "LOG_TABLE[0] and INV_TABLE[0] are 0, as zero has neither".into(),
+    ]
+}
+
+fn write_entries(out: &mut String, entries: &[usize]) {
+    for line in entries.chunks(ENTRIES_PER_LINE) {
+        out.push_str("   ");
+        for x in line {
+            write!(out, " {},", x).expect("Writing to a String can't fail");
+        }
+        out.push('\n');
+    }
+}
+
+/// Generate source code declaring the `EXP_TABLE`, `LOG_TABLE` and `INV_TABLE` of `F`
+///
+/// The output starts with a comment recording the modulus and generator, except for JSON, which
+/// has them as keys instead. The tables have `SIZE - 1`, `SIZE` and `SIZE` entries, so this is
+/// only sensible for small fields.
+pub fn export_tables<F: GaloisField>(format: ExportFormat) -> String {
+    let tables = Tables::new::<F>();
+    let bits = match F::BITS {
+        0..=8 => 8,
+        9..=16 => 16,
+        _ => 32,
+    };
+    let mut out = String::new();
+    match format {
+        ExportFormat::C => {
+            out.push_str("/*\n");
+            for line in &header::<F>() {
+                writeln!(out, " * {}", line).expect("Writing to a String can't fail");
+            }
+            out.push_str(" */\n\n#include <stdint.h>\n");
+            for (name, entries) in &tables.named() {
+                writeln!(out, "\nstatic const uint{}_t {}[{}] = {{", bits, name, entries.len()).expect("Writing to a String can't fail");
+                write_entries(&mut out, entries);
+                out.push_str("};\n");
+            }
+        }
+        ExportFormat::Rust => {
+            for line in &header::<F>() {
+                writeln!(out, "// {}", line).expect("Writing to a String can't fail");
+            }
+            for (name, entries) in &tables.named() {
+                writeln!(out, "\npub const {}: [u{}; {}] = [", name, bits, entries.len()).expect("Writing to a String can't fail");
+                write_entries(&mut out, entries);
+                out.push_str("];\n");
+            }
+        }
+        ExportFormat::Json => {
+            write!(
+                out,
+                "{{\"bits\": {}, \"modulus\": {}, \"modulus_poly\": \"{}\", \"generator\": {}",
+                F::BITS,
+                F::MODULUS.0,
+                F::MODULUS,
+                F::GENERATOR.as_usize(),
+            ).expect("Writing to a String can't fail");
+            for (name, entries) in &tables.named() {
+                let key = name.trim_end_matches("_TABLE").to_ascii_lowercase();
+                write!(out, ", \"{}\": [", key).expect("Writing to a String can't fail");
+                for (i, x) in entries.iter().enumerate() {
+                    let separator = if i == 0 { "" } else { ", " };
+                    write!(out, "{}{}", separator, x).expect("Writing to a String can't fail");
+                }
+                out.push(']');
+            }
+            out.push_str("}\n");
+        }
+    }
+    out
+}
+
+/// Write the output of [`export_tables`] to a file, replacing it if it exists
+pub fn write_to<F: GaloisField, P: AsRef<Path>>(path: P, format: ExportFormat) -> io::Result<()> {
+    fs::write(path, export_tables::<F>(format))
+}
@@ -0,0 +1,256 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A field element parameterized by const generics instead of the macro
+
+use core::{fmt, iter::{Product, Sum}, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign}};
+
+use g2poly::G2Poly;
+
+use crate::{FieldError, GaloisField};
+
+/// Largest degree, the same as the largest element size of `g2p!`
+const MAX_DEGREE: u32 = 32;
+
+/// An element of GF(2^P) with the given modulus, without the `g2p!` macro
+///
+/// Multiplication is done by shift-and-reduce and inversion by exponentiation, so no tables are
+/// needed, but the operations are much slower than those of the generated types. The parameters
+/// are checked at compile time: `P` must be in the range 1 to 32 and `MODULUS` an irreducible
+/// polynomial of degree `P`. The check happens when one of the constants or operations of the
+/// type is used.
+///
+/// Like for the generated types, the value is stored as is. Only the lowest `P` bits are
+/// meaningful. Through a type alias, elements are created with [`GfElem::new`].
+///
+/// ```rust
+/// use g2p::{GaloisField, GfElem};
+///
+/// type GF256 = GfElem<8, 0x11d>;
+/// g2p::g2p!(Generated, 8, modulus: 0x11d);
+/// # fn main() {
+/// let a = GF256::new(0x53);
+/// let b = GF256::new(0xca);
+/// assert_eq!((a * b).0, u64::from((Generated(0x53) * Generated(0xca)).0));
+/// assert_eq!(a / a, GF256::ONE);
+/// assert_eq!(GF256::SIZE, 256);
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GfElem<const P: u32, const MODULUS: u64>(pub u64);
+
+impl<const P: u32, const MODULUS: u64> GfElem<P, MODULUS> {
+    /// Fails to evaluate if the parameters don't describe a field
+    const VALID: () = {
+        assert!(P >= 1 && P <= MAX_DEGREE, "GfElem: P must be in the range 1 to 32");
+        assert!(
+            matches!(G2Poly(MODULUS).degree(), Some(d) if d == P as u64),
+            "GfElem: the degree of MODULUS must be P"
+        );
+        assert!(G2Poly(MODULUS).is_irreducible(), "GfElem: MODULUS must be irreducible");
+    };
+
+    /// Mask of the bits used by the elements
+    pub const MASK: u64 = Self::new((1 << P) - 1).0;
+
+    /// The smallest generator of the multiplicative group, as chosen by `g2p!`
+    const GENERATOR_VALUE: u64 = {
+        let mut g = 1;
+        while !G2Poly(g).is_generator(G2Poly(MODULUS)) {
+            g += 1;
+        }
+        g
+    };
+
+    /// Create an element from its value, with bit `i` the coefficient of `x^i`
+    ///
+    /// Unlike the tuple constructor this also works through a type alias, and it checks the
+    /// parameters.
+    pub const fn new(value: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        Self(value)
+    }
+
+    /// Bit `i` of the value as the coefficient of `x^i`
+    const fn to_poly(self) -> G2Poly {
+        G2Poly(self.0)
+    }
+
+    /// The position of the highest set bit, i.e. the degree plus one, or 0 for zero
+    pub const fn bits(self) -> u32 {
+        64 - (self.0 & Self::MASK).leading_zeros()
+    }
+
+    /// The value as a `usize`, ignoring the unused high bits
+    pub const fn as_usize(self) -> usize {
+        (self.0 & Self::MASK) as usize
+    }
+
+    /// The number of set bits, i.e. the number of nonzero coefficients
+    pub const fn hamming_weight(self) -> u32 {
+        (self.0 & Self::MASK).count_ones()
+    }
+
+    /// The degree of the element as a polynomial, or `None` for zero
+    pub const fn leading_coefficient_degree(self) -> Option<u32> {
+        match self.bits() {
+            0 => None,
+            bits => Some(bits - 1),
+        }
+    }
+
+    /// Calculate the multiplicative inverse as `self^(2^P - 2)`
+    ///
+    /// # Panics
+    /// Panics if the element is zero.
+    #[track_caller]
+    pub fn inverse(self) -> Self {
+        if self.0 & Self::MASK == 0 {
+            panic!("GF(2^{}): attempted to invert 0", P);
+        }
+        Self(G2Poly(self.0 & Self::MASK).pow_mod(Self::MASK - 1, G2Poly(MODULUS)).0)
+    }
+
+    /// Divide by `rhs`, returning `None` if `rhs` is zero
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.try_div(rhs).ok()
+    }
+
+    /// Divide by `rhs`, returning an error if `rhs` is zero
+    pub fn try_div(self, rhs: Self) -> Result<Self, FieldError> {
+        if rhs.0 & Self::MASK == 0 {
+            Err(FieldError::DivisionByZero)
+        } else {
+            Ok(self / rhs)
+        }
+    }
+}
+
+impl<const P: u32, const MODULUS: u64> fmt::Debug for GfElem<P, MODULUS> {
+    /// The value with the field size as suffix, like `5_GF256` for the generated types
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_GF{}", self.0, Self::MASK + 1)
+    }
+}
+
+impl<const P: u32, const MODULUS: u64> fmt::Display for GfElem<P, MODULUS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const P: u32, const MODULUS: u64> Add for GfElem<P, MODULUS> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl<const P: u32, const MODULUS: u64> Sub for GfElem<P, MODULUS> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        self + rhs
+    }
+}
+
+impl<const P: u32, const MODULUS: u64> Neg for GfElem<P, MODULUS> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl<const P: u32, const MODULUS: u64> Mul for GfElem<P, MODULUS> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(G2Poly(self.0 & Self::MASK).mul_mod(G2Poly(rhs.0 & Self::MASK), G2Poly(MODULUS)).0)
+    }
+}
+
+impl<const P: u32, const MODULUS: u64> Div for GfElem<P, MODULUS> {
+    type Output = Self;
+
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    #[track_caller]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        if rhs.0 & Self::MASK == 0 {
+            panic!("GF(2^{}): attempted {} / 0", P, self.0);
+        }
+        self * rhs.inverse()
+    }
+}
+
+impl<const P: u32, const MODULUS: u64> AddAssign for GfElem<P, MODULUS> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u32, const MODULUS: u64> SubAssign for GfElem<P, MODULUS> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u32, const MODULUS: u64> MulAssign for GfElem<P, MODULUS> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u32, const MODULUS: u64> DivAssign for GfElem<P, MODULUS> {
+    #[track_caller]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const P: u32, const MODULUS: u64> Sum for GfElem<P, MODULUS> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<const P: u32, const MODULUS: u64> Product for GfElem<P, MODULUS> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl<const P: u32, const MODULUS: u64> GaloisField for GfElem<P, MODULUS> {
+    const SIZE: usize = Self::MASK as usize + 1;
+    const ZERO: Self = Self::new(0);
+    const ONE: Self = Self::new(1);
+    const GENERATOR: Self = Self::new(Self::GENERATOR_VALUE);
+    const MODULUS: G2Poly = Self::new(MODULUS).to_poly();
+    const BITS: u32 = P;
+    const BYTES: usize = (P as usize).div_ceil(8);
+
+    fn bits(self) -> u32 {
+        GfElem::bits(self)
+    }
+
+    fn as_usize(self) -> usize {
+        GfElem::as_usize(self)
+    }
+
+    fn hamming_weight(self) -> u32 {
+        GfElem::hamming_weight(self)
+    }
+
+    fn leading_coefficient_degree(self) -> Option<u32> {
+        GfElem::leading_coefficient_degree(self)
+    }
+}
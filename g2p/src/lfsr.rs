@@ -0,0 +1,174 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Linear feedback shift registers over a finite field
+
+use alloc::{vec, vec::Vec};
+
+use crate::GaloisField;
+
+/// A linear feedback shift register, whose taps and symbols are field elements
+///
+/// The register of length `L` produces the sequence `s_0, s_1, ...` starting with its initial
+/// state, and continuing with `s_t = c_1 s_(t-1) + c_2 s_(t-2) + ... + c_L s_(t-L)`. The taps
+/// `c_i` are the coefficients of the connection polynomial `1 + c_1 x + ... + c_L x^L`.
+///
+/// ```rust
+/// use g2p::{g2p, Lfsr};
+///
+/// g2p!(GF2, 1);
+/// # fn main() {
+/// // x^3 + x^2 + 1 is primitive, so all 7 nonzero states appear
+/// let lfsr = Lfsr::new(vec![GF2(0), GF2(1), GF2(1)], vec![GF2(1), GF2(0), GF2(0)]);
+/// assert_eq!(lfsr.period(100), Some(7));
+/// let bits: Vec<u8> = lfsr.take(7).map(|b| b.0).collect();
+/// assert_eq!(bits, [1, 0, 0, 1, 0, 1, 1]);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lfsr<F> {
+    /// The taps `c_1, ..., c_L`
+    taps: Vec<F>,
+    /// The next `L` symbols, oldest first
+    state: Vec<F>,
+}
+
+impl<F: GaloisField> Lfsr<F> {
+    /// Create a register from the taps `c_1, ..., c_L` and the first `L` symbols
+    ///
+    /// # Panics
+    /// Panics if the taps and the state have different lengths.
+    #[track_caller]
+    pub fn new(taps: Vec<F>, state: Vec<F>) -> Self {
+        assert_eq!(taps.len(), state.len(), "LFSR taps and state have different lengths");
+        Lfsr { taps, state }
+    }
+
+    /// Create a register from the coefficients of its connection polynomial, lowest degree
+    /// first, and the first symbols
+    ///
+    /// The polynomial is scaled so its constant term is one. The length of the register is the
+    /// number of coefficients minus one, so trailing zero coefficients make the register longer
+    /// than the degree. This matches the output of [`berlekamp_massey`].
+    ///
+    /// # Panics
+    /// Panics if the constant term is zero or missing, or if `state` doesn't have one symbol
+    /// less than the polynomial has coefficients.
+    #[track_caller]
+    pub fn from_connection_polynomial(polynomial: &[F], state: Vec<F>) -> Self {
+        let constant = match polynomial.first() {
+            Some(&c) if c != F::ZERO => c,
+            _ => panic!("connection polynomial must have a nonzero constant term"),
+        };
+        let taps = polynomial[1..].iter().map(|&c| c / constant).collect();
+        Self::new(taps, state)
+    }
+
+    /// The taps `c_1, ..., c_L`
+    pub fn taps(&self) -> &[F] {
+        &self.taps
+    }
+
+    /// The next `L` symbols that will be produced, oldest first
+    pub fn state(&self) -> &[F] {
+        &self.state
+    }
+
+    /// Produce the next symbol and advance the register
+    fn step(&mut self) -> F {
+        let len = self.state.len();
+        if len == 0 {
+            return F::ZERO;
+        }
+        let feedback = self.taps.iter().zip(self.state.iter().rev()).map(|(&c, &s)| c * s).sum();
+        let out = self.state[0];
+        self.state.rotate_left(1);
+        self.state[len - 1] = feedback;
+        out
+    }
+
+    /// The number of steps until the register returns to its current state
+    ///
+    /// Returns `None` if that doesn't happen within `max_steps` steps. That is always the case
+    /// if the state is never repeated, which can happen if the last tap is zero. A register of
+    /// length `L` repeats after at most `SIZE^L - 1` steps, so this is only feasible for small
+    /// registers.
+    pub fn period(&self, max_steps: usize) -> Option<usize> {
+        let mut lfsr = self.clone();
+        for steps in 1..=max_steps {
+            lfsr.step();
+            if lfsr.state == self.state {
+                return Some(steps);
+            }
+        }
+        None
+    }
+}
+
+impl<F: GaloisField> Iterator for Lfsr<F> {
+    type Item = F;
+
+    /// Produce the next symbol, the sequence never ends
+    fn next(&mut self) -> Option<F> {
+        Some(self.step())
+    }
+}
+
+/// Find the shortest linear feedback shift register generating `seq`
+///
+/// Returns the coefficients of the connection polynomial `1 + c_1 x + ... + c_L x^L`, lowest
+/// degree first, using the Berlekamp–Massey algorithm. The length `L` is the length of the
+/// returned vector minus one. Together with the first `L` symbols, it regenerates the sequence.
+///
+/// ```rust
+/// use g2p::{berlekamp_massey, g2p, GaloisField, Lfsr};
+///
+/// g2p!(GF256, 8);
+/// # fn main() {
+/// let seq: Vec<GF256> = (0..10).map(|i| GF256(3).pow(i) + GF256(7).pow(i)).collect();
+/// let polynomial = berlekamp_massey(&seq);
+/// assert_eq!(polynomial.len(), 3);
+/// let lfsr = Lfsr::from_connection_polynomial(&polynomial, seq[..2].to_vec());
+/// assert!(lfsr.take(10).eq(seq.iter().copied()));
+/// # }
+/// ```
+pub fn berlekamp_massey<F: GaloisField>(seq: &[F]) -> Vec<F> {
+    // The current connection polynomial and the one before the last length change
+    let mut c = vec![F::ONE];
+    let mut b = vec![F::ONE];
+    let mut len = 0;
+    let mut shift = 1;
+    let mut last_discrepancy = F::ONE;
+
+    for n in 0..seq.len() {
+        let discrepancy = seq[n] + (1..=len).map(|i| c[i] * seq[n - i]).sum::<F>();
+        if discrepancy == F::ZERO {
+            shift += 1;
+            continue;
+        }
+
+        let factor = discrepancy / last_discrepancy;
+        let previous = c.clone();
+        if c.len() < b.len() + shift {
+            c.resize(b.len() + shift, F::ZERO);
+        }
+        for (i, &x) in b.iter().enumerate() {
+            c[i + shift] += factor * x;
+        }
+
+        if 2 * len <= n {
+            len = n + 1 - len;
+            b = previous;
+            last_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+    }
+
+    c.resize(len + 1, F::ZERO);
+    c
+}
@@ -27,6 +27,18 @@
 //! for the multiplication table. This means it is not feasible to use this to generate fields of
 //! size 2^32, which would 4*4GB memory.
 //!
+//! With `tables: none`, no tables are generated at all. Multiplication then runs a
+//! shift-and-reduce loop and division multiplies with the inverse from the Itoh–Tsujii
+//! algorithm, which needs only O(log p) multiplications. This makes fields up to 2^32 usable:
+//!
+//! ```rust
+//! g2p::g2p!(GF4G, 32, tables: none);
+//! # fn main() {
+//! let a = GF4G(0xdead_beef);
+//! assert_eq!(a / a, GF4G(1));
+//! # }
+//! ```
+//!
 //! # Examples
 //!
 //! ```rust
@@ -36,14 +48,111 @@
 //! let a: GF16 = 5.into();
 //! let b: GF16 = 4.into();
 //! let c: GF16 = 7.into();
-//! assert_eq!(a + c, 2.into());
-//! assert_eq!(a - c, 2.into());
+//! assert_eq!(a + c, 2);
+//! assert_eq!(a - c, 2);
 //! assert_eq!(a * b, c);
 //! assert_eq!(a / c, one / b);
 //! assert_eq!(b / b, one);
 //! # }
 //! ```
 //!
+//! The modulus can also be chosen as the [Conway polynomial](g2poly::conway) of the field degree,
+//! which is what computer algebra systems such as GAP or Sage use by default.
+//!
+//! ```rust
+//! g2p::g2p!(GF256, 8, modulus: conway);
+//! # fn main() {
+//! assert_eq!(GF256::MODULUS, g2p::G2Poly(0b1_0001_1101));
+//! # }
+//! ```
+//!
+//! Without a modulus, the smallest primitive polynomial of degree p is used, and `x` is the
+//! generator. The choice is available as constants on the generated type:
+//!
+//! ```rust
+//! g2p::g2p!(GF256, 8);
+//! # fn main() {
+//! assert_eq!(GF256::MODULUS, g2p::G2Poly(0x11d));
+//! assert_eq!(GF256::MODULUS_POLY_STR, "x^8 + x^4 + x^3 + x^2 + 1");
+//! assert_eq!(GF256::GENERATOR, GF256(2));
+//! # }
+//! ```
+//!
+//! If a modulus is given, the power p can be left out, as it is the degree of the modulus:
+//!
+//! ```rust
+//! g2p::g2p!(GF256, modulus: 0b1_0001_1011);
+//! # fn main() {
+//! use g2p::GaloisField;
+//! assert_eq!(GF256::SIZE, 256);
+//! # }
+//! ```
+//!
+//! Next to the field type, the macro generates a wrapper for non-zero elements, named like
+//! `NonZeroU8`. Dividing by it or inverting it never panics:
+//!
+//! ```rust
+//! g2p::g2p!(GF256, 8);
+//! # fn main() {
+//! let d = NonZeroGF256::new(GF256(7)).expect("Not zero");
+//! assert_eq!(GF256(21) / d, GF256(21) / GF256(7));
+//! assert_eq!(d.inverse().get() * d.get(), GF256(1));
+//! assert_eq!(NonZeroGF256::new(GF256(0)), None);
+//! # }
+//! ```
+//!
+//! If the modulus is only known at runtime, e.g. from a file header, [`DynGf`] builds the tables
+//! on the heap instead. Its elements support the same operators. This requires the `alloc`
+//! feature.
+//!
+//! For a fixed field without the procedural macro, e.g. in a library that only needs correct
+//! results, [`GfElem<P, MODULUS>`](GfElem) implements the same operators and [`GaloisField`]
+//! without any tables, at the cost of speed.
+//!
+//! [`verify!`] checks the constants of a field at compile time, e.g. after editing a modulus by
+//! hand. It doesn't check the tables, which the `self_test` option of the macro covers.
+//!
+//! # Formatting and parsing
+//! `Display` prints the plain decimal value of an element, while `Debug` adds the type name, e.g.
+//! `5_GF256`. `FromStr` accepts both forms, and `from_str_radix` parses the value in other radixes,
+//! e.g. `GF65536::from_str_radix("0xbeef", 16)`. The `display` option of the macro changes the
+//! `Display` output. The alternate `Debug` form `{:#?}` prints zero-padded hex with one digit per
+//! four bits of the field, so tables of elements line up, and `{:x}` prints plain hex. The
+//! alternate `Display` form `{:#}` prints the polynomial that the bits represent, regardless of the
+//! `display` option.
+//!
+//! ```rust
+//! g2p::g2p!(GF256, 8);
+//! # fn main() {
+//! let a = GF256(5);
+//! assert_eq!(format!("{}", a), "5");
+//! assert_eq!(format!("{:?}", a), "5_GF256");
+//! assert_eq!(format!("{:#?}", a), "0x05");
+//! assert_eq!(format!("{:#}", GF256(0b1101_0001)), "x^7 + x^6 + x^4 + 1");
+//! assert_eq!("5".parse(), Ok(a));
+//! assert_eq!("5_GF256".parse(), Ok(a));
+//! # }
+//! ```
+//!
+//! **Migrating:** Earlier versions printed `5_GF256` for both `Display` and `Debug`. To keep
+//! the old `Display` output, pass `display: "{value}_{name}"` to the macro.
+//!
+//! With the `serde` feature, elements are serialized as a decimal string in human-readable
+//! formats like JSON and as the integer in binary formats like bincode. The `serde_repr: number`
+//! and `serde_repr: string` options of the macro select one of them for all formats. In
+//! human-readable formats, the default also deserializes plain integers, which earlier versions
+//! wrote.
+//!
+//! # `no_std`
+//! The crate and the generated code only depend on `core` when the default `std` feature is
+//! disabled. The `alloc` feature adds the functions that return a `Vec`, like `read_slice`, and
+//! `std` adds the implementations of `std::error::Error` and the [`export`] module. The optional
+//! `rand`, `serde`, `num-traits`, `subtle`, `zeroize`, `defmt`, `reed-solomon-erasure`, `shamir`,
+//! `aes` and `common-fields` features don't require `std`. The `rayon` feature requires `std` and
+//! adds parallel versions of the slice kernels, like `par_mul_add_slice`. The `portable-simd`
+//! feature vectorizes the byte field kernels in [`simd`] with `core::simd`. It needs a nightly
+//! compiler and is ignored on stable, where the kernels are plain loops.
+//!
 //! # Implementation details
 //! `g2p` generates a new type that implements all the common arithmetic operations. The
 //! calculations are performed on either u8, u16 or u32, depending on the field size.
@@ -67,16 +176,228 @@
 //! a table for first component times first component, first times second etc. The results then just
 //! have to be added together using the normal finite field addition. For our GF65536 example this
 //! means the multiplication tables use 4 * 256 * 256 entries á 2 byte which is ~0.5MB
+//!
+//! ## Inverse table cache
+//! The inverse table is computed by the macro itself, which takes noticeable time for large
+//! fields on every build. If the environment variable `G2P_TABLE_CACHE` is set to a directory,
+//! the macro stores the tables there and reads them back in later builds. The files are keyed by
+//! the degree, modulus, generator and `tables` option of the field. Unreadable or outdated files
+//! are ignored and the table is computed again, so the cache doesn't change the result.
+//!
+//! ## Without the procedural macro
+//! The code generation lives in the `g2p-gen` crate, which the macro only wraps. Its `generate`
+//! function renders the same code as a string, so a build script can write it to `OUT_DIR` for
+//! the crate to `include!`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(g2p_portable_simd, feature(portable_simd))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+// Allows the generated code to refer to `::g2p` inside this crate
+extern crate self as g2p;
 
 use core::{iter::{Product, Sum}, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign}};
 
 /// Procedural macro to generate binary galois fields
 pub use g2gen::g2p;
 
+#[cfg(feature = "aes")]
+pub mod aes;
+#[cfg(feature = "common-fields")]
+pub mod common;
+#[cfg(feature = "alloc")]
+mod dyn_gf;
+mod error;
+#[cfg(feature = "std")]
+pub mod export;
+mod gf_elem;
+#[cfg(feature = "alloc")]
+mod lfsr;
+#[cfg(feature = "alloc")]
+mod linearized;
+#[cfg(feature = "alloc")]
+mod matrix;
+#[cfg(feature = "rayon")]
+mod par;
+mod parse;
+mod quadratic;
+#[cfg(feature = "alloc")]
+mod rs;
+#[cfg(feature = "shamir")]
+pub mod shamir;
+pub mod simd;
+mod slice;
+mod subfield;
+mod tables;
+mod verify;
+
 /// Polynomial representation of values
 pub use g2poly::G2Poly;
 
+#[cfg(feature = "alloc")]
+pub use dyn_gf::{DynGf, DynGfElem};
+pub use error::{DecodeError, FieldError, RsError};
+pub use gf_elem::GfElem;
+#[cfg(feature = "alloc")]
+pub use lfsr::{berlekamp_massey, Lfsr};
+#[cfg(feature = "alloc")]
+pub use linearized::LinearizedPoly;
+#[cfg(feature = "alloc")]
+pub use matrix::Matrix;
+#[cfg(feature = "rayon")]
+pub use par::{par_mul_add_slice, par_scale_slice};
+pub use quadratic::solve_quadratic;
+#[cfg(feature = "alloc")]
+pub use rs::{decode_berlekamp_welch, rs_generator_matrix};
+pub use slice::{dot, dot_acc, mul_add_slice, scale_slice};
+
+/// Distribution sampling uniformly from the nonzero elements of a field
+///
+/// Generated types implement it if they implement `rand::distributions::Standard`. Zero is
+/// rejected and sampled again, which keeps the distribution uniform over the `SIZE - 1`
+/// nonzero elements.
+///
+/// ```rust
+/// use g2p::{GaloisField, NonZeroStandard};
+/// use rand::Rng;
+///
+/// g2p::g2p!(GF2, 1);
+/// # fn main() {
+/// let mut rng = rand::thread_rng();
+/// let x: GF2 = rng.sample(NonZeroStandard);
+/// assert_eq!(x, GF2::ONE);
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NonZeroStandard;
+
+/// Sampler for ranges of elements, used by the `SampleUniform` implementation of generated types
+///
+/// Ranges are taken over the integer order of the values, so `GF256(1)..GF256(100)` contains the
+/// elements with the values 1 to 99. The sampling is delegated to the sampler of the base type
+/// `T`.
+///
+/// ```rust
+/// use rand::Rng;
+///
+/// g2p::g2p!(GF256, 8);
+/// # fn main() {
+/// let mut rng = rand::thread_rng();
+/// let x = rng.gen_range(GF256(1)..GF256(100));
+/// assert!((1..100).contains(&x.0));
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+#[derive(Debug, Clone, Copy)]
+pub struct UniformElement<F, T: rand::distributions::uniform::SampleUniform> {
+    inner: T::Sampler,
+    field: core::marker::PhantomData<F>,
+}
+
+#[cfg(feature = "rand")]
+impl<F, T> rand::distributions::uniform::UniformSampler for UniformElement<F, T>
+where
+    F: Copy + From<T>,
+    T: rand::distributions::uniform::SampleUniform + From<F>,
+{
+    type X = F;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: rand::distributions::uniform::SampleBorrow<F> + Sized,
+        B2: rand::distributions::uniform::SampleBorrow<F> + Sized,
+    {
+        UniformElement {
+            inner: T::Sampler::new(T::from(*low.borrow()), T::from(*high.borrow())),
+            field: core::marker::PhantomData,
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: rand::distributions::uniform::SampleBorrow<F> + Sized,
+        B2: rand::distributions::uniform::SampleBorrow<F> + Sized,
+    {
+        UniformElement {
+            inner: T::Sampler::new_inclusive(T::from(*low.borrow()), T::from(*high.borrow())),
+            field: core::marker::PhantomData,
+        }
+    }
+
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> F {
+        F::from(self.inner.sample(rng))
+    }
+}
+
+/// Dependencies used by the generated code, not public API
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::parse::{parse_element, parse_radix};
+    pub use crate::slice::{
+        mul_add_slice_base, mul_add_slice_bytes, mul_slice_base, mul_slice_bytes, scale_slice_bytes,
+    };
+    pub use crate::subfield::Embedding;
+    pub use crate::tables::{mul_table_u16, mul_table_u32, mul_table_u8};
+    #[cfg(feature = "alloc")]
+    pub use alloc::vec::Vec;
+    #[cfg(feature = "rand")]
+    pub use rand;
+    #[cfg(feature = "serde")]
+    pub use serde;
+    #[cfg(feature = "num-traits")]
+    pub use num_traits;
+    #[cfg(feature = "subtle")]
+    pub use subtle;
+    #[cfg(feature = "zeroize")]
+    pub use zeroize;
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+    #[cfg(feature = "reed-solomon-erasure")]
+    pub use reed_solomon_erasure;
+
+    use crate::GaloisField;
+
+    /// Panic for a division by zero, shared by all generated types
+    #[cold]
+    #[inline(never)]
+    #[track_caller]
+    pub fn div_by_zero(name: &str, numerator: u64) -> ! {
+        panic!("{}: attempted {} / 0", name, numerator)
+    }
+
+    /// Reinterpret a vector of a generated field type as one of its base type, or the reverse
+    ///
+    /// The allocation is kept as is, so the length and capacity don't change. Only the generated
+    /// code calls this, with a field type and the base type it wraps, for fields where every
+    /// value of the base type is an element. It is safe to call so that crates which forbid
+    /// unsafe code can still expand the macro.
+    #[cfg(feature = "alloc")]
+    pub fn cast_vec<T, U>(v: Vec<T>) -> Vec<U> {
+        assert!(
+            core::mem::size_of::<T>() == core::mem::size_of::<U>()
+                && core::mem::align_of::<T>() == core::mem::align_of::<U>()
+        );
+        let mut v = core::mem::ManuallyDrop::new(v);
+        let (ptr, len, capacity) = (v.as_mut_ptr(), v.len(), v.capacity());
+        // SAFETY: the generated types are `#[repr(transparent)]` over their base type, so `T`
+        // and `U` have the same size, alignment and layout, which the assert double checks.
+        // The callers only use this for fields that use every bit of the base type, so each
+        // value of `T` is a valid `U`. The allocation was made for `len` and `capacity` values
+        // with this layout, and `v` doesn't free it as it is never dropped.
+        unsafe { Vec::from_raw_parts(ptr.cast::<U>(), len, capacity) }
+    }
+
+    /// Calculate `x^exp`, reducing the exponent modulo the order of the multiplicative group
+    pub fn pow_reduced<F: GaloisField>(x: F, exp: u64) -> F {
+        if x == F::ZERO {
+            return if exp == 0 { F::ONE } else { F::ZERO };
+        }
+        x.pow((exp % (F::SIZE as u64 - 1)) as usize)
+    }
+}
+
 /// Common trait for finite fields
 ///
 /// All types generated by `g2p!` implement this trait.
@@ -126,6 +447,197 @@ Add<Output=Self>
     /// Polynomial representation of the modulus used to generate the field
     const MODULUS: G2Poly;
 
+    /// The characteristic of the field, always 2
+    const CHAR: u32 = 2;
+
+    /// The number of bits p of an element, where `SIZE = 2^p`
+    const BITS: u32;
+
+    /// The number of bytes needed to store an element
+    const BYTES: usize;
+
+    /// The position of the highest set bit of the representation, or 0 for zero
+    ///
+    /// This is the degree of the element as a polynomial plus one, which is useful to track
+    /// progress in Euclid-style algorithms.
+    ///
+    /// ```rust
+    /// use g2p::{GaloisField, g2p};
+    ///
+    /// g2p!(GF16, 4);
+    /// # fn main() {
+    /// assert_eq!(GF16::ZERO.bits(), 0);
+    /// assert_eq!(GF16(0b101).bits(), 3);
+    /// # }
+    /// ```
+    fn bits(self) -> u32;
+
+    /// The value of the element as `usize`, e.g. to index a table
+    ///
+    /// ```rust
+    /// use g2p::{GaloisField, g2p};
+    ///
+    /// g2p!(GF16, 4);
+    /// # fn main() {
+    /// let weights = [0.5; 16];
+    /// assert_eq!(weights[GF16(7).as_usize()], 0.5);
+    /// # }
+    /// ```
+    fn as_usize(self) -> usize;
+
+    /// The number of set bits of the representation, i.e. the number of nonzero coefficients
+    ///
+    /// ```rust
+    /// use g2p::{GaloisField, g2p};
+    ///
+    /// g2p!(GF16, 4);
+    /// # fn main() {
+    /// assert_eq!(GF16::ZERO.hamming_weight(), 0);
+    /// assert_eq!(GF16(0b1011).hamming_weight(), 3);
+    /// # }
+    /// ```
+    fn hamming_weight(self) -> u32 {
+        self.as_usize().count_ones()
+    }
+
+    /// The degree of the element as a polynomial, or `None` for zero
+    ///
+    /// ```rust
+    /// use g2p::{GaloisField, g2p};
+    ///
+    /// g2p!(GF16, 4);
+    /// # fn main() {
+    /// assert_eq!(GF16::ZERO.leading_coefficient_degree(), None);
+    /// assert_eq!(GF16(0b101).leading_coefficient_degree(), Some(2));
+    /// # }
+    /// ```
+    fn leading_coefficient_degree(self) -> Option<u32> {
+        self.bits().checked_sub(1)
+    }
+
+    /// The absolute trace `x + x^2 + x^4 + ... + x^(2^(p-1))`, returned as `true` for one
+    ///
+    /// The default implementation squares `p - 1` times, the generated types use a precomputed
+    /// mask instead.
+    ///
+    /// ```rust
+    /// use g2p::{GaloisField, g2p};
+    ///
+    /// g2p!(GF16, 4);
+    /// # fn main() {
+    /// assert!(!GF16::ZERO.trace());
+    /// assert_eq!((GF16(3) + GF16(5)).trace(), GF16(3).trace() ^ GF16(5).trace());
+    /// # }
+    /// ```
+    fn trace(self) -> bool {
+        // The sum of the conjugates x^(2^i), calculated by p - 1 squarings
+        let mut x = self;
+        let mut sum = self;
+        for _ in 1..Self::BITS {
+            x *= x;
+            sum += x;
+        }
+        sum.as_usize() == 1
+    }
+
+    /// Solve `x^2 + x = a`, returning the two solutions `x` and `x + 1`
+    ///
+    /// Returns `None` if the trace of `a` is one, as there is no solution then. The default
+    /// implementation calculates the solution with `O(p)` multiplications, the generated types
+    /// precompute its constants.
+    ///
+    /// ```rust
+    /// use g2p::{GaloisField, g2p};
+    ///
+    /// g2p!(GF256, 8);
+    /// # fn main() {
+    /// let (x, y) = GF256::solve_artin_schreier(GF256(6)).expect("Trace zero");
+    /// assert_eq!(x * x + x, GF256(6));
+    /// assert_eq!(y, x + GF256::ONE);
+    /// # }
+    /// ```
+    fn solve_artin_schreier(a: Self) -> Option<(Self, Self)> {
+        if a.trace() {
+            return None;
+        }
+        let x = if Self::BITS % 2 == 1 {
+            // The half-trace
+            let mut power = a;
+            let mut sum = a;
+            for _ in 0..(Self::BITS - 1) / 2 {
+                power = power * power * power * power;
+                sum += power;
+            }
+            sum
+        } else {
+            // sum_(i < j) d^(2^j) a^(2^i) for some d of trace one
+            let generator = Self::GENERATOR;
+            let delta = core::iter::successors(Some(generator), |&d| Some(d * generator))
+                .find(|d| d.trace())
+                .expect("Trace is not identically zero");
+            let (mut a_power, mut d_power) = (a, delta);
+            let mut partial = Self::ZERO;
+            let mut x = Self::ZERO;
+            for _ in 1..Self::BITS {
+                partial += a_power;
+                a_power *= a_power;
+                d_power *= d_power;
+                x += d_power * partial;
+            }
+            x
+        };
+        Some((x, x + Self::ONE))
+    }
+
+    /// Calculate `self^(2^k)` by repeated squaring
+    ///
+    /// ```rust
+    /// use g2p::{GaloisField, g2p};
+    ///
+    /// g2p!(GF16, 4);
+    /// # fn main() {
+    /// assert_eq!(GaloisField::pow2k(GF16(7), 2), GF16(7).pow(4));
+    /// assert_eq!(GaloisField::pow2k(GF16(7), 4), GF16(7));
+    /// # }
+    /// ```
+    fn pow2k(self, k: u32) -> Self {
+        // x^(2^p) = x, so only k mod p squarings are needed
+        let mut x = self;
+        for _ in 0..k % Self::BITS {
+            x *= x;
+        }
+        x
+    }
+
+    /// The square root, which is unique as squaring is a bijection in characteristic 2
+    ///
+    /// ```rust
+    /// use g2p::{GaloisField, g2p};
+    ///
+    /// g2p!(GF16, 4);
+    /// # fn main() {
+    /// assert_eq!(GF16(7).sqrt() * GF16(7).sqrt(), GF16(7));
+    /// # }
+    /// ```
+    fn sqrt(self) -> Self {
+        // x^(2^p) = x, so x^(2^(p-1)) squares to x
+        self.pow(Self::SIZE / 2)
+    }
+
+    /// Multiply every element of `data` by `c` in place, see [`scale_slice`]
+    ///
+    /// The types generated by `g2p!` with at most 8 bits override this with the nibble table
+    /// kernel of [`simd`].
+    fn scale_slice(c: Self, data: &mut [Self]) {
+        if c == Self::ZERO {
+            data.fill(Self::ZERO);
+        } else if c != Self::ONE {
+            for x in data {
+                *x *= c;
+            }
+        }
+    }
+
     /// Calculate the p-th power of a value
     ///
     /// Calculate the value of x to the power p in finite field arithmethic
@@ -139,14 +651,14 @@ Add<Output=Self>
     /// let g: GF16 = 2.into();
     /// assert_eq!(g.pow(0), GF16::ONE);
     /// assert_eq!(g.pow(1), g);
-    /// assert_eq!(g.pow(2), 4.into());
-    /// assert_eq!(g.pow(3), 8.into());
-    /// assert_eq!(g.pow(4), 3.into());
+    /// assert_eq!(g.pow(2), 4);
+    /// assert_eq!(g.pow(3), 8);
+    /// assert_eq!(g.pow(4), 3);
     /// # }
     /// ```
     fn pow(self, p: usize) -> Self {
         let mut val = Self::ONE;
-        let mut pow_pos = 1 << (::std::mem::size_of::<usize>() * 8 - 1);
+        let mut pow_pos = 1 << (::core::mem::size_of::<usize>() * 8 - 1);
         assert_eq!(pow_pos << 1, 0);
         while pow_pos > 0 {
             val *= val;
@@ -157,4 +669,37 @@ Add<Output=Self>
         }
         val
     }
+
+    /// Calculate the power of a value with a possibly negative exponent
+    ///
+    /// The exponent is reduced modulo `SIZE - 1`, the order of the multiplicative group, so
+    /// `x.pow_signed(-1)` is the inverse of `x`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero and the exponent is negative, as zero has no inverse.
+    ///
+    /// # Example
+    /// ```rust
+    /// use g2p::{GaloisField, g2p};
+    ///
+    /// g2p!(GF16, 4);
+    /// # fn main() {
+    /// let g: GF16 = 2.into();
+    /// assert_eq!(g.pow_signed(-1), GF16::ONE / g);
+    /// assert_eq!(g.pow_signed(-15), GF16::ONE);
+    /// assert_eq!(g.pow_signed(-3) * g.pow_signed(3), GF16::ONE);
+    /// # }
+    /// ```
+    #[track_caller]
+    fn pow_signed(self, exp: i64) -> Self {
+        if self == Self::ZERO {
+            return match exp {
+                0 => Self::ONE,
+                1..=i64::MAX => Self::ZERO,
+                _ => panic!("attempted to raise 0 to the negative power {}", exp),
+            };
+        }
+        let order = (Self::SIZE - 1) as i64;
+        self.pow(exp.rem_euclid(order) as usize)
+    }
 }
@@ -0,0 +1,169 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Linearized polynomials over a finite field
+
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul};
+
+use crate::GaloisField;
+
+/// A linearized polynomial `L(x) = c_0 x + c_1 x^2 + c_2 x^4 + ... + c_i x^(2^i)`
+///
+/// As squaring is additive in characteristic 2, these are exactly the polynomials with
+/// `L(x + y) = L(x) + L(y)`. They map the field to itself GF(2)-linearly, and the product that
+/// matters for them is composition.
+///
+/// ```rust
+/// use g2p::{g2p, GaloisField, LinearizedPoly};
+///
+/// g2p!(GF256, 8);
+/// # fn main() {
+/// // L(x) = x^2 + x, whose kernel is GF(2)
+/// let l = LinearizedPoly::new(vec![GF256::ONE, GF256::ONE]);
+/// assert_eq!(l.evaluate(GF256(3)), GF256(3) * GF256(3) + GF256(3));
+/// assert_eq!(l.kernel(), [GF256::ONE]);
+/// assert_eq!(l.rank(), 7);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LinearizedPoly<F> {
+    /// The coefficients `c_0, c_1, ...` without trailing zeros
+    coefficients: Vec<F>,
+}
+
+impl<F: GaloisField> LinearizedPoly<F> {
+    /// Create the polynomial `sum_i coefficients[i] x^(2^i)`
+    pub fn new(mut coefficients: Vec<F>) -> Self {
+        while coefficients.last() == Some(&F::ZERO) {
+            coefficients.pop();
+        }
+        LinearizedPoly { coefficients }
+    }
+
+    /// The zero polynomial
+    pub fn zero() -> Self {
+        LinearizedPoly { coefficients: Vec::new() }
+    }
+
+    /// The polynomial `x`, which is the identity under composition
+    pub fn identity() -> Self {
+        LinearizedPoly { coefficients: vec![F::ONE] }
+    }
+
+    /// The coefficients `c_0, c_1, ...` of `x, x^2, x^4, ...` without trailing zeros
+    pub fn coefficients(&self) -> &[F] {
+        &self.coefficients
+    }
+
+    /// The highest `i` with a nonzero coefficient of `x^(2^i)`, or `None` for zero
+    pub fn q_degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// Evaluate the polynomial at `x`
+    pub fn evaluate(&self, x: F) -> F {
+        self.coefficients.iter().enumerate().map(|(i, &c)| c * x.pow2k(i as u32)).sum()
+    }
+
+    /// The symbolic composition `self(other(x))`
+    ///
+    /// This is `sum_(i,j) a_i b_j^(2^i) x^(2^(i+j))`. Composition is associative and
+    /// distributes over addition, but is not commutative.
+    pub fn compose(&self, other: &Self) -> Self {
+        if self.coefficients.is_empty() || other.coefficients.is_empty() {
+            return Self::zero();
+        }
+        let mut res = vec![F::ZERO; self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (j, &b) in other.coefficients.iter().enumerate() {
+                res[i + j] += a * b.pow2k(i as u32);
+            }
+        }
+        Self::new(res)
+    }
+
+    /// The coefficients of the ordinary polynomial, lowest degree first
+    ///
+    /// The degree is `2^q_degree`, so the vector gets long quickly.
+    pub fn to_ordinary(&self) -> Vec<F> {
+        let len = match self.q_degree() {
+            Some(d) => (1 << d) + 1,
+            None => return Vec::new(),
+        };
+        let mut res = vec![F::ZERO; len];
+        for (i, &c) in self.coefficients.iter().enumerate() {
+            res[1 << i] = c;
+        }
+        res
+    }
+
+    /// The rank of the polynomial as a GF(2)-linear map on the field
+    pub fn rank(&self) -> usize {
+        F::BITS as usize - self.kernel().len()
+    }
+
+    /// A GF(2)-basis of the kernel, the elements with `L(x) = 0`
+    ///
+    /// The kernel has `2^kernel().len()` elements. It is calculated by Gaussian elimination on the
+    /// bit matrix of the map, taking the images of the basis `1, g, g^2, ..., g^(p-1)` built from
+    /// the generator, which works for any field.
+    pub fn kernel(&self) -> Vec<F> {
+        // Reduced images indexed by their highest bit, together with an element mapping to them
+        let mut pivots: Vec<Option<(usize, F)>> = vec![None; F::BITS as usize];
+        let mut kernel = Vec::new();
+        let mut basis = F::ONE;
+        for _ in 0..F::BITS {
+            let mut image = self.evaluate(basis).as_usize();
+            let mut preimage = basis;
+            while image != 0 {
+                let top = (usize::BITS - 1 - image.leading_zeros()) as usize;
+                match pivots[top] {
+                    Some((pivot_image, pivot_preimage)) => {
+                        image ^= pivot_image;
+                        preimage += pivot_preimage;
+                    }
+                    None => {
+                        pivots[top] = Some((image, preimage));
+                        break;
+                    }
+                }
+            }
+            if image == 0 {
+                kernel.push(preimage);
+            }
+            basis *= F::GENERATOR;
+        }
+        kernel
+    }
+}
+
+impl<F: GaloisField> Add for &LinearizedPoly<F> {
+    type Output = LinearizedPoly<F>;
+
+    /// The sum, which evaluates to `self(x) + rhs(x)`
+    fn add(self, rhs: Self) -> LinearizedPoly<F> {
+        let (long, short) = if self.coefficients.len() >= rhs.coefficients.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut res = long.coefficients.clone();
+        for (acc, &c) in res.iter_mut().zip(&short.coefficients) {
+            *acc += c;
+        }
+        LinearizedPoly::new(res)
+    }
+}
+
+impl<F: GaloisField> Mul for &LinearizedPoly<F> {
+    type Output = LinearizedPoly<F>;
+
+    /// The composition `self(rhs(x))`, see [`LinearizedPoly::compose`]
+    fn mul(self, rhs: Self) -> LinearizedPoly<F> {
+        self.compose(rhs)
+    }
+}
@@ -0,0 +1,243 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Dense matrices over a finite field
+
+use alloc::{vec, vec::Vec};
+use core::ops::{Index, IndexMut, Mul};
+
+use crate::{dot, scale_slice, GaloisField};
+
+/// A dense matrix over the field `F`, stored row by row
+///
+/// ```rust
+/// use g2p::{g2p, Matrix};
+///
+/// g2p!(GF16, 4);
+/// # fn main() {
+/// let a = Matrix::from_vec(2, 2, vec![GF16(1), GF16(2), GF16(3), GF16(4)]);
+/// let inv = a.inverse().expect("Invertible");
+/// assert_eq!(&a * &inv, Matrix::identity(2));
+/// assert_eq!(a[(1, 0)], GF16(3));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<F> {
+    rows: usize,
+    cols: usize,
+    data: Vec<F>,
+}
+
+impl<F: GaloisField> Matrix<F> {
+    /// The `rows x cols` matrix with all entries zero
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        Matrix { rows, cols, data: vec![F::ZERO; rows * cols] }
+    }
+
+    /// The `n x n` identity matrix
+    pub fn identity(n: usize) -> Self {
+        let mut res = Self::zero(n, n);
+        for i in 0..n {
+            res[(i, i)] = F::ONE;
+        }
+        res
+    }
+
+    /// Build a matrix from its entries, listed row by row
+    ///
+    /// # Panics
+    /// Panics if `data` doesn't have `rows * cols` entries.
+    #[track_caller]
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<F>) -> Self {
+        assert_eq!(data.len(), rows * cols, "matrix data doesn't match the dimensions");
+        Matrix { rows, cols, data }
+    }
+
+    /// The number of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The entries of row `r`
+    pub fn row(&self, r: usize) -> &[F] {
+        &self.data[r * self.cols..(r + 1) * self.cols]
+    }
+
+    /// The mutable entries of row `r`
+    pub fn row_mut(&mut self, r: usize) -> &mut [F] {
+        &mut self.data[r * self.cols..(r + 1) * self.cols]
+    }
+
+    /// The transposed matrix
+    pub fn transpose(&self) -> Self {
+        let mut res = Self::zero(self.cols, self.rows);
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                res[(c, r)] = self[(r, c)];
+            }
+        }
+        res
+    }
+
+    /// The matrix made of the given columns, in the given order
+    ///
+    /// # Panics
+    /// Panics if a column is out of range.
+    pub fn select_columns(&self, columns: &[usize]) -> Self {
+        let mut res = Self::zero(self.rows, columns.len());
+        for r in 0..self.rows {
+            for (i, &c) in columns.iter().enumerate() {
+                res[(r, i)] = self[(r, c)];
+            }
+        }
+        res
+    }
+
+    /// Multiply the row vector `v` by the matrix, i.e. calculate `v * self`
+    ///
+    /// # Panics
+    /// Panics if `v` doesn't have one entry per row.
+    #[track_caller]
+    pub fn mul_row_vector(&self, v: &[F]) -> Vec<F> {
+        assert_eq!(v.len(), self.rows, "vector length doesn't match the number of rows");
+        let mut res = vec![F::ZERO; self.cols];
+        for (r, &factor) in v.iter().enumerate() {
+            for (acc, &x) in res.iter_mut().zip(self.row(r)) {
+                *acc += factor * x;
+            }
+        }
+        res
+    }
+
+    /// The inverse of a square matrix, or `None` if it is singular
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    #[track_caller]
+    pub fn inverse(&self) -> Option<Self> {
+        assert_eq!(self.rows, self.cols, "only square matrices can be inverted");
+        let mut res = Self::identity(self.rows);
+        if self.clone().gauss_jordan(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solve `self * x = b` for a square matrix, or return `None` if it is singular
+    ///
+    /// ```rust
+    /// use g2p::{g2p, Matrix};
+    ///
+    /// g2p!(GF256, 8);
+    /// # fn main() {
+    /// let a = Matrix::from_vec(2, 2, vec![GF256(1), GF256(1), GF256(1), GF256(2)]);
+    /// let x = a.solve(&[GF256(3), GF256(5)]).expect("Invertible");
+    /// assert_eq!(x[0] + x[1], GF256(3));
+    /// assert_eq!(x[0] + GF256(2) * x[1], GF256(5));
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square or `b` doesn't have one entry per row.
+    #[track_caller]
+    pub fn solve(&self, b: &[F]) -> Option<Vec<F>> {
+        assert_eq!(self.rows, self.cols, "only square systems can be solved");
+        assert_eq!(b.len(), self.rows, "vector length doesn't match the number of rows");
+        let mut rhs = Matrix::from_vec(b.len(), 1, b.to_vec());
+        if self.clone().gauss_jordan(&mut rhs) {
+            Some(rhs.data)
+        } else {
+            None
+        }
+    }
+
+    /// Reduce the square matrix `self` to the identity, applying the same row operations to `rhs`
+    ///
+    /// Returns `false` if `self` is singular.
+    fn gauss_jordan(mut self, rhs: &mut Self) -> bool {
+        let n = self.rows;
+        for col in 0..n {
+            let pivot = match (col..n).find(|&r| self[(r, col)] != F::ZERO) {
+                Some(pivot) => pivot,
+                None => return false,
+            };
+            self.swap_rows(col, pivot);
+            rhs.swap_rows(col, pivot);
+
+            let factor = F::ONE / self[(col, col)];
+            scale_slice(factor, self.row_mut(col));
+            scale_slice(factor, rhs.row_mut(col));
+
+            for r in (0..n).filter(|&r| r != col) {
+                let factor = self[(r, col)];
+                if factor != F::ZERO {
+                    self.add_scaled_row(r, col, factor);
+                    rhs.add_scaled_row(r, col, factor);
+                }
+            }
+        }
+        true
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        if a != b {
+            for c in 0..self.cols {
+                self.data.swap(a * self.cols + c, b * self.cols + c);
+            }
+        }
+    }
+
+    /// Add `factor` times row `src` to row `dest`
+    fn add_scaled_row(&mut self, dest: usize, src: usize, factor: F) {
+        for c in 0..self.cols {
+            let x = self[(src, c)];
+            self[(dest, c)] += factor * x;
+        }
+    }
+}
+
+impl<F> Index<(usize, usize)> for Matrix<F> {
+    type Output = F;
+
+    /// The entry at `(row, column)`
+    fn index(&self, (r, c): (usize, usize)) -> &F {
+        assert!(r < self.rows && c < self.cols, "matrix index out of bounds");
+        &self.data[r * self.cols + c]
+    }
+}
+
+impl<F> IndexMut<(usize, usize)> for Matrix<F> {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut F {
+        assert!(r < self.rows && c < self.cols, "matrix index out of bounds");
+        &mut self.data[r * self.cols + c]
+    }
+}
+
+impl<F: GaloisField> Mul for &Matrix<F> {
+    type Output = Matrix<F>;
+
+    /// The matrix product
+    ///
+    /// # Panics
+    /// Panics if the number of columns of `self` doesn't match the number of rows of `rhs`.
+    fn mul(self, rhs: Self) -> Matrix<F> {
+        assert_eq!(self.cols, rhs.rows, "matrix dimensions don't match");
+        let rhs = rhs.transpose();
+        let mut res = Matrix::zero(self.rows, rhs.rows);
+        for r in 0..self.rows {
+            for c in 0..rhs.rows {
+                res[(r, c)] = dot(self.row(r), rhs.row(c));
+            }
+        }
+        res
+    }
+}
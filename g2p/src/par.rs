@@ -0,0 +1,59 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parallel versions of the slice kernels, using rayon
+//!
+//! The slices are split into stripes that fit into the L1 cache, and every stripe is processed
+//! by the sequential kernel. Each element only depends on the elements at the same position, so
+//! the results are identical to the sequential functions regardless of the number of threads.
+
+use core::mem::size_of;
+
+use rayon::prelude::*;
+
+use crate::{mul_add_slice, scale_slice, GaloisField};
+
+/// Size of a stripe in bytes
+const STRIPE_BYTES: usize = 16 * 1024;
+
+/// Number of elements of a stripe
+fn stripe_len<F>() -> usize {
+    (STRIPE_BYTES / size_of::<F>().max(1)).max(1)
+}
+
+/// Multiply every element of `data` by `c` in place, in parallel
+///
+/// The result is the same as that of [`scale_slice`].
+///
+/// # Example
+/// ```rust
+/// use g2p::{g2p, par_scale_slice};
+///
+/// g2p!(GF256, 8);
+/// # fn main() {
+/// let mut data = vec![GF256(3); 100_000];
+/// par_scale_slice(GF256(2), &mut data);
+/// assert!(data.iter().all(|&x| x == GF256(6)));
+/// # }
+/// ```
+pub fn par_scale_slice<F: GaloisField + Send + Sync>(c: F, data: &mut [F]) {
+    data.par_chunks_mut(stripe_len::<F>()).for_each(|stripe| scale_slice(c, stripe));
+}
+
+/// Add `c` times `src` to `dst` element-wise, in parallel
+///
+/// The result is the same as that of [`mul_add_slice`].
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+#[track_caller]
+pub fn par_mul_add_slice<F: GaloisField + Send + Sync>(c: F, src: &[F], dst: &mut [F]) {
+    assert_eq!(src.len(), dst.len(), "mul_add_slice of slices with different lengths");
+    let len = stripe_len::<F>();
+    dst.par_chunks_mut(len)
+        .zip(src.par_chunks(len))
+        .for_each(|(d, s)| mul_add_slice(c, s, d));
+}
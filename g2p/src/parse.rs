@@ -0,0 +1,107 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::FieldError;
+
+/// Parse the value of an element of the field `name`, used by the generated `FromStr`
+///
+/// Accepts both the decimal value `5` printed by `Display`, and the form `5_GF16` printed by
+/// `Debug`. Values above `mask` are rejected with [`FieldError::OutOfRange`] instead of being
+/// masked.
+pub fn parse_element(s: &str, name: &str, mask: u64) -> Result<u64, FieldError> {
+    let digits_end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    if digits_end == 0 {
+        return Err(FieldError::Parse { pos: 0 });
+    }
+
+    let suffix = &s[digits_end..];
+    if !suffix.is_empty() && suffix.strip_prefix('_') != Some(name) {
+        return Err(FieldError::Parse { pos: digits_end });
+    }
+
+    match s[..digits_end].parse::<u64>() {
+        Ok(value) if value <= mask => Ok(value),
+        Ok(value) => Err(FieldError::OutOfRange { value, mask }),
+        Err(_) => Err(FieldError::Parse { pos: 0 }),
+    }
+}
+
+/// Parse the value of an element in `radix`, used by the generated `from_str_radix`
+///
+/// Accepts a `0x` prefix for radix 16 and a `0b` prefix for radix 2. Values above `mask` are
+/// rejected with [`FieldError::OutOfRange`] instead of being masked.
+///
+/// # Panics
+/// Panics if `radix` is not in the range 2 to 36.
+pub fn parse_radix(s: &str, radix: u32, mask: u64) -> Result<u64, FieldError> {
+    assert!((2..=36).contains(&radix), "from_str_radix: radix must be in the range 2 to 36, not {}", radix);
+
+    let prefix = match radix {
+        16 => "0x",
+        2 => "0b",
+        _ => "",
+    };
+    let start = if !prefix.is_empty() && s.starts_with(prefix) { prefix.len() } else { 0 };
+    let digits = &s[start..];
+    if digits.is_empty() {
+        return Err(FieldError::Parse { pos: start });
+    }
+
+    let mut value: u64 = 0;
+    let mut overflow = false;
+    for (i, c) in digits.char_indices() {
+        let digit = c.to_digit(radix).ok_or(FieldError::Parse { pos: start + i })?;
+        match value.checked_mul(u64::from(radix)).and_then(|v| v.checked_add(u64::from(digit))) {
+            Some(v) => value = v,
+            None => overflow = true,
+        }
+    }
+    if overflow {
+        return Err(FieldError::Parse { pos: 0 });
+    }
+    if value > mask {
+        return Err(FieldError::OutOfRange { value, mask });
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_element() {
+        assert_eq!(parse_element("0", "GF16", 15), Ok(0));
+        assert_eq!(parse_element("15", "GF16", 15), Ok(15));
+        assert_eq!(parse_element("007", "GF16", 15), Ok(7));
+        assert_eq!(parse_element("5_GF16", "GF16", 15), Ok(5));
+
+        let err = |s: &str| parse_element(s, "GF16", 15).unwrap_err();
+        assert_eq!(err(""), FieldError::Parse { pos: 0 });
+        assert_eq!(err("x"), FieldError::Parse { pos: 0 });
+        assert_eq!(err("-1"), FieldError::Parse { pos: 0 });
+        assert_eq!(err("16"), FieldError::OutOfRange { value: 16, mask: 15 });
+        assert_eq!(err("99999999999999999999999"), FieldError::Parse { pos: 0 });
+        assert_eq!(err("5_GF256"), FieldError::Parse { pos: 1 });
+        assert_eq!(err("5_"), FieldError::Parse { pos: 1 });
+        assert_eq!(err("5 "), FieldError::Parse { pos: 1 });
+    }
+
+    #[test]
+    fn test_parse_radix() {
+        assert_eq!(parse_radix("ff", 16, 255), Ok(255));
+        assert_eq!(parse_radix("0xFf", 16, 255), Ok(255));
+        assert_eq!(parse_radix("0b101", 2, 255), Ok(5));
+        assert_eq!(parse_radix("z", 36, 255), Ok(35));
+
+        let err = |s: &str, radix| parse_radix(s, radix, 255).unwrap_err();
+        assert_eq!(err("0x", 16), FieldError::Parse { pos: 2 });
+        assert_eq!(err("0x1f", 10), FieldError::Parse { pos: 1 });
+        assert_eq!(err("100", 16), FieldError::OutOfRange { value: 256, mask: 255 });
+        assert_eq!(err("ffffffffffffffffffff", 16), FieldError::Parse { pos: 0 });
+        assert_eq!(err("fffffffffffffffffffg", 16), FieldError::Parse { pos: 19 });
+    }
+}
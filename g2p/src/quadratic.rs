@@ -0,0 +1,49 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Roots of quadratic polynomials
+
+use smallvec::{smallvec, SmallVec};
+
+use crate::GaloisField;
+
+/// Find the distinct roots of `a x^2 + b x + c`
+///
+/// The cases are:
+/// * `a = 0`: the linear equation `b x = c` has the single root `c / b`.
+/// * `b = 0`: `x^2 = c / a` has the double root `sqrt(c / a)`, which is returned once.
+/// * otherwise substituting `x = (b / a) y` gives `y^2 + y = a c / b^2`, which has two or no
+///   solutions depending on its trace, see [`GaloisField::solve_artin_schreier`].
+///
+/// If all coefficients are zero, every element is a root. This is not enumerated, the result
+/// is empty just as for a nonzero constant.
+///
+/// # Example
+/// ```rust
+/// use g2p::{g2p, solve_quadratic, GaloisField};
+///
+/// g2p!(GF256, 8);
+/// # fn main() {
+/// // (x + 3)(x + 5) = x^2 + 6x + 15
+/// let mut roots = solve_quadratic(GF256::ONE, GF256(6), GF256(15));
+/// roots.sort_by_key(|x| x.0);
+/// assert_eq!(roots.as_slice(), [GF256(3), GF256(5)]);
+/// assert_eq!(solve_quadratic(GF256(2), GF256::ZERO, GF256(8)).as_slice(), [GF256(2)]);
+/// # }
+/// ```
+pub fn solve_quadratic<F: GaloisField>(a: F, b: F, c: F) -> SmallVec<[F; 2]> {
+    if a == F::ZERO {
+        return if b == F::ZERO { SmallVec::new() } else { smallvec![c / b] };
+    }
+    if b == F::ZERO {
+        return smallvec![(c / a).sqrt()];
+    }
+    let scale = b / a;
+    match F::solve_artin_schreier(a * c / (b * b)) {
+        Some((y0, y1)) => smallvec![scale * y0, scale * y1],
+        None => SmallVec::new(),
+    }
+}
@@ -0,0 +1,197 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Reed–Solomon codes
+
+use alloc::vec::Vec;
+
+use crate::{GaloisField, Matrix, RsError};
+
+/// The `i`-th element in the fixed enumeration `0, 1, g, g^2, ...` of the field
+fn nth_element<F: GaloisField>(i: usize) -> F {
+    if i == 0 {
+        F::ZERO
+    } else {
+        F::GENERATOR.pow(i - 1)
+    }
+}
+
+/// The `k x n` generator matrix of a systematic Reed–Solomon code
+///
+/// A message `m` of `k` symbols is encoded as the codeword `m * G` of `n` symbols, which starts
+/// with the message itself. Any `k` symbols of the codeword are enough to recover the message,
+/// as any `k` columns of the matrix are linearly independent.
+///
+/// The matrix is `[I | C]`, where `I` is the `k x k` identity and `C` is the Cauchy matrix with
+/// `C[i][j] = 1 / (x_i + y_j)`. The points are taken from the enumeration
+/// `e_0 = 0, e_1 = 1, e_t = GENERATOR^(t - 1)` of the field as `x_i = e_i` and `y_j = e_(k + j)`.
+/// All square submatrices of a Cauchy matrix are invertible, which gives the property above.
+/// This construction will not change between versions, so codewords stay compatible.
+///
+/// Returns an error if `k` is zero, `k > n` or `n` exceeds the size of the field.
+///
+/// # Example
+/// ```rust
+/// use g2p::{g2p, rs_generator_matrix};
+///
+/// g2p!(GF256, 8);
+/// # fn main() {
+/// let g = rs_generator_matrix::<GF256>(2, 4).unwrap();
+/// let message = [GF256(7), GF256(42)];
+/// let codeword = g.mul_row_vector(&message);
+/// assert_eq!(&codeword[..2], &message);
+///
+/// // Recover the message from the parity symbols alone
+/// let lost = g.select_columns(&[2, 3]).transpose();
+/// assert_eq!(lost.solve(&codeword[2..]).unwrap(), message);
+/// # }
+/// ```
+pub fn rs_generator_matrix<F: GaloisField>(k: usize, n: usize) -> Result<Matrix<F>, RsError> {
+    if k == 0 || k > n || n > F::SIZE {
+        return Err(RsError::InvalidParameters { k, n });
+    }
+    let mut data = Vec::with_capacity(k * n);
+    for i in 0..k {
+        let x: F = nth_element(i);
+        data.extend((0..k).map(|j| if i == j { F::ONE } else { F::ZERO }));
+        data.extend((k..n).map(|j| F::ONE / (x + nth_element(j))));
+    }
+    Ok(Matrix::from_vec(k, n, data))
+}
+
+/// Evaluate the polynomial with the given coefficients, lowest degree first, at `x`
+fn evaluate<F: GaloisField>(coefficients: &[F], x: F) -> F {
+    coefficients.iter().rev().fold(F::ZERO, |acc, &c| acc * x + c)
+}
+
+/// Find any solution of the linear system `a * x = b`, or `None` if it is inconsistent
+///
+/// Unlike `Matrix::solve` the system doesn't have to be square, and free variables are set to
+/// zero.
+fn solve_any<F: GaloisField>(a: &Matrix<F>, b: &[F]) -> Option<Vec<F>> {
+    let (rows, cols) = (a.rows(), a.cols());
+    let mut aug = Matrix::zero(rows, cols + 1);
+    for r in 0..rows {
+        aug.row_mut(r)[..cols].copy_from_slice(a.row(r));
+        aug[(r, cols)] = b[r];
+    }
+
+    let mut pivots = Vec::new();
+    for c in 0..cols {
+        let r = pivots.len();
+        let pivot = match (r..rows).find(|&i| aug[(i, c)] != F::ZERO) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        for i in 0..=cols {
+            let (x, y) = (aug[(r, i)], aug[(pivot, i)]);
+            aug[(r, i)] = y;
+            aug[(pivot, i)] = x;
+        }
+        let factor = F::ONE / aug[(r, c)];
+        crate::scale_slice(factor, aug.row_mut(r));
+        for i in (0..rows).filter(|&i| i != r) {
+            let factor = aug[(i, c)];
+            if factor != F::ZERO {
+                for j in 0..=cols {
+                    let x = aug[(r, j)];
+                    aug[(i, j)] += factor * x;
+                }
+            }
+        }
+        pivots.push(c);
+    }
+
+    if (pivots.len()..rows).any(|r| aug[(r, cols)] != F::ZERO) {
+        return None;
+    }
+    let mut x = alloc::vec![F::ZERO; cols];
+    for (r, &c) in pivots.iter().enumerate() {
+        x[c] = aug[(r, cols)];
+    }
+    Some(x)
+}
+
+/// Decode a Reed–Solomon codeword given as evaluations of the message polynomial
+///
+/// `points` are pairs `(x_i, y_i)` with distinct `x_i`, where `y_i` should be `P(x_i)` for a
+/// message polynomial `P` of degree less than `k`. If at most `(n - k) / 2` of the `n` values
+/// are wrong, the `k` coefficients of `P` are returned, lowest degree first.
+///
+/// This uses the Berlekamp–Welch algorithm: It solves the linear system `y_i * E(x_i) = Q(x_i)`
+/// for a monic error locator `E` of degree `(n - k) / 2` and `Q` of degree less than
+/// `(n - k) / 2 + k`, and then divides `Q` by `E`. It is slower than syndrome decoding, but
+/// easy to follow.
+///
+/// Returns an error if `k` is zero or larger than `n`, two points share an `x` or there are too
+/// many errors to decode.
+///
+/// # Example
+/// ```rust
+/// use g2p::{decode_berlekamp_welch, g2p};
+///
+/// g2p!(GF256, 8);
+/// # fn main() {
+/// // P(x) = 3 + 5x
+/// let mut points: Vec<_> = (1..=6).map(|x| (GF256(x), GF256(3) + GF256(5) * GF256(x))).collect();
+/// points[2].1 = GF256(0);
+/// points[4].1 = GF256(1);
+/// assert_eq!(decode_berlekamp_welch(&points, 2), Ok(vec![GF256(3), GF256(5)]));
+/// # }
+/// ```
+pub fn decode_berlekamp_welch<F: GaloisField>(points: &[(F, F)], k: usize) -> Result<Vec<F>, RsError> {
+    let n = points.len();
+    if k == 0 || k > n {
+        return Err(RsError::InvalidParameters { k, n });
+    }
+    for (i, &(x, _)) in points.iter().enumerate() {
+        if points[..i].iter().any(|&(other, _)| other == x) {
+            return Err(RsError::DuplicatePoint(i));
+        }
+    }
+
+    let e = (n - k) / 2;
+    // Unknowns: the coefficients q_0, ..., q_(e + k - 1) of Q, then e_0, ..., e_(e - 1) of E
+    let unknowns = 2 * e + k;
+    let mut a = Matrix::zero(n, unknowns);
+    let mut b = Vec::with_capacity(n);
+    for (r, &(x, y)) in points.iter().enumerate() {
+        let mut power = F::ONE;
+        for j in 0..e + k {
+            a[(r, j)] = power;
+            if j < e {
+                a[(r, e + k + j)] = y * power;
+            }
+            if j + 1 < e + k {
+                power *= x;
+            }
+        }
+        b.push(y * x.pow(e));
+    }
+    let solution = solve_any(&a, &b).ok_or(RsError::Uncorrectable)?;
+
+    // Divide Q by the monic E = x^e + e_(e - 1) x^(e - 1) + ... + e_0
+    let mut remainder = solution[..e + k].to_vec();
+    let locator = &solution[e + k..];
+    let mut quotient = alloc::vec![F::ZERO; k];
+    for i in (0..k).rev() {
+        let c = remainder[i + e];
+        quotient[i] = c;
+        for (j, &l) in locator.iter().enumerate() {
+            remainder[i + j] += c * l;
+        }
+        remainder[i + e] = F::ZERO;
+    }
+    if remainder.iter().any(|&c| c != F::ZERO) {
+        return Err(RsError::Uncorrectable);
+    }
+
+    let errors = points.iter().filter(|&&(x, y)| evaluate(&quotient, x) != y).count();
+    if errors > e {
+        return Err(RsError::Uncorrectable);
+    }
+    Ok(quotient)
+}
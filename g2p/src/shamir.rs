@@ -0,0 +1,125 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Shamir's secret sharing over GF(256)
+//!
+//! Every byte of the secret is the constant term of a random polynomial of degree `k - 1` over
+//! GF(2^8) with the AES modulus `x^8 + x^4 + x^3 + x + 1`. Share `i` contains the values of
+//! these polynomials at `x = i`. Any `k` shares determine the polynomials, and with them the
+//! secret, while fewer shares reveal nothing about it.
+//!
+//! ```rust
+//! use g2p::shamir::{combine, split};
+//!
+//! let mut rng = rand::thread_rng();
+//! let shares = split(b"hunter2", 3, 5, &mut rng);
+//! let subset: Vec<(u8, &[u8])> = shares[1..4].iter().map(|(i, s)| (*i, &s[..])).collect();
+//! assert_eq!(combine(&subset).unwrap(), b"hunter2");
+//! ```
+
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use rand::Rng;
+
+crate::g2p!(GF256, modulus: 0b1_0001_1011, module: gf256, impls: []);
+
+use gf256::GF256;
+
+/// Split `secret` into `n` shares, any `k` of which can recover it
+///
+/// Returns pairs of the share index, from 1 to `n`, and the share, which has the same length as
+/// the secret. Both are needed by [`combine`].
+///
+/// # Panics
+/// Panics unless `1 <= k <= n < 255`.
+#[track_caller]
+pub fn split(secret: &[u8], k: u8, n: u8, rng: &mut impl Rng) -> Vec<(u8, Vec<u8>)> {
+    assert!(1 <= k && k <= n && n < 255, "invalid threshold k = {} for n = {} shares", k, n);
+
+    let mut shares: Vec<(u8, Vec<u8>)> = (1..=n).map(|i| (i, Vec::with_capacity(secret.len()))).collect();
+    let mut coefficients = vec![GF256(0); k as usize];
+    for &byte in secret {
+        coefficients[0] = GF256(byte);
+        for c in &mut coefficients[1..] {
+            *c = GF256(rng.gen());
+        }
+        for (i, share) in &mut shares {
+            let x = GF256(*i);
+            share.push(coefficients.iter().rev().fold(GF256(0), |acc, &c| acc * x + c).0);
+        }
+    }
+    shares
+}
+
+/// Recover the secret from shares produced by [`split`]
+///
+/// The shares are given as pairs of the share index and the share. With fewer than `k` shares,
+/// the result is unrelated to the secret, which can't be detected.
+pub fn combine(shares: &[(u8, &[u8])]) -> Result<Vec<u8>, ShareError> {
+    let len = shares.first().ok_or(ShareError::NoShares)?.1.len();
+    for (pos, &(i, share)) in shares.iter().enumerate() {
+        if i == 0 {
+            return Err(ShareError::ZeroIndex);
+        }
+        if shares[..pos].iter().any(|&(other, _)| other == i) {
+            return Err(ShareError::DuplicateIndex(i));
+        }
+        if share.len() != len {
+            return Err(ShareError::LengthMismatch(i));
+        }
+    }
+
+    // The values of the Lagrange basis polynomials at zero
+    let weights: Vec<GF256> = shares.iter()
+        .map(|&(i, _)| {
+            shares.iter()
+                .filter(|&&(other, _)| other != i)
+                .map(|&(other, _)| GF256(other) / (GF256(other) + GF256(i)))
+                .product()
+        })
+        .collect();
+
+    let secret = (0..len)
+        .map(|pos| shares.iter().zip(&weights).map(|(&(_, share), &w)| GF256(share[pos]) * w).sum::<GF256>().0)
+        .collect();
+    Ok(secret)
+}
+
+/// Error returned by [`combine`] for invalid shares
+///
+/// ```rust
+/// use g2p::shamir::{combine, ShareError};
+///
+/// let err = combine(&[(1, &[1, 2][..]), (1, &[3, 4][..])]).unwrap_err();
+/// assert_eq!(err, ShareError::DuplicateIndex(1));
+/// assert_eq!(err.to_string(), "share index 1 is used more than once");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ShareError {
+    /// No shares were given
+    NoShares,
+    /// A share has index 0, which is never produced by [`split`]
+    ZeroIndex,
+    /// The share index is used more than once
+    DuplicateIndex(u8),
+    /// The share with the given index has a different length than the first share
+    LengthMismatch(u8),
+}
+
+impl fmt::Display for ShareError {
+    fn fmt<'a>(&self, f: &mut fmt::Formatter<'a>) -> fmt::Result {
+        match self {
+            ShareError::NoShares => f.write_str("no shares given"),
+            ShareError::ZeroIndex => f.write_str("share index 0 is invalid"),
+            ShareError::DuplicateIndex(i) => write!(f, "share index {} is used more than once", i),
+            ShareError::LengthMismatch(i) => write!(f, "share {} has a different length than the first share", i),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ShareError {}
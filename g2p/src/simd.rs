@@ -0,0 +1,177 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Slice kernels for fields with elements of at most 8 bits
+//!
+//! With the `portable-simd` feature on a nightly compiler, these are written with `core::simd`,
+//! which compiles to the byte shuffles of SSSE3, AVX2 or NEON without any per-ISA code. A product
+//! `c * x` is looked up in two 16 entry tables, one for each nibble of `x`, using
+//! `Simd::swizzle_dyn`. Without the feature, or on a stable compiler, the same functions use
+//! plain loops, so code calling them does not need to change. Scaling a slice in place is done by
+//! [`scale_slice`](crate::scale_slice), which uses the same kernel for these fields.
+//!
+//! The elements are converted with `From<u8>` and `Into<u8>`, which all fields of `g2p!` with
+//! `p <= 8` implement.
+//!
+//! ```rust
+//! use g2p::scale_slice;
+//! use g2p::simd::{add_assign_slice, mul_add_slice};
+//!
+//! g2p::g2p!(GF256, 8);
+//! # fn main() {
+//! let src = [GF256(1), GF256(2), GF256(3)];
+//! let mut dst = [GF256(4), GF256(5), GF256(6)];
+//! mul_add_slice(GF256(2), &src, &mut dst);
+//! assert_eq!(dst, [GF256(6), GF256(1), GF256(0)]);
+//! add_assign_slice(&src, &mut dst);
+//! scale_slice(GF256(3), &mut dst);
+//! assert_eq!(dst, [GF256(9), GF256(5), GF256(5)]);
+//! # }
+//! ```
+
+use crate::GaloisField;
+
+/// Number of bytes processed at once, matching the width of the table lookups
+#[cfg(g2p_portable_simd)]
+const LANES: usize = 16;
+
+#[cfg(g2p_portable_simd)]
+type Bytes = core::simd::Simd<u8, LANES>;
+
+/// The products `c * i` and `c * (i << 4)` for all nibbles `i`
+#[cfg(g2p_portable_simd)]
+fn nibble_tables<F: GaloisField + From<u8> + Into<u8>>(c: F) -> (Bytes, Bytes) {
+    let lo = core::array::from_fn(|i| (c * F::from(i as u8)).into());
+    let hi = core::array::from_fn(|i| (c * F::from((i as u8) << 4)).into());
+    (Bytes::from_array(lo), Bytes::from_array(hi))
+}
+
+/// Multiply all lanes by the constant of the nibble tables
+#[cfg(g2p_portable_simd)]
+fn mul_lanes(x: Bytes, (lo, hi): (Bytes, Bytes)) -> Bytes {
+    let mask = Bytes::splat(0x0f);
+    lo.swizzle_dyn(x & mask) ^ hi.swizzle_dyn(x >> Bytes::splat(4))
+}
+
+#[cfg(g2p_portable_simd)]
+fn load<F: Into<u8> + Copy>(chunk: &[F]) -> Bytes {
+    Bytes::from_array(core::array::from_fn(|i| chunk[i].into()))
+}
+
+#[cfg(g2p_portable_simd)]
+fn store<F: From<u8>>(chunk: &mut [F], x: Bytes) {
+    for (d, v) in chunk.iter_mut().zip(x.to_array()) {
+        *d = F::from(v);
+    }
+}
+
+/// Fails to evaluate for fields with more than 8 bits
+struct AssertByteField<F>(core::marker::PhantomData<F>);
+
+impl<F: GaloisField> AssertByteField<F> {
+    const VALID: () = assert!(F::BITS <= 8, "The byte slice kernels require a field with at most 8 bits");
+}
+
+/// Multiply every element of `data` by `c` in place
+///
+/// [`scale_slice`](crate::scale_slice) uses this for the fields generated by `g2p!`.
+pub(crate) fn mul_slice<F>(c: F, data: &mut [F])
+where
+    F: GaloisField + From<u8> + Into<u8>,
+{
+    #[allow(clippy::let_unit_value)]
+    let () = AssertByteField::<F>::VALID;
+
+    if c == F::ZERO {
+        data.fill(F::ZERO);
+        return;
+    }
+    if c == F::ONE {
+        return;
+    }
+
+    #[cfg(g2p_portable_simd)]
+    let data = {
+        let tables = nibble_tables(c);
+        let mut chunks = data.chunks_exact_mut(LANES);
+        for chunk in &mut chunks {
+            let x = load(chunk);
+            store(chunk, mul_lanes(x, tables));
+        }
+        chunks.into_remainder()
+    };
+    for x in data {
+        *x *= c;
+    }
+}
+
+/// Add `c` times `src` to `dst` element-wise, i.e. `dst[i] += c * src[i]`
+///
+/// The same as [`crate::mul_add_slice`], but vectorized with the `portable-simd` feature.
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+#[track_caller]
+pub fn mul_add_slice<F>(c: F, src: &[F], dst: &mut [F])
+where
+    F: GaloisField + From<u8> + Into<u8>,
+{
+    #[allow(clippy::let_unit_value)]
+    let () = AssertByteField::<F>::VALID;
+
+    assert_eq!(src.len(), dst.len(), "mul_add_slice of slices with different lengths");
+    if c == F::ZERO {
+        return;
+    }
+    if c == F::ONE {
+        add_assign_slice(src, dst);
+        return;
+    }
+
+    #[cfg(g2p_portable_simd)]
+    let (src, dst) = {
+        let tables = nibble_tables(c);
+        let mut src_chunks = src.chunks_exact(LANES);
+        let mut dst_chunks = dst.chunks_exact_mut(LANES);
+        for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+            let sum = load(d) ^ mul_lanes(load(s), tables);
+            store(d, sum);
+        }
+        (src_chunks.remainder(), dst_chunks.into_remainder())
+    };
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d += c * s;
+    }
+}
+
+/// Add `src` to `dst` element-wise
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+#[track_caller]
+pub fn add_assign_slice<F>(src: &[F], dst: &mut [F])
+where
+    F: GaloisField + From<u8> + Into<u8>,
+{
+    #[allow(clippy::let_unit_value)]
+    let () = AssertByteField::<F>::VALID;
+
+    assert_eq!(src.len(), dst.len(), "add_assign_slice of slices with different lengths");
+
+    #[cfg(g2p_portable_simd)]
+    let (src, dst) = {
+        let mut src_chunks = src.chunks_exact(LANES);
+        let mut dst_chunks = dst.chunks_exact_mut(LANES);
+        for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+            let sum = load(d) ^ load(s);
+            store(d, sum);
+        }
+        (src_chunks.remainder(), dst_chunks.into_remainder())
+    };
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d += s;
+    }
+}
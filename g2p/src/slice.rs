@@ -0,0 +1,244 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Kernels operating on slices of field elements
+
+use crate::GaloisField;
+
+/// Number of independent sums kept by the dot product
+const LANES: usize = 4;
+
+/// Calculate the dot product `a[0] * b[0] + a[1] * b[1] + ...` of two slices
+///
+/// The elements are processed in chunks with independent partial sums, so the products of a
+/// chunk don't have to wait for each other. This is faster than `zip().map().sum()`.
+///
+/// # Panics
+/// Panics if the slices have different lengths, like `copy_from_slice`.
+///
+/// # Example
+/// ```rust
+/// use g2p::{dot, g2p};
+///
+/// g2p!(GF256, 8);
+/// # fn main() {
+/// let row = [GF256(1), GF256(2), GF256(3)];
+/// let column = [GF256(4), GF256(5), GF256(6)];
+/// assert_eq!(dot(&row, &column), GF256(4) + GF256(2) * GF256(5) + GF256(3) * GF256(6));
+/// assert_eq!(dot::<GF256>(&[], &[]), GF256(0));
+/// # }
+/// ```
+#[track_caller]
+pub fn dot<F: GaloisField>(a: &[F], b: &[F]) -> F {
+    let mut acc = F::ZERO;
+    dot_acc(&mut acc, a, b);
+    acc
+}
+
+/// Add the dot product of two slices to `acc`
+///
+/// This is the same as `*acc += dot(a, b)`, e.g. to accumulate a product of matrices split into
+/// blocks.
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+///
+/// # Example
+/// ```rust
+/// use g2p::{dot, dot_acc, g2p};
+///
+/// g2p!(GF256, 8);
+/// # fn main() {
+/// let (a, b) = ([GF256(7); 10], [GF256(9); 10]);
+/// let mut acc = dot(&a[..4], &b[..4]);
+/// dot_acc(&mut acc, &a[4..], &b[4..]);
+/// assert_eq!(acc, dot(&a, &b));
+/// # }
+/// ```
+#[track_caller]
+pub fn dot_acc<F: GaloisField>(acc: &mut F, a: &[F], b: &[F]) {
+    assert_eq!(a.len(), b.len(), "dot product of slices with different lengths");
+
+    let mut sums = [F::ZERO; LANES];
+    let mut a_chunks = a.chunks_exact(LANES);
+    let mut b_chunks = b.chunks_exact(LANES);
+    for (x, y) in (&mut a_chunks).zip(&mut b_chunks) {
+        for i in 0..LANES {
+            sums[i] += x[i] * y[i];
+        }
+    }
+    let mut sum = sums.iter().copied().sum::<F>();
+    for (&x, &y) in a_chunks.remainder().iter().zip(b_chunks.remainder()) {
+        sum += x * y;
+    }
+    *acc += sum;
+}
+
+/// Multiply every element of `data` by `c` in place
+///
+/// This is the most common step of row reduction. Multiplying by zero or one doesn't look at the
+/// elements at all. Fields generated by `g2p!` with at most 8 bits use the nibble table kernel of
+/// [`simd`](crate::simd), see [`GaloisField::scale_slice`].
+///
+/// # Example
+/// ```rust
+/// use g2p::{g2p, scale_slice};
+///
+/// g2p!(GF256, 8);
+/// # fn main() {
+/// let mut row = [GF256(1), GF256(2), GF256(3)];
+/// scale_slice(GF256(2), &mut row);
+/// assert_eq!(row, [GF256(2), GF256(4), GF256(6)]);
+/// # }
+/// ```
+pub fn scale_slice<F: GaloisField>(c: F, data: &mut [F]) {
+    F::scale_slice(c, data)
+}
+
+/// Add `c` times `src` to `dst` element-wise, i.e. `dst[i] += c * src[i]`
+///
+/// This is the inner loop of encoding with a generator matrix. Multiplying by zero leaves `dst`
+/// unchanged without looking at the elements, and multiplying by one only adds.
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+///
+/// # Example
+/// ```rust
+/// use g2p::{g2p, mul_add_slice};
+///
+/// g2p!(GF256, 8);
+/// # fn main() {
+/// let src = [GF256(1), GF256(2), GF256(3)];
+/// let mut dst = [GF256(4), GF256(5), GF256(6)];
+/// mul_add_slice(GF256(2), &src, &mut dst);
+/// assert_eq!(dst, [GF256(4) + GF256(2), GF256(5) + GF256(4), GF256(6) + GF256(6)]);
+/// # }
+/// ```
+#[track_caller]
+pub fn mul_add_slice<F: GaloisField>(c: F, src: &[F], dst: &mut [F]) {
+    assert_eq!(src.len(), dst.len(), "mul_add_slice of slices with different lengths");
+    if c == F::ZERO {
+        return;
+    }
+    if c == F::ONE {
+        for (d, &s) in dst.iter_mut().zip(src) {
+            *d += s;
+        }
+    } else {
+        for (d, &s) in dst.iter_mut().zip(src) {
+            *d += c * s;
+        }
+    }
+}
+
+/// Number of elements converted at once by the kernels on base type slices
+const BASE_CHUNK: usize = 64;
+
+/// Run `kernel` on elements stored as their base type, converting a chunk at a time
+///
+/// For the generated types the conversions are free, so this is as fast as running the kernel
+/// on slices of elements, without casting the slices and thus without unsafe code in the
+/// expansion of `g2p!`.
+fn with_base_chunks<F, T>(input: &[T], out: &mut [T], mut kernel: impl FnMut(&[F], &mut [F]))
+where
+    F: GaloisField + From<T> + Into<T>,
+    T: Copy,
+{
+    let mut src = [F::ZERO; BASE_CHUNK];
+    let mut dst = [F::ZERO; BASE_CHUNK];
+    for (input, out) in input.chunks(BASE_CHUNK).zip(out.chunks_mut(BASE_CHUNK)) {
+        let src = &mut src[..input.len()];
+        let dst = &mut dst[..input.len()];
+        for (s, &x) in src.iter_mut().zip(input) {
+            *s = F::from(x);
+        }
+        for (d, &x) in dst.iter_mut().zip(out.iter()) {
+            *d = F::from(x);
+        }
+        kernel(src, dst);
+        for (o, &d) in out.iter_mut().zip(dst.iter()) {
+            *o = d.into();
+        }
+    }
+}
+
+/// Set `out` to `c` times `input` for elements stored as their base type, used by the generated
+/// `reed_solomon_erasure::Field` implementation
+///
+/// Multiplying by zero or one doesn't look at the elements.
+#[track_caller]
+pub fn mul_slice_base<F, T>(c: F, input: &[T], out: &mut [T])
+where
+    F: GaloisField + From<T> + Into<T>,
+    T: Copy,
+{
+    assert_eq!(input.len(), out.len(), "mul_slice of slices with different lengths");
+    if c == F::ZERO {
+        out.fill(F::ZERO.into());
+    } else if c == F::ONE {
+        out.copy_from_slice(input);
+    } else {
+        with_base_chunks(input, out, |src, dst| {
+            dst.copy_from_slice(src);
+            scale_slice(c, dst);
+        });
+    }
+}
+
+/// Add `c` times `input` to `out` for elements stored as their base type, used by the generated
+/// `reed_solomon_erasure::Field` implementation
+#[track_caller]
+pub fn mul_add_slice_base<F, T>(c: F, input: &[T], out: &mut [T])
+where
+    F: GaloisField + From<T> + Into<T>,
+    T: Copy,
+{
+    assert_eq!(input.len(), out.len(), "mul_add_slice of slices with different lengths");
+    if c != F::ZERO {
+        with_base_chunks(input, out, |src, dst| mul_add_slice(c, src, dst));
+    }
+}
+
+/// [`mul_slice_base`] for fields of at most 8 bits, using the kernels of [`crate::simd`]
+#[track_caller]
+pub fn mul_slice_bytes<F>(c: F, input: &[u8], out: &mut [u8])
+where
+    F: GaloisField + From<u8> + Into<u8>,
+{
+    assert_eq!(input.len(), out.len(), "mul_slice of slices with different lengths");
+    if c == F::ZERO {
+        out.fill(0);
+    } else if c == F::ONE {
+        out.copy_from_slice(input);
+    } else {
+        with_base_chunks(input, out, |src, dst| {
+            dst.copy_from_slice(src);
+            crate::simd::mul_slice(c, dst);
+        });
+    }
+}
+
+/// [`scale_slice`] for fields of at most 8 bits, used by the generated `GaloisField`
+/// implementations
+pub fn scale_slice_bytes<F>(c: F, data: &mut [F])
+where
+    F: GaloisField + From<u8> + Into<u8>,
+{
+    crate::simd::mul_slice(c, data)
+}
+
+/// [`mul_add_slice_base`] for fields of at most 8 bits, using the kernels of [`crate::simd`]
+#[track_caller]
+pub fn mul_add_slice_bytes<F>(c: F, input: &[u8], out: &mut [u8])
+where
+    F: GaloisField + From<u8> + Into<u8>,
+{
+    assert_eq!(input.len(), out.len(), "mul_add_slice of slices with different lengths");
+    if c != F::ZERO {
+        with_base_chunks(input, out, |src, dst| crate::simd::mul_add_slice(c, src, dst));
+    }
+}
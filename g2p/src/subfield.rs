@@ -0,0 +1,162 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Embedding of a subfield, used by the `contains` option of the macro
+//!
+//! The macro only knows the name of the subfield type, so the embedding is calculated by constant
+//! evaluation from the moduli of both fields. Invalid combinations fail to compile.
+
+use g2poly::G2Poly;
+
+/// Maximum degree of a field, and so of a subfield
+const MAX_BITS: usize = 32;
+
+/// The GF(2)-linear map sending `x` of the subfield to a root of its modulus in the field
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct Embedding {
+    /// Degree of the subfield
+    bits: u32,
+    /// The images of `1, x, ..., x^(bits - 1)`
+    basis: [u64; MAX_BITS],
+    /// The images reduced to distinct highest bits, in order of decreasing highest bit
+    reduced: [u64; MAX_BITS],
+    /// The subfield elements mapping to the reduced images
+    preimages: [u64; MAX_BITS],
+}
+
+/// The value of the polynomial `poly` at `x` in the field with the given modulus
+const fn evaluate(poly: G2Poly, x: G2Poly, modulus: G2Poly) -> G2Poly {
+    let mut res = G2Poly(0);
+    let mut i = 64 - poly.0.leading_zeros();
+    while i > 0 {
+        i -= 1;
+        res = res.mul_mod(x, modulus);
+        res.0 ^= (poly.0 >> i) & 1;
+    }
+    res
+}
+
+impl Embedding {
+    /// Calculate the embedding of the field with modulus `sub` into the field with `modulus`
+    ///
+    /// The root is searched among the subfield, i.e. the powers of `generator^((2^p - 1) / (2^q -
+    /// 1))`. The map is then checked to be multiplicative on all pairs of basis elements, which
+    /// makes it a homomorphism as both sides of `f(a b) = f(a) f(b)` are bilinear.
+    ///
+    /// # Panics
+    /// Panics if the degree of `sub` does not divide the degree of `modulus`, or if the
+    /// embedding is not a homomorphism, which happens if `sub` is not irreducible.
+    pub const fn new(modulus: u64, generator: u64, sub: u64) -> Self {
+        let (modulus, sub) = (G2Poly(modulus), G2Poly(sub));
+        let p = match modulus.degree() {
+            Some(p) => p,
+            None => panic!("The modulus of the field is zero"),
+        };
+        let q = match sub.degree() {
+            Some(q) if q > 0 => q,
+            _ => panic!("The modulus of the subfield is constant"),
+        };
+        if p % q != 0 {
+            panic!("The degree of the subfield does not divide the degree of the field");
+        }
+
+        let cofactor = ((1_u64 << p) - 1) / ((1_u64 << q) - 1);
+        let subfield_generator = G2Poly(generator).pow_mod(cofactor, modulus);
+        let mut root = G2Poly(1);
+        let mut k = 0;
+        while evaluate(sub, root, modulus).0 != 0 {
+            k += 1;
+            if k == (1_u64 << q) - 1 {
+                panic!("The modulus of the subfield has no root in the field");
+            }
+            root = root.mul_mod(subfield_generator, modulus);
+        }
+
+        let mut basis = [0; MAX_BITS];
+        let mut power = G2Poly(1);
+        let mut i = 0;
+        while i < q as usize {
+            basis[i] = power.0;
+            power = power.mul_mod(root, modulus);
+            i += 1;
+        }
+
+        let mut res = Embedding { bits: q as u32, basis, reduced: [0; MAX_BITS], preimages: [0; MAX_BITS] };
+        let mut i = 0;
+        while i < q {
+            let mut j = 0;
+            while j < q {
+                let product = G2Poly(1 << i).mul_mod(G2Poly(1 << j), sub);
+                let expected = G2Poly(basis[i as usize]).mul_mod(G2Poly(basis[j as usize]), modulus);
+                if res.embed(product.0) != expected.0 {
+                    panic!("The embedding of the subfield is not a homomorphism");
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+
+        // Gaussian elimination on the images, keeping track of the combination of basis elements
+        let mut count = 0;
+        let mut i = 0;
+        while i < q as usize {
+            let mut image = basis[i];
+            let mut preimage = 1_u64 << i;
+            let mut j = 0;
+            while j < count {
+                if image & (1 << (63 - res.reduced[j].leading_zeros())) != 0 {
+                    image ^= res.reduced[j];
+                    preimage ^= res.preimages[j];
+                }
+                j += 1;
+            }
+            // The images are linearly independent, as the map is injective
+            let mut pos = count;
+            while pos > 0 && res.reduced[pos - 1] < image {
+                res.reduced[pos] = res.reduced[pos - 1];
+                res.preimages[pos] = res.preimages[pos - 1];
+                pos -= 1;
+            }
+            res.reduced[pos] = image;
+            res.preimages[pos] = preimage;
+            count += 1;
+            i += 1;
+        }
+        res
+    }
+
+    /// The image of the subfield element with value `v`
+    pub const fn embed(&self, v: u64) -> u64 {
+        let mut res = 0;
+        let mut i = 0;
+        while i < self.bits as usize {
+            if (v >> i) & 1 != 0 {
+                res ^= self.basis[i];
+            }
+            i += 1;
+        }
+        res
+    }
+
+    /// The subfield element mapping to `v`, or `None` if `v` is not in the image
+    pub const fn project(&self, mut v: u64) -> Option<u64> {
+        let mut res = 0;
+        let mut i = 0;
+        while i < self.bits as usize {
+            if v & (1 << (63 - self.reduced[i].leading_zeros())) != 0 {
+                v ^= self.reduced[i];
+                res ^= self.preimages[i];
+            }
+            i += 1;
+        }
+        if v == 0 {
+            Some(res)
+        } else {
+            None
+        }
+    }
+}
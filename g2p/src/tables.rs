@@ -0,0 +1,102 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Multiplication tables built by constant evaluation
+//!
+//! The generated code only contains calls to these functions instead of the table entries, which
+//! keeps the macro expansion small. The construction uses few steps, so it stays well below the
+//! limits of the constant evaluator even for the largest tables.
+
+/// Calculate `x^(shift + i) mod modulus` for the 15 possible degrees `i` of a product of two bytes
+const fn basis(modulus: u64, shift: u32) -> [u64; 15] {
+    let degree = 63 - modulus.leading_zeros();
+    let mut res = [0; 15];
+    let mut v = 1_u64;
+    let mut i = 0;
+    while i < shift + 15 {
+        if i >= shift {
+            res[(i - shift) as usize] = v;
+        }
+        v <<= 1;
+        if (v >> degree) & 1 != 0 {
+            v ^= modulus;
+        }
+        i += 1;
+    }
+    res
+}
+
+macro_rules! mul_table {
+    ($name:ident, $ty:ty) => {
+        /// Table of `a * b * x^shift mod modulus` for all bytes `a` and `b`
+        ///
+        /// The rows of powers of x are built from the reduced powers of x, as the product is
+        /// linear in `b`. All other rows are sums of those.
+        pub const fn $name(modulus: u64, shift: u32) -> [[$ty; 256]; 256] {
+            let basis = basis(modulus, shift);
+            let mut table: [[$ty; 256]; 256] = [[0; 256]; 256];
+            let mut j = 0;
+            while j < 8 {
+                let mut row: [$ty; 256] = [0; 256];
+                let mut b = 1_usize;
+                while b < 256 {
+                    row[b] = row[b & (b - 1)] ^ basis[j + b.trailing_zeros() as usize] as $ty;
+                    b += 1;
+                }
+                table[1 << j] = row;
+                j += 1;
+            }
+
+            let mut a = 3_usize;
+            while a < 256 {
+                let low = a & a.wrapping_neg();
+                if low != a {
+                    let mut b = 0;
+                    while b < 256 {
+                        table[a][b] = table[a ^ low][b] ^ table[low][b];
+                        b += 1;
+                    }
+                }
+                a += 1;
+            }
+            table
+        }
+    };
+}
+
+mul_table!(mul_table_u8, u8);
+mul_table!(mul_table_u16, u16);
+mul_table!(mul_table_u32, u32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use g2poly::G2Poly;
+
+    #[test]
+    fn test_mul_table_matches_g2poly() {
+        let m = G2Poly(0x11d);
+        for (a, row) in mul_table_u8(m.0, 0).iter().enumerate() {
+            for (b, &v) in row.iter().enumerate() {
+                assert_eq!(u64::from(v), G2Poly(a as u64).mul_mod(G2Poly(b as u64), m).0);
+            }
+        }
+
+        let m = G2Poly(0x1002d);
+        for (a, row) in mul_table_u16(m.0, 8).iter().enumerate() {
+            for (b, &v) in row.iter().enumerate() {
+                assert_eq!(u64::from(v), G2Poly(a as u64).mul_mod(G2Poly((b as u64) << 8), m).0);
+            }
+        }
+
+        let m = G2Poly(0x100009);
+        let table = mul_table_u32(m.0, 32);
+        for &(a, b) in [(1, 1), (3, 200), (255, 255), (17, 128)].iter() {
+            let expected = G2Poly(a as u64).mul_mod(G2Poly(1 << 16), m).mul_mod(G2Poly((b as u64) << 16), m);
+            assert_eq!(u64::from(table[a][b]), expected.0);
+        }
+    }
+}
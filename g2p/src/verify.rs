@@ -0,0 +1,61 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Compile-time checks of the constants of a field
+
+/// Check the constants of a field at compile time
+///
+/// Expands to `const` items that fail to compile if the constants of the field are inconsistent:
+///
+/// - `MODULUS` is irreducible and has degree `BITS`
+/// - `SIZE` is `2^BITS` and `MASK` is `SIZE - 1`
+/// - `GENERATOR` is reduced, and `GENERATOR^(SIZE - 1)` is one, but no smaller power is
+///
+/// This works for every type generated by [`g2p!`](macro@crate::g2p) and for
+/// [`GfElem`](crate::GfElem).
+///
+/// # Tables are not checked
+/// `verify!` does **not** check that the multiplication and inverse tables agree with each
+/// other. The tables of the generated types are statics, which can't be read at compile time,
+/// and checking every entry exceeds what the constant evaluator allows: a loop over the 2^16
+/// elements of GF(2^16) already trips the `long_running_const_eval` lint. Use the `self_test`
+/// option of the macro instead. Its tests multiply every nonzero element with its inverse from
+/// the table.
+///
+/// ```rust
+/// g2p::g2p!(GF256, 8, modulus: 0x11b);
+/// g2p::verify!(GF256);
+/// g2p::verify!(g2p::GfElem<4, 0b1_0011>);
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! verify {
+    ($field:ty) => {
+        $crate::verify!(
+            @check
+            modulus: <$field as $crate::GaloisField>::MODULUS,
+            generator: <$field as $crate::GaloisField>::GENERATOR.0 as u64,
+            mask: <$field>::MASK as u64,
+            bits: <$field as $crate::GaloisField>::BITS,
+            size: <$field as $crate::GaloisField>::SIZE,
+        );
+    };
+    // Checks the raw values, so that tests can pass inconsistent ones
+    (@check modulus: $modulus:expr, generator: $generator:expr, mask: $mask:expr, bits: $bits:expr, size: $size:expr,) => {
+        const _: () = assert!($modulus.is_irreducible(), "g2p::verify!: MODULUS is not irreducible");
+        const _: () = assert!(
+            matches!($modulus.degree(), Some(degree) if degree == $bits as u64),
+            "g2p::verify!: the degree of MODULUS is not BITS",
+        );
+        const _: () = assert!($size as u64 == 1 << $bits, "g2p::verify!: SIZE is not 2^BITS");
+        const _: () = assert!($mask + 1 == $size as u64, "g2p::verify!: MASK is not SIZE - 1");
+        const _: () = assert!($generator <= $mask, "g2p::verify!: GENERATOR is not reduced");
+        const _: () = assert!(
+            matches!($crate::G2Poly($generator).order_mod($modulus), Some(order) if order == $size as u64 - 1),
+            "g2p::verify!: GENERATOR does not generate the multiplicative group",
+        );
+    };
+}
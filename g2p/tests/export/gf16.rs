@@ -0,0 +1,17 @@
+// Tables for GF(2^4) generated by g2p
+// modulus: x^4 + x + 1 (0x13)
+// generator: 2
+// EXP_TABLE[i] = generator^i, LOG_TABLE[EXP_TABLE[i]] = i, INV_TABLE[x] = 1 / x
+// LOG_TABLE[0] and INV_TABLE[0] are 0, as zero has neither
+
+pub const EXP_TABLE: [u8; 15] = [
+    1, 2, 4, 8, 3, 6, 12, 11, 5, 10, 7, 14, 15, 13, 9,
+];
+
+pub const LOG_TABLE: [u8; 16] = [
+    0, 0, 1, 4, 2, 8, 5, 10, 3, 14, 9, 7, 6, 13, 11, 12,
+];
+
+pub const INV_TABLE: [u8; 16] = [
+    0, 1, 9, 14, 13, 11, 7, 6, 15, 2, 12, 5, 10, 4, 3, 8,
+];
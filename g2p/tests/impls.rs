@@ -70,3 +70,49 @@ fn test_div_panic() {
 
     let _ = a / z;
 }
+
+g2p!(GF256, 8);
+
+#[test]
+fn test_sum_product_by_ref() {
+    static_assertions::assert_impl_all!(GF256:
+        core::iter::Sum<GF256>,
+        core::iter::Sum<&'static GF256>,
+        core::iter::Product<GF256>,
+        core::iter::Product<&'static GF256>,
+    );
+
+    let values: Vec<GF256> = (1..=20).map(GF256).collect();
+    assert_eq!(values.iter().sum::<GF256>(), values.iter().copied().sum::<GF256>());
+    assert_eq!(values.iter().product::<GF256>(), values.iter().copied().product::<GF256>());
+
+    let empty: [GF256; 0] = [];
+    assert_eq!(empty.iter().sum::<GF256>(), GF256::ZERO);
+    assert_eq!(empty.iter().product::<GF256>(), GF256::ONE);
+}
+
+fn accumulate<T>(init: T, items: &[T]) -> (T, T, T, T)
+where
+    T: Clone + for<'a> AddAssign<&'a T> + for<'a> SubAssign<&'a T> + for<'a> MulAssign<&'a T> + for<'a> DivAssign<&'a T>,
+{
+    let (mut sum, mut diff, mut prod, mut quot) = (init.clone(), init.clone(), init.clone(), init);
+    for item in items {
+        sum += item;
+        diff -= item;
+        prod *= item;
+        quot /= item;
+    }
+    (sum, diff, prod, quot)
+}
+
+#[test]
+fn test_assign_by_ref() {
+    let items: Vec<GF256> = (1..=30).map(GF256).collect();
+    let init = GF256(7);
+    let (sum, diff, prod, quot) = accumulate(init, &items);
+
+    assert_eq!(sum, init + items.iter().sum::<GF256>());
+    assert_eq!(diff, init - items.iter().sum::<GF256>());
+    assert_eq!(prod, init * items.iter().product::<GF256>());
+    assert_eq!(quot, init / items.iter().product::<GF256>());
+}
@@ -0,0 +1,89 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, GaloisField};
+
+g2p!(GF2, 1);
+g2p!(GF16, 4);
+g2p!(GF32, 5);
+g2p!(GF256, 8);
+g2p!(GF256Slow, 8, tables: none);
+g2p!(GF8192, 13);
+
+macro_rules! check_exhaustive {
+    ($field:ident, $p:expr) => {
+        for v in 0..=$field::MASK {
+            let a = $field(v);
+            let trace: $field = (0..$p).map(|i| a.pow2k(i)).sum();
+            assert!(trace == $field::ZERO || trace == $field::ONE);
+            assert_eq!(a.trace(), trace == $field::ONE, "Trace of {:?}", a);
+
+            match $field::solve_artin_schreier(a) {
+                Some((x, y)) => {
+                    assert!(!a.trace(), "Solution for {:?}", a);
+                    assert_eq!(x * x + x, a);
+                    assert_eq!(y * y + y, a);
+                    assert_eq!(x + y, $field::ONE);
+                }
+                None => assert!(a.trace(), "No solution for {:?}", a),
+            }
+        }
+    };
+}
+
+#[test]
+fn test_gf2() {
+    check_exhaustive!(GF2, 1);
+}
+
+#[test]
+fn test_gf16() {
+    check_exhaustive!(GF16, 4);
+}
+
+#[test]
+fn test_gf32() {
+    check_exhaustive!(GF32, 5);
+}
+
+#[test]
+fn test_gf256() {
+    check_exhaustive!(GF256, 8);
+    check_exhaustive!(GF256Slow, 8);
+}
+
+#[test]
+fn test_gf8192() {
+    check_exhaustive!(GF8192, 13);
+}
+
+#[test]
+fn test_half_trace() {
+    for v in 0..32 {
+        let a = GF32(v);
+        let h = a.half_trace();
+        let expected = if a.trace() { a + GF32::ONE } else { a };
+        assert_eq!(h * h + h, expected);
+    }
+    assert_eq!(GF2(1).half_trace(), GF2(1));
+}
+
+#[test]
+fn test_trace_is_additive() {
+    for a in 0..=255 {
+        for b in 0..=255 {
+            assert_eq!((GF256(a) + GF256(b)).trace(), GF256(a).trace() ^ GF256(b).trace());
+        }
+    }
+    // Half of the elements have trace one
+    assert_eq!((0..=255).filter(|&v| GF256(v).trace()).count(), 128);
+}
+
+#[test]
+fn test_masked() {
+    assert_eq!(GF16(0x13).trace(), GF16(3).trace());
+    assert_eq!(GF16::solve_artin_schreier(GF16(0x16)), GF16::solve_artin_schreier(GF16(6)));
+}
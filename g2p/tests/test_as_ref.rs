@@ -0,0 +1,45 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::g2p;
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+
+g2p!(GF256, 8);
+g2p!(GF65536, 16);
+
+fn byte_of(v: &impl AsRef<u8>) -> u8 {
+    *v.as_ref()
+}
+
+fn word_of(v: &impl AsRef<u16>) -> u16 {
+    *v.as_ref()
+}
+
+#[test]
+fn test_as_ref() {
+    assert_eq!(byte_of(&GF256(42)), 42);
+    assert_eq!(word_of(&GF65536(4242)), 4242);
+    let b: &u8 = GF256(7).borrow();
+    assert_eq!(*b, 7);
+}
+
+#[test]
+fn test_borrow_lookup() {
+    let mut logs = HashMap::new();
+    let mut x = GF256(1);
+    for i in 0..255 {
+        logs.insert(x, i);
+        x *= GF256::GENERATOR;
+    }
+    assert_eq!(logs.get(&1_u8), Some(&0));
+    assert_eq!(logs.get(&GF256::GENERATOR.0), Some(&1));
+    assert_eq!(logs.get(&0_u8), None);
+
+    let set: HashSet<GF65536> = [GF65536(1), GF65536(300)].iter().copied().collect();
+    assert!(set.contains(&300_u16));
+    assert!(!set.contains(&2_u16));
+}
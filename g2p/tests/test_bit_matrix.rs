@@ -0,0 +1,72 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, GaloisField};
+
+g2p!(GF2, 1);
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+g2p!(GF1024, 10, tables: none);
+
+#[test]
+fn test_apply_gf256() {
+    for c in 0..=255 {
+        let m = GF256(c).mul_matrix();
+        for x in 0..=255 {
+            assert_eq!(GF256::apply_bit_matrix(&m, GF256(x)), GF256(c) * GF256(x));
+        }
+    }
+}
+
+#[test]
+fn test_apply_small_and_unaligned() {
+    for c in 0..=1 {
+        for x in 0..=1 {
+            assert_eq!(GF2::apply_bit_matrix(&GF2(c).mul_matrix(), GF2(x)), GF2(c) * GF2(x));
+        }
+    }
+    for c in 0..16 {
+        for x in 0..16 {
+            assert_eq!(GF16::apply_bit_matrix(&GF16(c).mul_matrix(), GF16(x)), GF16(c) * GF16(x));
+        }
+    }
+    for &c in &[0, 1, 2, 3, 511, 512, 1000, 1023] {
+        let m = GF1024(c).mul_matrix();
+        for x in 0..1024 {
+            assert_eq!(GF1024::apply_bit_matrix(&m, GF1024(x)), GF1024(c) * GF1024(x));
+        }
+    }
+}
+
+#[test]
+fn test_columns() {
+    let m = GF256(0x53).mul_matrix();
+    let mut x_i = GF256::ONE;
+    for &column in &m {
+        assert_eq!(column, (GF256(0x53) * x_i).0);
+        x_i *= GF256(2);
+    }
+    assert_eq!(GF256::ONE.mul_matrix(), [1, 2, 4, 8, 16, 32, 64, 128]);
+    // Only the bits of the field count
+    assert_eq!(GF16(0x13).mul_matrix(), GF16(3).mul_matrix());
+}
+
+#[test]
+fn test_compose() {
+    for c in 0..=255 {
+        for d in (0..=255).step_by(7) {
+            let composed = GF256::compose_bit_matrices(&GF256(c).mul_matrix(), &GF256(d).mul_matrix());
+            assert_eq!(composed, (GF256(c) * GF256(d)).mul_matrix());
+        }
+    }
+}
+
+#[test]
+fn test_const() {
+    const M: [u8; 8] = GF256(3).mul_matrix();
+    const PRODUCT: GF256 = GF256::apply_bit_matrix(&M, GF256(7));
+    assert_eq!(PRODUCT, GF256(3) * GF256(7));
+}
@@ -0,0 +1,67 @@
+use g2p::{g2p, DecodeError, FieldError};
+
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+g2p!(GF1024, 10);
+g2p!(GF4096, 12);
+g2p!(GF1M, 20);
+g2p!(GF4G, 32, tables: none);
+
+#[test]
+fn test_round_trip_boundaries() {
+    for &v in [0, 1, 0x7f, 0xff].iter() {
+        assert_eq!(GF256::from_le_bytes(GF256(v).to_le_bytes()), Ok(GF256(v)));
+        assert_eq!(GF256::from_be_bytes(GF256(v).to_be_bytes()), Ok(GF256(v)));
+    }
+    for &v in [0, 1, 0xff, 0x100, 0x3ff].iter() {
+        assert_eq!(GF1024::from_le_bytes(GF1024(v).to_le_bytes()), Ok(GF1024(v)));
+        assert_eq!(GF1024::from_be_bytes(GF1024(v).to_be_bytes()), Ok(GF1024(v)));
+    }
+    for &v in [0, 1, 0xffff, 0x10000, 0xfffff].iter() {
+        assert_eq!(GF1M::from_le_bytes(GF1M(v).to_le_bytes()), Ok(GF1M(v)));
+        assert_eq!(GF1M::from_be_bytes(GF1M(v).to_be_bytes()), Ok(GF1M(v)));
+    }
+    for &v in [0, 1, u32::MAX].iter() {
+        assert_eq!(GF4G::from_le_bytes(GF4G(v).to_le_bytes()), Ok(GF4G(v)));
+        assert_eq!(GF4G::from_be_bytes(GF4G(v).to_be_bytes()), Ok(GF4G(v)));
+    }
+}
+
+#[test]
+fn test_byte_order() {
+    assert_eq!(GF1024(0x312).to_le_bytes(), [0x12, 0x03]);
+    assert_eq!(GF1024(0x312).to_be_bytes(), [0x03, 0x12]);
+    assert_eq!(GF1M(0xabcde).to_le_bytes(), [0xde, 0xbc, 0x0a]);
+    assert_eq!(GF1M(0xabcde).to_be_bytes(), [0x0a, 0xbc, 0xde]);
+    assert_eq!(GF16(9).to_le_bytes(), [9]);
+}
+
+#[test]
+fn test_out_of_range() {
+    assert_eq!(GF4096::from_le_bytes([0x00, 0x10]), Err(FieldError::OutOfRange { value: 0x1000, mask: 0xfff }));
+    assert_eq!(GF4096::from_be_bytes([0x10, 0x00]), Err(FieldError::OutOfRange { value: 0x1000, mask: 0xfff }));
+    assert_eq!(GF4096::from_le_bytes([0xff, 0x0f]), Ok(GF4096(0xfff)));
+    assert_eq!(GF16::from_le_bytes([0x10]), Err(FieldError::OutOfRange { value: 0x10, mask: 0xf }));
+    assert_eq!(GF1M::from_be_bytes([0x10, 0, 0]), Err(FieldError::OutOfRange { value: 0x100000, mask: 0xfffff }));
+    assert_eq!(GF16::from_le_bytes([0x10]).unwrap_err().to_string(), "16 is out of range for the mask 0xf");
+}
+
+#[test]
+fn test_slices() {
+    let elements: Vec<GF4096> = (0..100).map(|v| GF4096(v * 41)).collect();
+    let mut bytes = vec![0xaa];
+    GF4096::write_slice(&elements, &mut bytes);
+    assert_eq!(bytes.len(), 201);
+    assert_eq!(&bytes[1..3], &elements[0].to_le_bytes());
+    assert_eq!(GF4096::read_slice(&bytes[1..]), Ok(elements));
+
+    assert_eq!(GF4096::read_slice(&bytes), Err(DecodeError::InvalidLength(201)));
+    assert_eq!(GF4096::read_slice(&[1, 0, 0xff, 0xff]), Err(DecodeError::OutOfRange(1)));
+    assert_eq!(GF4096::read_slice(&[]), Ok(vec![]));
+}
+
+#[test]
+fn test_error_display() {
+    assert_eq!(DecodeError::InvalidLength(3).to_string(), "input length 3 is not a multiple of the element size");
+    assert_eq!(DecodeError::OutOfRange(1).to_string(), "element 1 is out of range for the field");
+}
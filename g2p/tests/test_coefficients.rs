@@ -0,0 +1,123 @@
+use g2p::{g2p, GaloisField};
+
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+g2p!(GF4G, 32, tables: none);
+
+#[test]
+fn test_coefficient() {
+    // The default GF(256) generator is x, i.e. 2
+    assert_eq!(GF256::GENERATOR, GF256(0b10));
+    let coefficients: Vec<bool> = (0..10).map(|i| GF256::GENERATOR.coefficient(i)).collect();
+    assert_eq!(coefficients, [false, true, false, false, false, false, false, false, false, false]);
+
+    let mask = GF16(GF16::MASK);
+    assert!((0..4).all(|i| mask.coefficient(i)));
+    assert!(!mask.coefficient(4));
+    assert!(!mask.coefficient(u32::MAX));
+
+    assert!(GF4G(u32::MAX).coefficient(31));
+    assert!(!GF4G(u32::MAX).coefficient(32));
+}
+
+#[test]
+fn test_support() {
+    assert_eq!(GF256(0b1010_0001).support().collect::<Vec<_>>(), [0, 5, 7]);
+    assert_eq!(GF256::ZERO.support().count(), 0);
+    for v in 0..=255 {
+        let x = GF256(v);
+        let rebuilt = x.support().map(|i| 1 << i).sum::<u8>();
+        assert_eq!(rebuilt, x);
+    }
+}
+
+#[test]
+fn test_from_coefficients() {
+    for v in 0..=255 {
+        let x = GF256(v);
+        let bits: Vec<bool> = (0..8).map(|i| x.coefficient(i)).collect();
+        assert_eq!(GF256::from_coefficients(&bits), Some(x));
+    }
+    assert_eq!(GF16::from_coefficients(&[]), Some(GF16::ZERO));
+    assert_eq!(GF16::from_coefficients(&[true, false, true]), Some(GF16(0b101)));
+}
+
+#[test]
+fn test_from_coefficients_oversized() {
+    assert_eq!(GF16::from_coefficients(&[true, false, false, false, true]), None);
+    assert_eq!(GF16::from_coefficients(&[true, false, false, true, false, false, false]), Some(GF16(0b1001)));
+    let mut bits = [false; 40];
+    bits[31] = true;
+    assert_eq!(GF4G::from_coefficients(&bits), Some(GF4G(1 << 31)));
+    bits[32] = true;
+    assert_eq!(GF4G::from_coefficients(&bits), None);
+}
+
+#[test]
+fn test_from_coefficient_array() {
+    const X: GF16 = GF16::from_coefficient_array([false, true, false, true]);
+    assert_eq!(X, GF16(0b1010));
+    let x = GF256(0b1100_0101);
+    let mut bits = [false; 8];
+    for (i, bit) in bits.iter_mut().enumerate() {
+        *bit = x.coefficient(i as u32);
+    }
+    assert_eq!(GF256::from_coefficient_array(bits), x);
+}
+
+#[test]
+fn test_iter_bits() {
+    let x = GF16(0b0011);
+    assert_eq!(x.iter_bits().collect::<Vec<_>>(), [true, true, false, false]);
+    assert_eq!(x.iter_bits().rev().collect::<Vec<_>>(), [false, false, true, true]);
+
+    for v in 0..=255 {
+        let x = GF256(v);
+        assert_eq!(x.iter_bits().len(), 8);
+        let bits: Vec<bool> = x.iter_bits().collect();
+        assert_eq!(GF256::from_coefficients(&bits), Some(x));
+    }
+    assert_eq!(GF4G::ZERO.iter_bits().len(), 32);
+    assert_eq!(GF4G(u32::MAX).iter_bits().filter(|&b| b).count(), 32);
+}
+
+fn weight<F: GaloisField>(x: F) -> (u32, Option<u32>) {
+    (x.hamming_weight(), x.leading_coefficient_degree())
+}
+
+#[test]
+fn test_hamming_weight() {
+    const WEIGHT: u32 = GF256(0b1010_0001).hamming_weight();
+    assert_eq!(WEIGHT, 3);
+    assert_eq!(GF256::ZERO.hamming_weight(), 0);
+    assert_eq!(GF16(GF16::MASK).hamming_weight(), 4);
+    assert_eq!(GF256(GF256::MASK).hamming_weight(), 8);
+    assert_eq!(GF4G(GF4G::MASK).hamming_weight(), 32);
+    assert_eq!(GF256::GENERATOR.hamming_weight(), 1);
+    assert_eq!(GF16::GENERATOR.hamming_weight(), GF16::GENERATOR.support().count() as u32);
+    for v in 0..=255 {
+        assert_eq!(GF256(v).hamming_weight(), GF256(v).support().count() as u32);
+    }
+}
+
+#[test]
+fn test_leading_coefficient_degree() {
+    const DEGREE: Option<u32> = GF256::ONE.leading_coefficient_degree();
+    assert_eq!(DEGREE, Some(0));
+    assert_eq!(GF256::ZERO.leading_coefficient_degree(), None);
+    assert_eq!(GF256::GENERATOR.leading_coefficient_degree(), Some(1));
+    assert_eq!(GF16(GF16::MASK).leading_coefficient_degree(), Some(3));
+    assert_eq!(GF4G(GF4G::MASK).leading_coefficient_degree(), Some(31));
+    for v in 1..=255 {
+        assert_eq!(GF256(v).leading_coefficient_degree(), GF256(v).support().last());
+    }
+}
+
+#[test]
+fn test_generic_weight() {
+    assert_eq!(weight(GF256::ZERO), (0, None));
+    assert_eq!(weight(GF256::ONE), (1, Some(0)));
+    assert_eq!(weight(GF16(0b1100)), (2, Some(3)));
+    assert_eq!(weight(g2p::GfElem::<8, 0x11d>::new(0b1100_0000)), (2, Some(7)));
+    assert_eq!(weight(g2p::GfElem::<8, 0x11d>::new(0)), (0, None));
+}
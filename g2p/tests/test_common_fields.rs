@@ -0,0 +1,40 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(feature = "common-fields")]
+
+use g2p::{common::{Gf16, Gf256Aes, Gf256Rs, Gf65536}, g2p, scale_slice, GaloisField, G2Poly};
+
+g2p!(GF256, 8, modulus: 0x11d);
+
+#[test]
+fn test_gf256_rs_matches_local_field() {
+    for a in 0..=255 {
+        for b in 0..=255 {
+            assert_eq!((Gf256Rs(a) * Gf256Rs(b)).0, (GF256(a) * GF256(b)).0);
+            if b != 0 {
+                assert_eq!((Gf256Rs(a) / Gf256Rs(b)).0, (GF256(a) / GF256(b)).0);
+            }
+        }
+    }
+    assert_eq!(Gf256Rs::GENERATOR.0, GF256::GENERATOR.0);
+}
+
+#[test]
+fn test_moduli() {
+    assert_eq!(Gf16::MODULUS, G2Poly(0x13));
+    assert_eq!(Gf256Aes::MODULUS, G2Poly(0x11b));
+    assert_eq!(Gf256Rs::MODULUS, G2Poly(0x11d));
+    assert_eq!(Gf65536::MODULUS, G2Poly(0x1100b));
+}
+
+#[test]
+fn test_kernels_apply() {
+    let mut data = [Gf256Aes(0x53), Gf256Aes(1)];
+    scale_slice(Gf256Aes(0xca), &mut data);
+    assert_eq!(data, [Gf256Aes(1), Gf256Aes(0xca)]);
+    assert_eq!(Gf65536(3).pow(65535), Gf65536::ONE);
+}
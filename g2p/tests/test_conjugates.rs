@@ -0,0 +1,51 @@
+use g2p::{g2p, GaloisField};
+
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+
+#[test]
+fn test_subfield_orbit() {
+    // g^5 has order 3, so it lies in the subfield GF(4)
+    let x = GF16::GENERATOR.pow(5);
+    let orbit: Vec<GF16> = x.conjugates().collect();
+    assert_eq!(orbit, [x, x * x]);
+    assert_eq!(x.conjugate_count(), 2);
+
+    assert_eq!(GF16::ZERO.conjugates().collect::<Vec<_>>(), [GF16::ZERO]);
+    assert_eq!(GF16::ONE.conjugate_count(), 1);
+    assert_eq!(GF16::GENERATOR.conjugate_count(), 4);
+}
+
+#[test]
+fn test_product_is_norm() {
+    for v in 1..=255 {
+        let x = GF256(v);
+        let count = x.conjugate_count();
+        assert_eq!(8 % count, 0);
+        // The orbit is repeated 8 / count times among the 8 Frobenius images
+        let orbit_product: GF256 = x.conjugates().product();
+        assert_eq!(orbit_product.pow(8 / count as usize), x.pow(GF256::SIZE - 1));
+        assert_eq!(orbit_product.pow(8 / count as usize), GF256::ONE);
+    }
+}
+
+#[test]
+fn test_orbits_partition() {
+    let mut seen = [0_u32; 256];
+    for v in 1..=255 {
+        let x = GF256(v);
+        let mut orbit: Vec<GF256> = x.conjugates().collect();
+        orbit.sort_by_key(|z| z.0);
+        for y in &orbit {
+            seen[y.0 as usize] += 1;
+            let mut other: Vec<GF256> = y.conjugates().collect();
+            other.sort_by_key(|z| z.0);
+            assert_eq!(orbit, other);
+        }
+    }
+    // Every element is visited once for each member of its orbit
+    for v in 1..=255 {
+        assert_eq!(seen[v as usize], GF256(v).conjugate_count());
+    }
+    assert_eq!(seen[0], 0);
+}
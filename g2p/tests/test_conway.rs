@@ -0,0 +1,20 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, G2Poly};
+
+g2p!(GF16, 4, modulus: conway);
+g2p!(GF256, 8, modulus: conway);
+
+#[test]
+fn test_conway_modulus() {
+    assert_eq!(GF16::MODULUS, G2Poly(0b1_0011));
+    assert_eq!(GF256::MODULUS, G2Poly(0b1_0001_1101));
+
+    // Conway polynomials are primitive, so x generates the multiplicative group
+    assert_eq!(GF16::GENERATOR, GF16(0b10));
+    assert_eq!(GF256::GENERATOR, GF256(0b10));
+}
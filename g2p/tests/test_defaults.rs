@@ -0,0 +1,36 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, GaloisField, G2Poly};
+
+g2p!(GF8, 3);
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+
+#[test]
+fn test_default_modulus() {
+    assert_eq!(GF8::MODULUS, G2Poly(0b1011));
+    assert_eq!(GF16::MODULUS, G2Poly(0b1_0011));
+    assert_eq!(GF256::MODULUS, G2Poly(0b1_0001_1101));
+
+    assert_eq!(GF8::MODULUS_POLY_STR, "x^3 + x + 1");
+    assert_eq!(GF16::MODULUS_POLY_STR, "x^4 + x + 1");
+    assert_eq!(GF256::MODULUS_POLY_STR, "x^8 + x^4 + x^3 + x^2 + 1");
+}
+
+#[test]
+fn test_default_generator() {
+    assert_eq!(GF8::GENERATOR, GF8(2));
+    assert_eq!(GF16::GENERATOR, GF16(2));
+    assert_eq!(GF256::GENERATOR, GF256(2));
+}
+
+#[test]
+fn test_trait_consts_agree() {
+    assert_eq!(<GF256 as GaloisField>::MODULUS, GF256::MODULUS);
+    assert_eq!(<GF256 as GaloisField>::GENERATOR, GF256::GENERATOR);
+    assert_eq!(GF256::MODULUS.to_string(), GF256::MODULUS_POLY_STR);
+}
@@ -0,0 +1,81 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(feature = "defmt")]
+
+use std::sync::Mutex;
+
+use g2p::g2p;
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+g2p!(GF16, 4);
+g2p!(GF1024, 10);
+g2p!(GF16Hash, 4, impls: [hash]);
+
+assert_impl_all!(GF16: defmt::Format);
+assert_impl_all!(GF1024: defmt::Format);
+assert_not_impl_any!(GF16Hash: defmt::Format);
+
+/// The bytes written by the logger, instead of sending them to a debug probe
+static OUTPUT: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+#[defmt::global_logger]
+struct CaptureLogger;
+
+unsafe impl defmt::Logger for CaptureLogger {
+    fn acquire() {}
+    unsafe fn flush() {}
+    unsafe fn release() {}
+    unsafe fn write(bytes: &[u8]) {
+        OUTPUT.lock().unwrap().extend_from_slice(bytes);
+    }
+}
+
+defmt::timestamp!("");
+
+/// Log `x` from a single call site and return the encoded frame
+fn log<T: defmt::Format>(x: T) -> Vec<u8> {
+    let mut output = OUTPUT.lock().unwrap();
+    output.clear();
+    drop(output);
+    defmt::println!("{}", x);
+    OUTPUT.lock().unwrap().clone()
+}
+
+/// Find the byte positions where the two frames differ
+fn differences(a: &[u8], b: &[u8]) -> Vec<usize> {
+    assert_eq!(a.len(), b.len());
+    (0..a.len()).filter(|&i| a[i] != b[i]).collect()
+}
+
+#[test]
+fn test_encodes_value() {
+    // The frames only differ in the little endian value, which has the size of the base type
+    let zero = log(GF16(0));
+    for i in 1..16 {
+        let frame = log(GF16(i));
+        let pos = differences(&zero, &frame);
+        assert_eq!(pos.len(), 1);
+        assert_eq!(frame[pos[0]], i);
+    }
+
+    let zero = log(GF1024(0));
+    let frame = log(GF1024(0x3ff));
+    let pos = differences(&zero, &frame);
+    assert_eq!(pos.len(), 2);
+    assert_eq!(pos[1], pos[0] + 1);
+    assert_eq!(frame[pos[0]..=pos[1]], [0xff, 0x03]);
+}
+
+#[test]
+fn test_format_string() {
+    // defmt interns format strings as symbol names for the host to decode, so they are part of
+    // the test binary
+    let binary = std::fs::read(std::env::current_exe().unwrap()).unwrap();
+    let contains = |needle: &str| binary.windows(needle.len()).any(|w| w == needle.as_bytes());
+    assert!(contains(r#""data":"{=u8}_GF16""#));
+    assert!(contains(r#""data":"{=u16}_GF1024""#));
+}
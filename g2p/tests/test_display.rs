@@ -0,0 +1,138 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, FieldError};
+
+g2p!(GF256, 8);
+g2p!(GF256Suffixed, 8, display: "{value}_{name}");
+g2p!(GF256Hex, 8, display: "0x{hex} ({name})");
+
+#[test]
+fn test_default_display() {
+    assert_eq!(format!("{}", GF256(5)), "5");
+    assert_eq!(format!("{}", GF256(255)), "255");
+    assert_eq!(format!("{:?}", GF256(5)), "5_GF256");
+}
+
+#[test]
+fn test_suffixed_display() {
+    assert_eq!(format!("{}", GF256Suffixed(5)), "5_GF256Suffixed");
+    assert_eq!(format!("{:?}", GF256Suffixed(5)), "5_GF256Suffixed");
+}
+
+#[test]
+fn test_hex_display() {
+    assert_eq!(format!("{}", GF256Hex(0x1d)), "0x1d (GF256Hex)");
+    assert_eq!(format!("{}", GF256Hex(0)), "0x0 (GF256Hex)");
+    assert_eq!(format!("{:?}", GF256Hex(0x1d)), "29_GF256Hex");
+}
+
+g2p!(GF16, 4);
+g2p!(GF1024, 10);
+
+#[test]
+fn test_formats() {
+    for &(v, display, debug) in [(0, "0", "0_GF16"), (5, "5", "5_GF16"), (15, "15", "15_GF16")].iter() {
+        assert_eq!(format!("{}", GF16(v)), display);
+        assert_eq!(format!("{:?}", GF16(v)), debug);
+    }
+    for &(v, display, debug) in [(0, "0", "0_GF1024"), (300, "300", "300_GF1024"), (1023, "1023", "1023_GF1024")].iter() {
+        assert_eq!(format!("{}", GF1024(v)), display);
+        assert_eq!(format!("{:?}", GF1024(v)), debug);
+    }
+}
+
+g2p!(GF4096, 12);
+
+#[test]
+fn test_alternate_debug() {
+    assert_eq!(format!("{:#?}", GF16(5)), "0x5");
+    assert_eq!(format!("{:#?}", GF16(0)), "0x0");
+    assert_eq!(format!("{:#?}", GF256(0x1d)), "0x1d");
+    assert_eq!(format!("{:#?}", GF256(5)), "0x05");
+    assert_eq!(format!("{:#?}", GF1024(0x3ff)), "0x3ff");
+    assert_eq!(format!("{:#?}", GF4096(0x1d)), "0x01d");
+    assert_eq!(format!("{:#?}", GF4096(0xabc)), "0xabc");
+    assert_eq!(format!("{:#?}", [GF256(1), GF256(0xff)]), "[\n    0x01,\n    0xff,\n]");
+}
+
+#[test]
+fn test_hex() {
+    assert_eq!(format!("{:x}", GF256(0x1d)), "1d");
+    assert_eq!(format!("{:#06X}", GF4096(0xabc)), "0x0ABC");
+    assert_eq!(format!("{:x?}", GF256(0x1d)), "1d_GF256");
+    assert_eq!(format!("{:X?}", [GF4096(0xabc), GF4096(1)]), "[ABC_GF4096, 1_GF4096]");
+    assert_eq!(format!("{:?}", [GF16(10), GF16(11)]), "[10_GF16, 11_GF16]");
+}
+
+#[test]
+fn test_from_str() {
+    for v in 0..1024 {
+        let a = GF1024(v);
+        assert_eq!(format!("{}", a).parse(), Ok(a));
+        assert_eq!(format!("{:?}", a).parse(), Ok(a));
+    }
+    assert_eq!("7_GF16".parse(), Ok(GF16(7)));
+
+    let err = |s: &str| s.parse::<GF16>().unwrap_err();
+    assert_eq!(err("16"), FieldError::OutOfRange { value: 16, mask: 0xf });
+    assert_eq!(err("7_GF1024"), FieldError::Parse { pos: 1 });
+    assert_eq!(err(""), "".parse::<GF1024>().unwrap_err());
+    assert_eq!(err("0x7"), FieldError::Parse { pos: 1 });
+    assert_eq!(err("16").to_string(), "16 is out of range for the mask 0xf");
+    assert_eq!(err("7_GF1024").to_string(), "invalid field element at byte 1");
+}
+
+g2p!(GF65536, 16);
+
+#[test]
+fn test_polynomial_display() {
+    assert_eq!(format!("{:#}", GF256(0b1101_0001)), "x^7 + x^6 + x^4 + 1");
+    assert_eq!(format!("{}", GF256(0b1101_0001)), "209");
+    for &(v, poly) in [(0, "0"), (1, "1"), (2, "x"), (3, "x + 1"), (GF256::MASK, "x^7 + x^6 + x^5 + x^4 + x^3 + x^2 + x + 1")].iter() {
+        assert_eq!(format!("{:#}", GF256(v)), poly);
+    }
+    for &(v, poly) in [
+        (0, "0"),
+        (1, "1"),
+        (0x8002, "x^15 + x"),
+        (0x0104, "x^8 + x^2"),
+        (GF65536::MASK, "x^15 + x^14 + x^13 + x^12 + x^11 + x^10 + x^9 + x^8 + x^7 + x^6 + x^5 + x^4 + x^3 + x^2 + x + 1"),
+    ].iter() {
+        assert_eq!(format!("{:#}", GF65536(v)), poly);
+    }
+    assert_eq!(format!("{:#}", GF256Hex(5)), "x^2 + 1");
+}
+
+#[test]
+fn test_from_str_radix() {
+    assert_eq!(GF65536::from_str_radix("beef", 16), Ok(GF65536(0xbeef)));
+    assert_eq!(GF65536::from_str_radix("0xBEEF", 16), Ok(GF65536(0xbeef)));
+    assert_eq!(GF65536::from_str_radix("ffff", 16), Ok(GF65536(GF65536::MASK)));
+    assert_eq!(GF256::from_str_radix("0b11010001", 2), Ok(GF256(0b1101_0001)));
+    assert_eq!(GF256::from_str_radix("11010001", 2), Ok(GF256(0b1101_0001)));
+    assert_eq!(GF256::from_str_radix("209", 10), Ok(GF256(209)));
+    assert_eq!(GF16::from_str_radix("f", 16), Ok(GF16(15)));
+    assert_eq!(GF1024::from_str_radix("rr", 36), Ok(GF1024(27 * 36 + 27)));
+
+    let err = |s: &str, radix| GF16::from_str_radix(s, radix).unwrap_err();
+    assert_eq!(err("10", 16), FieldError::OutOfRange { value: 16, mask: 0xf });
+    assert_eq!(err("10000", 2), FieldError::OutOfRange { value: 16, mask: 0xf });
+    assert_eq!(GF65536::from_str_radix("10000", 16), Err(FieldError::OutOfRange { value: 0x10000, mask: 0xffff }));
+    assert_eq!(err("12", 2), FieldError::Parse { pos: 1 });
+    assert_eq!(err("0x1g", 16), FieldError::Parse { pos: 3 });
+    assert_eq!(err("0x5", 10), FieldError::Parse { pos: 1 });
+    assert_eq!(err("-1", 10), FieldError::Parse { pos: 0 });
+    assert_eq!(err("", 16), FieldError::Parse { pos: 0 });
+    assert_eq!(err("0b", 2), FieldError::Parse { pos: 2 });
+    assert_eq!(err("0x1g", 16).to_string(), "invalid field element at byte 3");
+}
+
+#[test]
+#[should_panic(expected = "radix must be in the range 2 to 36")]
+fn test_from_str_radix_invalid_radix() {
+    let _ = GF16::from_str_radix("1", 37);
+}
@@ -0,0 +1,64 @@
+use std::panic;
+
+use g2p::{g2p, FieldError};
+
+g2p!(GF256, 8);
+g2p!(GF256Slow, 8, tables: none);
+
+fn panic_message<F: FnOnce() + panic::UnwindSafe>(f: F) -> String {
+    let payload = panic::catch_unwind(f).expect_err("Division by zero panics");
+    match payload.downcast::<String>() {
+        Ok(msg) => *msg,
+        Err(payload) => payload.downcast_ref::<&str>().expect("Panic message is a string").to_string(),
+    }
+}
+
+#[test]
+fn test_panic_message() {
+    assert_eq!(panic_message(|| { let _ = GF256(37) / GF256(0); }), "GF256: attempted 37 / 0");
+    assert_eq!(panic_message(|| { let _ = GF256Slow(37) / GF256Slow(0); }), "GF256Slow: attempted 37 / 0");
+    assert_eq!(panic_message(|| {
+        let mut a = GF256(5);
+        a /= GF256(0);
+    }), "GF256: attempted 5 / 0");
+}
+
+#[test]
+fn test_panic_location() {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let tx = std::sync::Mutex::new(tx);
+    let prev_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let location = info.location().expect("Panic has a location");
+        tx.lock().unwrap().send((location.file().to_string(), location.line())).unwrap();
+    }));
+    let line = line!() + 1;
+    let result = panic::catch_unwind(|| GF256(1) / GF256(0));
+    panic::set_hook(prev_hook);
+
+    assert!(result.is_err());
+    // Other tests may panic concurrently, so look for the location among all reported panics
+    let expected = (file!().to_string(), line);
+    assert!(rx.try_iter().any(|location| location == expected));
+}
+
+#[test]
+fn test_checked_div() {
+    assert_eq!(GF256(37).checked_div(GF256(0)), None);
+    assert_eq!(GF256(37).try_div(GF256(0)), Err(FieldError::DivisionByZero));
+    assert_eq!(GF256Slow(37).try_div(GF256Slow(0)), Err(FieldError::DivisionByZero));
+    assert_eq!(GF256(37).checked_div(GF256(1)), Some(GF256(37)));
+    assert_eq!(GF256Slow(37).try_div(GF256Slow(37)), Ok(GF256Slow(1)));
+    for a in 0..=255 {
+        for b in 1..=255 {
+            assert_eq!(GF256(a).try_div(GF256(b)), Ok(GF256(a) / GF256(b)));
+        }
+    }
+}
+
+#[test]
+fn test_error_display() {
+    assert_eq!(FieldError::DivisionByZero.to_string(), "attempted division by zero");
+    let err: Box<dyn std::error::Error> = Box::new(GF256(1).try_div(GF256(0)).unwrap_err());
+    assert_eq!(err.to_string(), "attempted division by zero");
+}
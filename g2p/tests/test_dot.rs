@@ -0,0 +1,39 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{dot, dot_acc, g2p};
+use rand::Rng;
+
+g2p!(GF256, 8);
+
+fn naive(a: &[GF256], b: &[GF256]) -> GF256 {
+    a.iter().zip(b).map(|(&x, &y)| x * y).sum()
+}
+
+fn random_vec(len: usize) -> Vec<GF256> {
+    let mut rng = rand::thread_rng();
+    (0..len).map(|_| GF256(rng.gen())).collect()
+}
+
+#[test]
+fn test_dot_matches_naive() {
+    for &len in [0, 1, 2, 3, 4, 5, 7, 8, 15, 16, 17, 100, 1023].iter() {
+        for _ in 0..10 {
+            let (a, b) = (random_vec(len), random_vec(len));
+            assert_eq!(dot(&a, &b), naive(&a, &b));
+
+            let mut acc = GF256(42);
+            dot_acc(&mut acc, &a, &b);
+            assert_eq!(acc, GF256(42) + naive(&a, &b));
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "dot product of slices with different lengths")]
+fn test_dot_length_mismatch() {
+    dot(&[GF256(1), GF256(2)], &[GF256(1)]);
+}
@@ -0,0 +1,70 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, GaloisField};
+
+g2p!(GF2, 1, impls: [dual_basis]);
+g2p!(GF16, 4, impls: [dual_basis]);
+g2p!(GF256, 8, impls: [dual_basis]);
+g2p!(GF256Aes, modulus: 0b1_0001_1011, impls: [dual_basis]);
+g2p!(GF8192, 13, tables: none, impls: [dual_basis]);
+
+macro_rules! check_duality {
+    ($field:ident, $p:expr) => {
+        let mut x_i = $field::ONE;
+        for i in 0..$p {
+            for (j, &d) in $field::DUAL_BASIS.iter().enumerate() {
+                assert_eq!((x_i * d).trace(), i == j, "Tr(x^{} d_{})", i, j);
+            }
+            x_i *= $field(2);
+        }
+    };
+}
+
+macro_rules! check_round_trip {
+    ($field:ident) => {
+        for v in 0..=$field::MASK {
+            let x = $field(v);
+            let repr = x.to_dual();
+            assert_eq!($field::from_dual(repr), x);
+            assert_eq!($field::from_dual(v).to_dual(), v);
+            // The coordinates are the traces of the products with the polynomial basis
+            let mut x_j = $field::ONE;
+            for j in 0..$field::BITS {
+                assert_eq!(repr >> j & 1 != 0, (x * x_j).trace());
+                x_j *= $field(2);
+            }
+        }
+    };
+}
+
+#[test]
+fn test_duality() {
+    check_duality!(GF2, 1);
+    check_duality!(GF16, 4);
+    check_duality!(GF256, 8);
+    check_duality!(GF256Aes, 8);
+    check_duality!(GF8192, 13);
+}
+
+#[test]
+fn test_round_trip() {
+    check_round_trip!(GF2);
+    check_round_trip!(GF16);
+    check_round_trip!(GF256);
+    check_round_trip!(GF256Aes);
+}
+
+#[test]
+fn test_matrices() {
+    for (j, &column) in GF256::FROM_DUAL_MATRIX.iter().enumerate() {
+        assert_eq!(GF256(column), GF256::DUAL_BASIS[j]);
+        assert_eq!(GF256::DUAL_BASIS[j].to_dual(), 1 << j);
+    }
+    for (i, &column) in GF256::TO_DUAL_MATRIX.iter().enumerate() {
+        assert_eq!(GF256(1 << i).to_dual(), column);
+    }
+}
@@ -0,0 +1,134 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(feature = "alloc")]
+
+use g2p::{g2p, DynGf, FieldError, G2Poly, GaloisField};
+use rand::{Rng, SeedableRng};
+
+g2p!(GF2, 1);
+g2p!(GF256, 8, modulus: 0x11d);
+g2p!(GF256Aes, 8, modulus: 0x11b);
+g2p!(GF4096, 12);
+
+#[test]
+fn test_gf256_matches_macro() {
+    let field = DynGf::new(8, 0x11d).unwrap();
+    assert_eq!(field.generator().value(), u32::from(GF256::GENERATOR));
+    for a in 0..=255_u8 {
+        let x = field.elem(a.into()).unwrap();
+        for b in 0..=255_u8 {
+            let y = field.elem(b.into()).unwrap();
+            assert_eq!((x + y).value(), u32::from(GF256(a) + GF256(b)));
+            assert_eq!((x - y).value(), u32::from(GF256(a) - GF256(b)));
+            assert_eq!((x * y).value(), u32::from(GF256(a) * GF256(b)));
+            assert_eq!(x.checked_div(y).map(|z| z.value()), GF256(a).checked_div(GF256(b)).map(u32::from));
+        }
+        assert_eq!((-x).value(), u32::from(-GF256(a)));
+        if a != 0 {
+            assert_eq!(x.inverse().value(), u32::from(GF256(a).inverse()));
+        }
+        for &exp in &[0, 1, 2, 3, 254, 255, 256, 1000, u64::MAX] {
+            assert_eq!(x.pow(exp).value(), u32::from(GF256(a).pow(exp as usize)), "{}^{}", a, exp);
+        }
+    }
+}
+
+#[test]
+fn test_other_fields_match_macro() {
+    // A modulus for which x is not a generator
+    let field = DynGf::new(8, 0x11b).unwrap();
+    assert_eq!(field.generator().value(), u32::from(GF256Aes::GENERATOR));
+    for a in 0..=255_u8 {
+        for b in 0..=255_u8 {
+            let (x, y) = (field.elem(a.into()).unwrap(), field.elem(b.into()).unwrap());
+            assert_eq!((x * y).value(), u32::from(GF256Aes(a) * GF256Aes(b)));
+        }
+    }
+
+    let field = DynGf::new(12, GF4096::MODULUS.0).unwrap();
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0x1234);
+    for _ in 0..10_000 {
+        let (a, b) = (rng.gen_range(0..4096_u16), rng.gen_range(1..4096_u16));
+        let (x, y) = (field.elem(a.into()).unwrap(), field.elem(b.into()).unwrap());
+        assert_eq!((x * y).value(), u32::from(GF4096(a) * GF4096(b)));
+        assert_eq!((x / y).value(), u32::from(GF4096(a) / GF4096(b)));
+    }
+
+    let field = DynGf::new(1, 0b11).unwrap();
+    for (x, a) in field.elements().zip([GF2(0), GF2(1)]) {
+        for (y, b) in field.elements().zip([GF2(0), GF2(1)]) {
+            assert_eq!((x * y).value(), u32::from(a * b));
+        }
+    }
+}
+
+#[test]
+fn test_field_properties() {
+    let field = DynGf::new(5, 0b100101).unwrap();
+    assert_eq!((field.degree(), field.size(), field.mask()), (5, 32, 31));
+    assert_eq!(field.modulus(), G2Poly(0b100101));
+    assert_eq!(field.elements().count(), 32);
+    assert_eq!(field.one() * field.generator(), field.generator());
+    assert_eq!(field.zero() + field.one(), field.one());
+    assert_eq!(format!("{:?}", field.elem(5).unwrap()), "5_GF32");
+    assert_eq!(format!("{}", field.elem(5).unwrap()), "5");
+    assert_eq!(field.elem(5).unwrap().field(), &field);
+
+    let mut x = field.elem(7).unwrap();
+    x *= field.generator();
+    x /= field.generator();
+    x += field.one();
+    x -= field.one();
+    assert_eq!(x.value(), 7);
+}
+
+#[test]
+fn test_invalid_moduli() {
+    assert_eq!(DynGf::new(0, 0b1).unwrap_err(), FieldError::InvalidDegree { degree: 0 });
+    assert_eq!(DynGf::new(21, 0b1).unwrap_err(), FieldError::InvalidDegree { degree: 21 });
+    assert_eq!(DynGf::new(9, 0x11d).unwrap_err(), FieldError::ModulusDegree { modulus: 0x11d, degree: 9 });
+    assert_eq!(DynGf::new(8, 0).unwrap_err(), FieldError::ModulusDegree { modulus: 0, degree: 8 });
+    // x^8 and (x^4 + x + 1)^2
+    assert_eq!(DynGf::new(8, 0x100).unwrap_err(), FieldError::ReducibleModulus { modulus: 0x100 });
+    assert_eq!(DynGf::new(8, 0x10101).unwrap_err(), FieldError::ModulusDegree { modulus: 0x10101, degree: 8 });
+    assert_eq!(DynGf::new(8, 0x105).unwrap_err(), FieldError::ReducibleModulus { modulus: 0x105 });
+
+    assert_eq!(DynGf::new(8, 0x100).unwrap_err().to_string(), "modulus 0x100 is not irreducible");
+    assert_eq!(DynGf::new(9, 0x11d).unwrap_err().to_string(), "modulus 0x11d does not have degree 9");
+    assert_eq!(DynGf::new(30, 0b1).unwrap_err().to_string(), "degree 30 is out of range");
+}
+
+#[test]
+fn test_out_of_range() {
+    let field = DynGf::new(4, 0b10011).unwrap();
+    let err = field.elem(16).unwrap_err();
+    assert_eq!(err, FieldError::OutOfRange { value: 16, mask: 15 });
+    assert_eq!(err.to_string(), "16 is out of range for the mask 0xf");
+}
+
+#[test]
+fn test_try_div() {
+    let field = DynGf::new(4, 0b10011).unwrap();
+    let three = field.elem(3).unwrap();
+    assert_eq!(three.try_div(field.zero()), Err(FieldError::DivisionByZero));
+    assert_eq!(three.try_div(three), Ok(field.one()));
+}
+
+#[test]
+#[should_panic(expected = "GF(2^4): attempted 3 / 0")]
+fn test_div_by_zero() {
+    let field = DynGf::new(4, 0b10011).unwrap();
+    let _ = field.elem(3).unwrap() / field.zero();
+}
+
+#[test]
+#[should_panic(expected = "can't be combined")]
+fn test_mixed_fields() {
+    let a = DynGf::new(8, 0x11d).unwrap();
+    let b = DynGf::new(8, 0x11b).unwrap();
+    let _ = a.one() * b.one();
+}
@@ -0,0 +1,23 @@
+use g2p::g2p;
+
+g2p!(GF16, 4);
+g2p!(GF1024, 10);
+
+#[test]
+fn test_eq_base_type() {
+    assert_eq!(GF16::from(7) * GF16::from(1), 7);
+    assert_eq!(7, GF16::from(7));
+    assert_ne!(GF16::from(7), 6);
+    assert_ne!(6, GF16::from(7));
+
+    assert_eq!(GF1024::from(1000_u16), 1000_u16);
+    assert_eq!(1000_u16, GF1024::from(1000_u16));
+}
+
+#[test]
+fn test_eq_base_type_does_not_mask() {
+    assert_eq!(19 & GF16::MASK, 3);
+    assert_ne!(GF16::from(3), 19_u8);
+    assert_ne!(19_u8, GF16::from(3));
+    assert_ne!(GF1024::from(3_u16), 1024_u16 + 3);
+}
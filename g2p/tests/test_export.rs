@@ -0,0 +1,74 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(feature = "std")]
+
+use g2p::export::{export_tables, write_to, ExportFormat};
+use g2p::{g2p, GaloisField};
+
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+g2p!(GF1024, 10);
+
+/// The Rust output for GF16, checked in to make sure it compiles
+mod gf16 {
+    include!("export/gf16.rs");
+}
+
+#[test]
+fn test_rust() {
+    assert_eq!(export_tables::<GF16>(ExportFormat::Rust), include_str!("export/gf16.rs"));
+    for (i, &x) in gf16::EXP_TABLE.iter().enumerate() {
+        assert_eq!(GF16(x), GF16::GENERATOR.pow(i));
+        assert_eq!(gf16::LOG_TABLE[x as usize] as usize, i);
+    }
+    for x in 1..16 {
+        assert_eq!(GF16(gf16::INV_TABLE[x]) * GF16(x as u8), GF16::ONE);
+    }
+    assert_eq!(gf16::LOG_TABLE[0], 0);
+    assert_eq!(gf16::INV_TABLE[0], 0);
+}
+
+#[test]
+fn test_c() {
+    let c = export_tables::<GF256>(ExportFormat::C);
+    assert!(c.starts_with("/*\n * Tables for GF(2^8) generated by g2p\n * modulus: x^8 + x^4 + x^3 + x^2 + 1 (0x11d)\n"));
+    assert!(c.contains("#include <stdint.h>\n"));
+    assert!(c.contains("static const uint8_t EXP_TABLE[255] = {\n    1, 2, 4, 8, 16, 32, 64, 128, 29, 58,"));
+    assert!(c.contains("static const uint8_t LOG_TABLE[256] = {\n"));
+    assert!(c.contains("static const uint8_t INV_TABLE[256] = {\n    0, 1, 142,"));
+
+    let c = export_tables::<GF1024>(ExportFormat::C);
+    assert!(c.contains("static const uint16_t EXP_TABLE[1023] = {"));
+}
+
+#[test]
+fn test_json() {
+    let json: serde_json::Value = serde_json::from_str(&export_tables::<GF256>(ExportFormat::Json)).unwrap();
+    assert_eq!(json["bits"], 8);
+    assert_eq!(json["modulus"], 0x11d);
+    assert_eq!(json["modulus_poly"], "x^8 + x^4 + x^3 + x^2 + 1");
+    assert_eq!(json["generator"], 2);
+    let exp = json["exp"].as_array().unwrap();
+    let log = json["log"].as_array().unwrap();
+    let inv = json["inv"].as_array().unwrap();
+    assert_eq!((exp.len(), log.len(), inv.len()), (255, 256, 256));
+    for x in 1..=255 {
+        let inverse = inv[x as usize].as_u64().unwrap() as u8;
+        assert_eq!(GF256(inverse), GF256::ONE / GF256(x));
+        let l = log[x as usize].as_u64().unwrap() as usize;
+        assert_eq!(exp[l], x);
+    }
+}
+
+#[test]
+fn test_write_to() {
+    let path = std::env::temp_dir().join(format!("g2p_export_{}.h", std::process::id()));
+    write_to::<GF16, _>(&path, ExportFormat::C).unwrap();
+    let written = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(written, export_tables::<GF16>(ExportFormat::C));
+}
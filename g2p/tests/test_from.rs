@@ -0,0 +1,78 @@
+use g2p::{g2p, GaloisField};
+
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+g2p!(GF4096, 12);
+g2p!(GF4G, 32, tables: none);
+
+#[test]
+fn test_from_u8() {
+    assert_eq!(GF4096::from(200_u8).value(), 200);
+    assert_eq!(GF4096::from(255_u8), GF4096::from(255_u16));
+    assert_eq!(GF4G::from(255_u8), GF4G::from(255_u32));
+
+    let data = [1_u8, 2, 3, 250];
+    let lifted: Vec<GF4096> = data.iter().map(|&b| b.into()).collect();
+    assert_eq!(lifted, [1, 2, 3, 250]);
+}
+
+#[test]
+fn test_from_bool() {
+    assert_eq!(GF256::from(true), GF256::ONE);
+    assert_eq!(GF256::from(false), GF256::ZERO);
+    assert_eq!(GF16::from(true), GF16::ONE);
+    assert_eq!(GF4096::from(true), GF4096::ONE);
+    assert_eq!(GF4G::from(false), GF4G::ZERO);
+}
+
+#[test]
+fn test_into_wider() {
+    // u8 backed
+    assert_eq!(u8::from(GF256(200)), 200);
+    assert_eq!(u16::from(GF256(200)), 200);
+    assert_eq!(u32::from(GF256(200)), 200);
+    assert_eq!(u64::from(GF256(200)), 200);
+    assert_eq!(u128::from(GF256(200)), 200);
+    assert_eq!(usize::from(GF256(200)), 200);
+    let x: u64 = GF16(7).into();
+    assert_eq!(x, 7);
+
+    // u16 backed
+    assert_eq!(u16::from(GF4096(4000)), 4000);
+    assert_eq!(u32::from(GF4096(4000)), 4000);
+    assert_eq!(u64::from(GF4096(4000)), 4000);
+    assert_eq!(u128::from(GF4096(4000)), 4000);
+    assert_eq!(usize::from(GF4096(4000)), 4000);
+
+    // u32 backed
+    assert_eq!(u32::from(GF4G(0xdead_beef)), 0xdead_beef);
+    assert_eq!(u64::from(GF4G(0xdead_beef)), 0xdead_beef);
+    assert_eq!(u128::from(GF4G(0xdead_beef)), 0xdead_beef);
+}
+
+#[test]
+fn test_into_masks() {
+    // The conversions return the value of the element, without bits above the field
+    assert_eq!(u8::from(GF16(0xf3)), 3);
+    assert_eq!(u64::from(GF16(0xf3)), 3);
+    assert_eq!(usize::from(GF16(0xf3)), GF16(0xf3).as_usize());
+    assert_eq!(u16::from(GF4096(0xffff)), 0xfff);
+    assert_eq!(u32::from(GF4096(0xffff)), 0xfff);
+}
+
+#[test]
+fn test_into_generic() {
+    // Conversions can be used through trait bounds, e.g. to index tables
+    fn index<F: GaloisField + Into<usize>>(table: &[u32], x: F) -> u32 {
+        table[x.into()]
+    }
+    let table: Vec<u32> = (0..4096).collect();
+    assert_eq!(index(&table, GF16(9)), 9);
+    assert_eq!(index(&table, GF4096(4095)), 4095);
+
+    fn sum_u64<T: Into<u64>>(xs: impl IntoIterator<Item = T>) -> u64 {
+        xs.into_iter().map(Into::into).sum()
+    }
+    assert_eq!(sum_u64([GF256(1), GF256(2), GF256(255)]), 258);
+    assert_eq!(sum_u64([GF4G(u32::MAX), GF4G(1)]), 1 << 32);
+}
@@ -10,10 +10,10 @@ g2p!(GF131072, 17);
 
 #[test]
 fn test_gf131072() {
-    let z: GF131072 = 0.into();
-    let e: GF131072 = 1.into();
-    let a: GF131072 = 131071.into();
-    let b: GF131072 = 30000.into();
+    let z: GF131072 = 0_u32.into();
+    let e: GF131072 = 1_u32.into();
+    let a: GF131072 = 131071_u32.into();
+    let b: GF131072 = 30000_u32.into();
 
     assert_eq!(z, a + a);
     assert_eq!(z, a - a);
@@ -0,0 +1,151 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, G2Poly, GaloisField};
+
+g2p!(GF2, 1);
+g2p!(GF2Loop, 1, tables: none);
+g2p!(GF2x, 1, modulus: 0b10);
+g2p!(GF16, 4);
+
+/// All elements of the field, as zero and the powers of the generator
+fn elements<F: GaloisField>() -> Vec<F> {
+    let mut res = vec![F::ZERO];
+    res.extend((0..F::SIZE - 1).map(|i| F::GENERATOR.pow(i)));
+    res
+}
+
+/// Check the field axioms and the provided methods of the trait for all elements
+fn check_field<F: GaloisField + std::fmt::Debug>() {
+    let all = elements::<F>();
+    assert_eq!(all.len(), F::SIZE);
+    for (i, a) in all.iter().enumerate() {
+        assert!(!all[..i].contains(a), "{:?} is a power of the generator twice", a);
+    }
+    assert_eq!(F::GENERATOR.pow(F::SIZE - 1), F::ONE);
+    assert_eq!(1 << F::BITS, F::SIZE);
+
+    for &a in &all {
+        assert_eq!(a + F::ZERO, a);
+        assert_eq!(a * F::ONE, a);
+        assert_eq!(a * F::ZERO, F::ZERO);
+        assert_eq!(a + a, F::ZERO);
+        assert_eq!(-a, a);
+        assert_eq!(a.pow(0), F::ONE);
+        assert_eq!(a.pow(F::SIZE), a);
+        assert_eq!(a.pow2k(F::BITS), a);
+        assert_eq!(a.sqrt() * a.sqrt(), a);
+        assert_eq!(F::ONE.pow(F::SIZE - 1), F::ONE);
+        if a != F::ZERO {
+            assert_eq!(F::ONE / a * a, F::ONE);
+            assert_eq!(a.pow(F::SIZE - 1), F::ONE);
+            assert_eq!(a.pow_signed(-1) * a, F::ONE);
+        }
+        match F::solve_artin_schreier(a) {
+            Some((x, y)) => {
+                assert!(!a.trace());
+                assert_eq!(x * x + x, a);
+                assert_eq!(y, x + F::ONE);
+            }
+            None => assert!(a.trace()),
+        }
+        for &b in &all {
+            assert_eq!(a + b, b + a);
+            assert_eq!(a * b, b * a);
+            assert_eq!(a - b, a + b);
+            if b != F::ZERO {
+                assert_eq!(a / b * b, a);
+            }
+            for &c in &all {
+                assert_eq!((a + b) + c, a + (b + c));
+                assert_eq!((a * b) * c, a * (b * c));
+                assert_eq!(a * (b + c), a * b + a * c);
+            }
+        }
+    }
+    assert_eq!(all.iter().copied().sum::<F>(), if F::SIZE == 2 { F::ONE } else { F::ZERO });
+    assert_eq!(all[1..].iter().copied().product::<F>(), F::ONE);
+}
+
+#[test]
+fn test_trait_gf2() {
+    check_field::<GF2>();
+    check_field::<GF2Loop>();
+    check_field::<GF2x>();
+    // The same checks for a larger field, to make sure they are meaningful
+    check_field::<GF16>();
+}
+
+#[test]
+fn test_constants() {
+    assert_eq!(GF2::SIZE, 2);
+    assert_eq!(GF2::BITS, 1);
+    assert_eq!(GF2::MASK, 1);
+    assert_eq!(GF2::ZERO, GF2(0));
+    assert_eq!(GF2::ONE, GF2(1));
+    assert_eq!(GF2::GENERATOR, GF2::ONE);
+    assert_eq!(GF2::MODULUS, G2Poly(0b11));
+    assert_eq!(GF2::MODULUS_POLY_STR, "x + 1");
+    assert_eq!(GF2Loop::GENERATOR, GF2Loop::ONE);
+    assert_eq!(GF2x::MODULUS, G2Poly(0b10));
+    assert_eq!(GF2x::GENERATOR, GF2x::ONE);
+    assert_eq!(GF2_mod::INV_TABLE, [0, 1]);
+}
+
+#[test]
+fn test_operations() {
+    for a in 0..2 {
+        for b in 0..2 {
+            assert_eq!(GF2(a) + GF2(b), GF2(a ^ b));
+            assert_eq!(GF2(a) - GF2(b), GF2(a ^ b));
+            assert_eq!(GF2(a) * GF2(b), GF2(a & b));
+            assert_eq!(GF2Loop(a) * GF2Loop(b), GF2Loop(a & b));
+            assert_eq!(GF2x(a) * GF2x(b), GF2x(a & b));
+        }
+        assert_eq!(GF2(a) / GF2(1), GF2(a));
+        assert_eq!(GF2Loop(a) / GF2Loop(1), GF2Loop(a));
+        assert_eq!(GF2(a).checked_div(GF2(0)), None);
+        assert_eq!(GF2(a).checked_div(GF2(1)), Some(GF2(a)));
+        assert_eq!(-GF2(a), GF2(a));
+        assert_eq!(GF2(a).pow(3), GF2(a));
+        assert_eq!(GF2(a).pow_signed(5), GF2(a));
+        assert_eq!(GF2(a).sqrt(), GF2(a));
+        assert_eq!(GF2(a).trace(), a == 1);
+    }
+    assert_eq!(GF2(1).inverse(), GF2(1));
+    assert_eq!(GF2Loop(1).inverse(), GF2Loop(1));
+    assert_eq!(GF2(1).pow_signed(-7), GF2(1));
+    assert_eq!(GF2(0).pow(0), GF2(1));
+    assert_eq!(NonZeroGF2::new(GF2(0)), None);
+    assert_eq!(NonZeroGF2::new(GF2(1)).unwrap().inverse().get(), GF2(1));
+}
+
+#[test]
+fn test_unmasked() {
+    // Bits above the field are ignored like for any other field
+    assert_eq!(GF2(0b10) * GF2(1), GF2(0));
+    assert_eq!(GF2(0b11) * GF2(0b11), GF2(1));
+    assert_eq!(GF2Loop(0b11) * GF2Loop(0b11), GF2Loop(1));
+    assert_eq!(GF2(0b11).inverse(), GF2(1));
+}
+
+#[test]
+#[should_panic(expected = "GF2: attempted 1 / 0")]
+fn test_div_by_zero() {
+    let _ = GF2(1) / GF2(0);
+}
+
+#[test]
+#[should_panic(expected = "GF2Loop: attempted 0 / 0")]
+fn test_div_by_zero_loop() {
+    let _ = GF2Loop(0) / GF2Loop(0);
+}
+
+#[test]
+#[should_panic(expected = "GF2: attempted to invert 0")]
+fn test_invert_zero() {
+    let _ = GF2(0).inverse();
+}
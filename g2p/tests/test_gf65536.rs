@@ -10,10 +10,10 @@ g2p!(GF65536, 16);
 
 #[test]
 fn test_gf65536() {
-    let z: GF65536 = 0.into();
-    let e: GF65536 = 1.into();
-    let a: GF65536 = 65535.into();
-    let b: GF65536 = 30000.into();
+    let z: GF65536 = 0_u16.into();
+    let e: GF65536 = 1_u16.into();
+    let a: GF65536 = 65535_u16.into();
+    let b: GF65536 = 30000_u16.into();
 
     assert_eq!(z, a + a);
     assert_eq!(z, a - a);
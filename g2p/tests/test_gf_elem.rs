@@ -0,0 +1,86 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, FieldError, GaloisField, GfElem, G2Poly};
+
+g2p!(GF256, 8, modulus: 0x11d);
+g2p!(GF16, 4);
+
+type Elem256 = GfElem<8, 0x11d>;
+type Elem16 = GfElem<4, 0b1_0011>;
+
+#[test]
+fn test_agrees_with_macro() {
+    for a in 0..=255_u8 {
+        let x = Elem256::new(a.into());
+        for b in 0..=255_u8 {
+            let y = Elem256::new(b.into());
+            assert_eq!((x * y).0, u64::from((GF256(a) * GF256(b)).0));
+            assert_eq!((x + y).0, u64::from((GF256(a) + GF256(b)).0));
+            if b != 0 {
+                assert_eq!((x / y).0, u64::from((GF256(a) / GF256(b)).0));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_constants() {
+    assert_eq!(Elem256::SIZE, GF256::SIZE);
+    assert_eq!(Elem256::MODULUS, G2Poly(0x11d));
+    assert_eq!(Elem256::GENERATOR.0, u64::from(GF256::GENERATOR.0));
+    assert_eq!(Elem256::BITS, 8);
+    assert_eq!(Elem256::BYTES, 1);
+    assert_eq!(Elem256::MASK, 0xff);
+    assert_eq!(Elem16::GENERATOR.0, u64::from(GF16::GENERATOR.0));
+}
+
+#[test]
+fn test_trace_and_artin_schreier() {
+    for a in 0..=255_u8 {
+        assert_eq!(Elem256::new(a.into()).trace(), GF256(a).trace());
+        let expected = GF256::solve_artin_schreier(GF256(a)).map(|(x, y)| (u64::from(x.0), u64::from(y.0)));
+        let got = Elem256::solve_artin_schreier(Elem256::new(a.into())).map(|(x, y)| (x.0, y.0));
+        assert_eq!(got, expected);
+    }
+    for a in 0..16_u8 {
+        assert_eq!(Elem16::new(a.into()).trace(), GF16(a).trace());
+    }
+    type Elem8 = GfElem<3, 0b1011>;
+    for a in 0..8 {
+        let a = Elem8::new(a);
+        if let Some((x, y)) = Elem8::solve_artin_schreier(a) {
+            assert_eq!(x * x + x, a);
+            assert_eq!(y * y + y, a);
+        } else {
+            assert!(a.trace());
+        }
+    }
+}
+
+#[test]
+fn test_inverse_and_division() {
+    for a in 1..16 {
+        let a = Elem16::new(a);
+        assert_eq!(a * a.inverse(), Elem16::ONE);
+    }
+    assert_eq!(Elem16::new(3).checked_div(Elem16::ZERO), None);
+    assert_eq!(Elem16::new(3).try_div(Elem16::ZERO), Err(FieldError::DivisionByZero));
+    assert_eq!(Elem16::new(3).try_div(Elem16::new(3)), Ok(Elem16::ONE));
+    assert_eq!(Elem16::new(3).pow(15), Elem16::ONE);
+}
+
+#[test]
+#[should_panic(expected = "GF(2^4): attempted 3 / 0")]
+fn test_div_by_zero() {
+    let _ = Elem16::new(3) / Elem16::ZERO;
+}
+
+#[test]
+fn test_formatting() {
+    assert_eq!(format!("{:?}", Elem256::new(5)), "5_GF256");
+    assert_eq!(format!("{}", Elem256::new(5)), "5");
+}
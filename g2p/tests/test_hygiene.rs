@@ -0,0 +1,60 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The generated code must not pick up items of the invoking scope
+
+#![allow(dead_code, unused_macros)]
+
+mod shadowed {
+    macro_rules! panic {
+        ($($t:tt)*) => {
+            compile_error!("shadowed panic! used by generated code")
+        };
+    }
+    macro_rules! write {
+        ($($t:tt)*) => {
+            compile_error!("shadowed write! used by generated code")
+        };
+    }
+    macro_rules! assert_eq {
+        ($($t:tt)*) => {
+            compile_error!("shadowed assert_eq! used by generated code")
+        };
+    }
+
+    pub struct From;
+    pub type Result = ();
+    pub struct Option;
+    pub trait Iterator {}
+    pub const MASK: u8 = 0;
+
+    g2p::g2p!(GF256, 8, self_test: true);
+    g2p::g2p!(GF256Slow, 8, tables: none, self_test: true);
+    g2p::g2p!(GF4096, 12, self_test: true);
+}
+
+use g2p::GaloisField;
+use shadowed::{GF256Slow, GF256, GF4096};
+
+#[test]
+fn test_shadowed_items_are_ignored() {
+    assert_eq!(GF256::MASK, 255);
+    assert_eq!(shadowed::MASK, 0);
+    assert_eq!(GF256(3) * GF256(7), GF256(9));
+    assert_eq!(GF256Slow(3) * GF256Slow(7), GF256Slow(9));
+    assert_eq!(GF256::from(3_u8), GF256(3));
+    assert_eq!(GF4096::from(3_u8) * GF4096::ONE, GF4096(3));
+    assert_eq!(format!("{} {:?}", GF256(5), GF256(5)), "5 5_GF256");
+    assert_eq!("7".parse::<GF256>(), Ok(GF256(7)));
+    assert_eq!(GF256(2).powers().take(3).collect::<Vec<_>>(), [GF256(1), GF256(2), GF256(4)]);
+    assert_eq!(GF256(2).inverse() * GF256(2), GF256::ONE);
+}
+
+#[test]
+fn test_shadowed_panic_is_ignored() {
+    assert!(std::panic::catch_unwind(|| GF256::ZERO.inverse()).is_err());
+    assert!(std::panic::catch_unwind(|| GF256Slow::ONE / GF256Slow::ZERO).is_err());
+}
@@ -0,0 +1,369 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::hash::Hash;
+use g2p::g2p;
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+g2p!(GF16, 4);
+g2p!(GF16Hash, 4, impls: [hash]);
+g2p!(GF16Bare, 4, impls: []);
+
+assert_impl_all!(GF16: Hash);
+assert_impl_all!(GF16Hash: Hash);
+assert_not_impl_any!(GF16Bare: Hash);
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::*;
+
+    assert_impl_all!(GF16: serde::Serialize, serde::de::DeserializeOwned);
+    assert_not_impl_any!(GF16Hash: serde::Serialize);
+
+    #[test]
+    fn test_serde_round_trip() {
+        for i in 0..16 {
+            let json = serde_json::to_string(&GF16(i)).unwrap();
+            assert_eq!(json, format!("\"{}\"", i));
+            assert_eq!(serde_json::from_str::<GF16>(&json).unwrap(), GF16(i));
+        }
+        let err = serde_json::from_str::<GF16>("\"16\"").unwrap_err();
+        assert!(err.to_string().contains("16 is out of range for GF16"));
+    }
+}
+
+#[cfg(feature = "rand")]
+mod rand_impls {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+
+    assert_not_impl_any!(GF16Hash: rand::distributions::Distribution<GF16Hash>);
+    assert_impl_all!(g2p::NonZeroStandard: rand::distributions::Distribution<GF16>);
+    assert_not_impl_any!(g2p::NonZeroStandard: rand::distributions::Distribution<GF16Hash>);
+
+    #[test]
+    fn test_sample() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x124);
+        let mut seen = [false; 16];
+        for _ in 0..1000 {
+            let a: GF16 = rng.gen();
+            seen[a.0 as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn test_fill_random() {
+        let mut buf = [GF16(0); 1024];
+        GF16::fill_random(&mut buf, &mut rand::rngs::StdRng::seed_from_u64(0x144));
+        assert!(buf.iter().all(|a| a.0 <= GF16::MASK));
+        let mut seen = [false; 16];
+        for a in buf.iter() {
+            seen[a.0 as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        let mut again = [GF16(0); 1024];
+        GF16::fill_random(&mut again, &mut rand::rngs::StdRng::seed_from_u64(0x144));
+        assert_eq!(buf, again);
+    }
+
+    #[test]
+    fn test_sample_nonzero() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x143);
+        let mut seen = [0_u32; 16];
+        for _ in 0..10_000 {
+            let a: GF16 = rng.sample(g2p::NonZeroStandard);
+            seen[a.0 as usize] += 1;
+        }
+        assert_eq!(seen[0], 0);
+        assert!(seen[1..].iter().all(|&count| count > 0));
+    }
+
+    g2p!(GF65536, 16);
+
+    assert_impl_all!(GF16: rand::distributions::uniform::SampleUniform);
+    assert_not_impl_any!(GF16Hash: rand::distributions::uniform::SampleUniform);
+
+    #[test]
+    fn test_gen_range() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x202);
+        let mut seen = [false; 16];
+        for _ in 0..1000 {
+            let a = rng.gen_range(GF16(3)..GF16(12));
+            assert!((3..12).contains(&a.0));
+            seen[a.0 as usize] = true;
+        }
+        // The end is excluded
+        assert!(seen[3..12].iter().all(|&s| s));
+
+        let mut seen = [false; 16];
+        for _ in 0..1000 {
+            let a = rng.gen_range(GF16(3)..=GF16(12));
+            assert!((3..=12).contains(&a.0));
+            seen[a.0 as usize] = true;
+        }
+        assert!(seen[3..=12].iter().all(|&s| s));
+
+        assert_eq!(rng.gen_range(GF16(7)..=GF16(7)), GF16(7));
+        assert_eq!(rng.gen_range(GF16(7)..GF16(8)), GF16(7));
+        for _ in 0..1000 {
+            let a = rng.gen_range(GF65536(1000)..GF65536(60000));
+            assert!((1000..60000).contains(&a.0));
+        }
+        for _ in 0..100 {
+            assert!(rng.gen_range(GF16(0)..=GF16(GF16::MASK)).0 <= GF16::MASK);
+        }
+    }
+
+    #[test]
+    fn test_uniform_distribution() {
+        use rand::distributions::{Distribution, Uniform};
+
+        let dist = Uniform::new(GF65536(0x100), GF65536(0x200));
+        let first: Vec<GF65536> = dist.sample_iter(rand::rngs::StdRng::seed_from_u64(0x203)).take(100).collect();
+        let again: Vec<GF65536> = dist.sample_iter(rand::rngs::StdRng::seed_from_u64(0x203)).take(100).collect();
+        assert_eq!(first, again);
+        assert!(first.iter().all(|a| (0x100..0x200).contains(&a.0)));
+
+        // The same as sampling the base type
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x204);
+        let mut base_rng = rand::rngs::StdRng::seed_from_u64(0x204);
+        let base = Uniform::new(0x100_u16, 0x200);
+        for _ in 0..100 {
+            assert_eq!(dist.sample(&mut rng).0, base.sample(&mut base_rng));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gen_range_empty() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x205);
+        let _ = rng.gen_range(GF16(5)..GF16(5));
+    }
+}
+
+#[cfg(feature = "num-traits")]
+mod num_traits_impls {
+    use super::*;
+    use core::ops::Mul;
+    use g2p::GaloisField;
+    use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Inv, Pow};
+    use std::convert::TryFrom;
+
+    g2p!(GF256, 8);
+
+    assert_impl_all!(GF16: Inv<Output = GF16>);
+    assert_impl_all!(&'static GF16: Inv<Output = GF16>);
+    assert_not_impl_any!(GF16Hash: Inv);
+
+    fn normalize<T: Inv<Output = T> + Mul<Output = T> + Copy>(values: &mut [T], pivot: T) {
+        let factor = pivot.inv();
+        for v in values {
+            *v = *v * factor;
+        }
+    }
+
+    #[test]
+    fn test_inv() {
+        let mut row = [GF256(3), GF256(7), GF256(200)];
+        normalize(&mut row, GF256(3));
+        assert_eq!(row[0], 1);
+        for v in 1..=255 {
+            assert_eq!(GF256(v).inv(), GF256(v).inverse());
+            assert_eq!((&GF256(v)).inv(), GF256(1) / GF256(v));
+        }
+    }
+
+    fn eval<T: Pow<u32, Output = T>>(x: T, exp: u32) -> T {
+        x.pow(exp)
+    }
+
+    #[test]
+    fn test_pow() {
+        let exponents = [0_u64, 1, 2, 7, 254, 255, 256, 510, 511, 1000, u32::MAX as u64, u64::MAX];
+        for v in 0..=255 {
+            let x = GF256(v);
+            for &e in exponents.iter() {
+                let expected = GaloisField::pow(x, e as usize);
+                assert_eq!(Pow::pow(x, e), expected);
+                assert_eq!(Pow::pow(&x, e), expected);
+                assert_eq!(Pow::pow(x, e as usize), expected);
+                if let Ok(e) = u32::try_from(e) {
+                    assert_eq!(eval(x, e), expected);
+                    assert_eq!(Pow::pow(&x, e), expected);
+                }
+            }
+        }
+    }
+
+    /// Divide the pivot row by its leading element and eliminate it from the other rows
+    ///
+    /// Returns `None` if the pivot is zero.
+    fn pivot_step<T>(rows: &mut [Vec<T>], pivot: usize) -> Option<()>
+    where
+        T: CheckedDiv + CheckedMul + CheckedSub + Copy,
+    {
+        let lead = rows[pivot][pivot];
+        for j in 0..rows[pivot].len() {
+            rows[pivot][j] = rows[pivot][j].checked_div(&lead)?;
+        }
+        for i in 0..rows.len() {
+            if i != pivot {
+                let factor = rows[i][pivot];
+                for j in 0..rows[i].len() {
+                    let sub = factor.checked_mul(&rows[pivot][j])?;
+                    rows[i][j] = rows[i][j].checked_sub(&sub)?;
+                }
+            }
+        }
+        Some(())
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        let mut rows = vec![
+            vec![GF16(3), GF16(5), GF16(1)],
+            vec![GF16(7), GF16(2), GF16(9)],
+        ];
+        assert_eq!(pivot_step(&mut rows, 0), Some(()));
+        assert_eq!(rows[0][0], 1);
+        assert_eq!(rows[1][0], 0);
+        assert_eq!(rows[0][1], GF16(5) / GF16(3));
+
+        let mut singular = vec![vec![GF16(0), GF16(1)], vec![GF16(1), GF16(1)]];
+        assert_eq!(pivot_step(&mut singular, 0), None);
+
+        for a in 0..16 {
+            for b in 0..16 {
+                assert_eq!(CheckedDiv::checked_div(&GF16(a), &GF16(b)), GF16(a).checked_div(GF16(b)));
+                assert_eq!(CheckedAdd::checked_add(&GF16(a), &GF16(b)), Some(GF16(a) + GF16(b)));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "GF256: attempted to invert 0")]
+    fn test_inv_zero() {
+        let _ = GF256(0).inv();
+    }
+}
+
+#[cfg(feature = "subtle")]
+mod subtle_impls {
+    use super::*;
+    use rand::Rng;
+    use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess};
+
+    g2p!(GF256, 8);
+
+    assert_impl_all!(GF16: ConstantTimeEq, ConditionallySelectable);
+    assert_not_impl_any!(GF16Hash: ConstantTimeEq, ConditionallySelectable);
+
+    #[test]
+    fn test_ct_eq() {
+        for a in 0..16 {
+            for b in 0..16 {
+                let (a, b) = (GF16(a), GF16(b));
+                assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ct_eq_slices() {
+        let tag = [GF16(1), GF16(7), GF16(12)];
+        assert!(bool::from(tag[..].ct_eq(&[GF16(1), GF16(7), GF16(12)])));
+        assert!(!bool::from(tag[..].ct_eq(&[GF16(1), GF16(7), GF16(13)])));
+        assert!(!bool::from(tag[..].ct_eq(&tag[..2])));
+    }
+
+    #[test]
+    fn test_conditional_select() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let (a, b) = (GF256(rng.gen()), GF256(rng.gen()));
+            for &c in [false, true].iter() {
+                let choice = Choice::from(c as u8);
+                let expected = if c { b } else { a };
+                assert_eq!(GF256::conditional_select(&a, &b, choice), expected);
+
+                let mut x = a;
+                x.conditional_assign(&b, choice);
+                assert_eq!(x, expected);
+
+                let (mut x, mut y) = (a, b);
+                GF256::conditional_swap(&mut x, &mut y, choice);
+                assert_eq!((x, y), if c { (b, a) } else { (a, b) });
+            }
+        }
+    }
+
+    /// Evaluate the polynomial with the first `len` coefficients, highest degree first, without
+    /// branching on `len`
+    fn horner_ct(coefficients: &[GF256], len: u32, x: GF256) -> GF256 {
+        let mut acc = GF256(0);
+        for (i, &c) in coefficients.iter().enumerate() {
+            let next = acc * x + c;
+            acc.conditional_assign(&next, (i as u32).ct_lt(&len));
+        }
+        acc
+    }
+
+    #[test]
+    fn test_constant_time_horner() {
+        let mut rng = rand::thread_rng();
+        let coefficients: Vec<GF256> = (0..16).map(|_| GF256(rng.gen())).collect();
+        for len in 0..=16 {
+            for _ in 0..32 {
+                let x = GF256(rng.gen());
+                let expected = coefficients[..len].iter().fold(GF256(0), |acc, &c| acc * x + c);
+                assert_eq!(horner_ct(&coefficients, len as u32, x), expected);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+mod zeroize_impls {
+    use super::*;
+    use g2p::GaloisField;
+    use zeroize::{Zeroize, Zeroizing};
+
+    g2p!(GF256, 8);
+
+    assert_impl_all!(GF16: Zeroize);
+    assert_not_impl_any!(GF16Hash: Zeroize);
+
+    #[test]
+    fn test_zeroize() {
+        let mut x = GF256(42);
+        x.zeroize();
+        assert_eq!(x, GF256::ZERO);
+
+        let mut shares: Vec<GF256> = (1..=255).map(GF256).collect();
+        shares.iter_mut().zeroize();
+        assert_eq!(shares.len(), 255);
+        assert!(shares.iter().all(|&s| s == GF256::ZERO));
+
+        let mut shares = [GF256(1), GF256(2), GF256(3)];
+        shares.zeroize();
+        assert!(shares.iter().all(|&s| s == GF256::ZERO));
+    }
+
+    #[test]
+    fn test_zeroizing_buffer() {
+        let mut shares = Zeroizing::new(vec![GF256(0); 8]);
+        for (i, s) in shares.iter_mut().enumerate() {
+            *s = GF256(i as u8 + 1);
+        }
+        assert_eq!(shares.iter().sum::<GF256>(), GF256(8));
+
+        let mut buf = Zeroizing::new([GF256(7); 4]);
+        buf.zeroize();
+        assert!(buf.iter().all(|&s| s == GF256::ZERO));
+    }
+}
@@ -0,0 +1,56 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, GaloisField};
+use rand::Rng;
+
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+
+#[test]
+fn test_histogram() {
+    let mut rng = rand::thread_rng();
+    let symbols: Vec<GF256> = (0..10_000).map(|_| GF256(rng.gen())).collect();
+
+    let mut counts = [0_u32; GF256::SIZE];
+    for &sym in &symbols {
+        counts[sym] += 1;
+    }
+    let mut manual = [0_u32; 256];
+    for &sym in &symbols {
+        manual[usize::from(u8::from(sym))] += 1;
+    }
+    assert_eq!(counts, manual);
+    assert_eq!(counts.iter().sum::<u32>(), 10_000);
+}
+
+#[test]
+fn test_small_field() {
+    let mut rng = rand::thread_rng();
+    let mut counts = [0_usize; 16];
+    let mut manual = [0_usize; 16];
+    for _ in 0..1000 {
+        let sym = GF16(rng.gen_range(0..16));
+        counts[sym] += 1;
+        manual[sym.as_usize()] += 1;
+    }
+    assert_eq!(counts, manual);
+
+    // Unused high bits are ignored, so indexing never goes out of bounds
+    let names = ["zero"; 16];
+    assert_eq!(names[GF16(0xf0)], "zero");
+}
+
+#[test]
+fn test_table_lookup() {
+    let mut squares = [GF256::ZERO; 256];
+    for v in 0..=255 {
+        let x = GF256(v);
+        squares[x] = x * x;
+    }
+    assert_eq!(squares[GF256(3)], GF256(5));
+    assert_eq!(squares[GF256::GENERATOR], GF256::GENERATOR * GF256::GENERATOR);
+}
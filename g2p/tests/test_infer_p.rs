@@ -0,0 +1,31 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, GaloisField, G2Poly};
+
+g2p!(GF256Short, modulus: 0x11d);
+g2p!(GF256Long, 8, modulus: 0x11d);
+g2p!(GF16Short, modulus: "x^4 + x + 1");
+
+#[test]
+fn test_inferred_p() {
+    assert_eq!(GF256Short::SIZE, 256);
+    assert_eq!(GF256Short::MODULUS, G2Poly(0x11d));
+    assert_eq!(GF16Short::SIZE, 16);
+    assert_eq!(GF16Short::MASK, 0b1111);
+}
+
+#[test]
+fn test_same_tables_as_long_form() {
+    for a in 0..=255 {
+        for b in 0..=255 {
+            assert_eq!((GF256Short(a) * GF256Short(b)).0, (GF256Long(a) * GF256Long(b)).0);
+        }
+        if a != 0 {
+            assert_eq!((GF256Short::ONE / GF256Short(a)).0, (GF256Long::ONE / GF256Long(a)).0);
+        }
+    }
+}
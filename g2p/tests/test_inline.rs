@@ -0,0 +1,38 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, GaloisField};
+
+g2p!(GF256, 8);
+g2p!(GF256Always, 8, inline: always);
+g2p!(GF256Never, 8, inline: never);
+g2p!(GF1024Never, 10, inline: never, tables: none);
+
+#[test]
+fn test_same_results() {
+    for a in 0..=255 {
+        for b in 0..=255 {
+            let expected = GF256(a) * GF256(b);
+            assert_eq!((GF256Always(a) * GF256Always(b)).0, expected.0);
+            assert_eq!((GF256Never(a) * GF256Never(b)).0, expected.0);
+            assert_eq!((GF256Never(a) + GF256Never(b)).0, (GF256(a) + GF256(b)).0);
+            if b != 0 {
+                assert_eq!((GF256Always(a) / GF256Always(b)).0, (GF256(a) / GF256(b)).0);
+                assert_eq!((GF256Never(a) / GF256Never(b)).0, (GF256(a) / GF256(b)).0);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_table_free() {
+    let x = GF1024Never(1000);
+    assert_eq!(x * x.inverse(), GF1024Never::ONE);
+    let mut y = x;
+    y *= x;
+    y /= x;
+    assert_eq!(y, x);
+}
@@ -0,0 +1,59 @@
+use g2p::{g2p, GaloisField};
+use rand::Rng;
+
+g2p!(GF2, 1);
+g2p!(GF4, 2);
+g2p!(GF8, 3);
+g2p!(GF256, 8);
+g2p!(GF256Slow, 8, tables: none);
+g2p!(GF4G, 32, tables: none);
+
+#[test]
+fn test_itoh_tsujii_matches_table() {
+    for v in 1..=255 {
+        let x = GF256(v);
+        assert_eq!(x.inverse_itoh_tsujii(), x.inverse());
+        assert_eq!(x * x.inverse(), GF256::ONE);
+        assert_eq!(GF256Slow(v).inverse(), GF256Slow(x.inverse().0));
+    }
+    assert_eq!(GF256::ZERO.inverse_itoh_tsujii(), GF256::ZERO);
+}
+
+#[test]
+fn test_itoh_tsujii_small_fields() {
+    assert_eq!(GF2::ONE.inverse_itoh_tsujii(), GF2::ONE);
+    for v in 1..4 {
+        assert_eq!(GF4(v) * GF4(v).inverse_itoh_tsujii(), GF4::ONE);
+    }
+    for v in 1..8 {
+        assert_eq!(GF8(v) * GF8(v).inverse_itoh_tsujii(), GF8::ONE);
+    }
+}
+
+#[test]
+fn test_inverse_table_free_large() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..1000 {
+        let x = GF4G(rng.gen_range(1..=u32::MAX));
+        assert_eq!(x * x.inverse(), GF4G::ONE);
+        assert_eq!(x.inverse().inverse(), x);
+    }
+}
+
+#[test]
+fn test_pow2k() {
+    for v in 0..=255 {
+        let x = GF256(v);
+        assert_eq!(x.square(), x * x);
+        assert_eq!(x.pow2k(0), x);
+        assert_eq!(x.pow2k(3), x.pow(8));
+        assert_eq!(x.pow2k(8), x);
+        assert_eq!(x.pow2k(11), x.pow2k(3));
+    }
+}
+
+#[test]
+#[should_panic(expected = "GF4G: attempted to invert 0")]
+fn test_inverse_zero() {
+    let _ = GF4G::ZERO.inverse();
+}
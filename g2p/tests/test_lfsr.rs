@@ -0,0 +1,64 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{berlekamp_massey, g2p, GaloisField, Lfsr};
+use rand::Rng;
+
+g2p!(GF2, 1);
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+
+fn regenerate<F: GaloisField + std::fmt::Debug>(seq: &[F]) {
+    let polynomial = berlekamp_massey(seq);
+    let len = polynomial.len() - 1;
+    assert_eq!(polynomial[0], F::ONE);
+    assert!(len <= seq.len());
+    let lfsr = Lfsr::from_connection_polynomial(&polynomial, seq[..len].to_vec());
+    assert_eq!(lfsr.take(seq.len()).collect::<Vec<_>>(), seq);
+}
+
+#[test]
+fn test_berlekamp_massey_regenerates() {
+    let mut rng = rand::thread_rng();
+    for len in 0..40 {
+        let seq: Vec<GF256> = (0..len).map(|_| GF256(rng.gen())).collect();
+        regenerate(&seq);
+        let seq: Vec<GF2> = (0..len).map(|_| GF2(rng.gen_range(0..2))).collect();
+        regenerate(&seq);
+    }
+}
+
+#[test]
+fn test_berlekamp_massey_finds_shortest() {
+    let mut rng = rand::thread_rng();
+    for taps_len in 1..6 {
+        let taps: Vec<GF16> = (0..taps_len).map(|_| GF16(rng.gen_range(1..16))).collect();
+        let state: Vec<GF16> = (0..taps_len).map(|_| GF16(rng.gen_range(0..16))).collect();
+        let seq: Vec<GF16> = Lfsr::new(taps, state).take(4 * taps_len).collect();
+        let polynomial = berlekamp_massey(&seq);
+        assert!(polynomial.len() - 1 <= taps_len);
+        regenerate(&seq);
+    }
+    assert_eq!(berlekamp_massey(&[GF16(0); 10]), vec![GF16(1)]);
+    assert_eq!(berlekamp_massey(&[GF16(5); 10]), vec![GF16(1), GF16(1)]);
+    // A single nonzero symbol at the end needs a register as long as the sequence
+    assert_eq!(berlekamp_massey(&[GF16(0), GF16(0), GF16(3)]).len(), 4);
+    regenerate(&[GF16(0), GF16(0), GF16(3)]);
+}
+
+#[test]
+fn test_maximal_length() {
+    // Connection polynomials whose reciprocals x^4 + x + 1 and x^5 + x^2 + 1 are primitive
+    let lfsr = Lfsr::from_connection_polynomial(&[GF2(1), GF2(0), GF2(0), GF2(1), GF2(1)], vec![GF2(1), GF2(0), GF2(0), GF2(0)]);
+    assert_eq!(lfsr.period(1000), Some(15));
+    let lfsr = Lfsr::from_connection_polynomial(&[GF2(1), GF2(0), GF2(0), GF2(1), GF2(0), GF2(1)], vec![GF2(0), GF2(0), GF2(0), GF2(0), GF2(1)]);
+    assert_eq!(lfsr.period(1000), Some(31));
+    assert_eq!(lfsr.period(30), None);
+
+    // Over GF(16), the connection polynomial 1 + g x has period 15
+    let lfsr = Lfsr::new(vec![GF16::GENERATOR], vec![GF16(1)]);
+    assert_eq!(lfsr.period(100), Some(15));
+}
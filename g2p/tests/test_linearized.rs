@@ -0,0 +1,97 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, GaloisField, LinearizedPoly};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+
+fn random_poly(rng: &mut StdRng, q_degree: usize) -> LinearizedPoly<GF256> {
+    LinearizedPoly::new((0..=q_degree).map(|_| GF256(rng.gen())).collect())
+}
+
+fn evaluate_ordinary<F: GaloisField>(coefficients: &[F], x: F) -> F {
+    coefficients.iter().rev().fold(F::ZERO, |acc, &c| acc * x + c)
+}
+
+#[test]
+fn test_evaluate_matches_ordinary() {
+    let mut rng = StdRng::seed_from_u64(167);
+    for q_degree in 0..6 {
+        let l = random_poly(&mut rng, q_degree);
+        let ordinary = l.to_ordinary();
+        assert_eq!(ordinary.len(), (1 << l.q_degree().unwrap()) + 1);
+        for v in 0..=255 {
+            assert_eq!(l.evaluate(GF256(v)), evaluate_ordinary(&ordinary, GF256(v)));
+        }
+    }
+    assert!(LinearizedPoly::<GF256>::zero().to_ordinary().is_empty());
+}
+
+#[test]
+fn test_linearity() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let l = random_poly(&mut rng, 4);
+    for _ in 0..1000 {
+        let (x, y) = (GF256(rng.gen()), GF256(rng.gen()));
+        assert_eq!(l.evaluate(x + y), l.evaluate(x) + l.evaluate(y));
+    }
+}
+
+#[test]
+fn test_add_and_compose() {
+    let mut rng = StdRng::seed_from_u64(2);
+    for _ in 0..20 {
+        let (da, db) = (rng.gen_range(0..5), rng.gen_range(0..5));
+        let a = random_poly(&mut rng, da);
+        let b = random_poly(&mut rng, db);
+        let sum = &a + &b;
+        let composed = &a * &b;
+        assert_eq!(composed, a.compose(&b));
+        for v in 0..=255 {
+            let x = GF256(v);
+            assert_eq!(sum.evaluate(x), a.evaluate(x) + b.evaluate(x));
+            assert_eq!(composed.evaluate(x), a.evaluate(b.evaluate(x)));
+        }
+    }
+    let a = random_poly(&mut rng, 3);
+    assert_eq!(&a + &a, LinearizedPoly::zero());
+    assert_eq!(&a * &LinearizedPoly::identity(), a);
+    assert_eq!(&LinearizedPoly::identity() * &a, a);
+    assert_eq!(&a * &LinearizedPoly::zero(), LinearizedPoly::zero());
+}
+
+#[test]
+fn test_kernel() {
+    let mut rng = StdRng::seed_from_u64(3);
+    for _ in 0..50 {
+        let q_degree = rng.gen_range(0..8);
+        let l = random_poly(&mut rng, q_degree);
+        let kernel = l.kernel();
+        for &x in &kernel {
+            assert_eq!(l.evaluate(x), GF256::ZERO);
+        }
+        let roots = (0..=255).filter(|&v| l.evaluate(GF256(v)) == GF256::ZERO).count();
+        assert_eq!(roots, 1 << kernel.len());
+        assert_eq!(l.rank() + kernel.len(), 8);
+    }
+}
+
+#[test]
+fn test_kernel_of_trace() {
+    // The trace x + x^2 + x^4 + x^8 has the trace-zero hyperplane as kernel
+    let trace = LinearizedPoly::new(vec![GF16::ONE; 4]);
+    assert_eq!(trace.rank(), 1);
+    let kernel = trace.kernel();
+    assert_eq!(kernel.len(), 3);
+    assert!(kernel.iter().all(|x| !x.trace()));
+    // x^16 + x is zero everywhere
+    let frobenius = LinearizedPoly::new(vec![GF16::ONE, GF16::ZERO, GF16::ZERO, GF16::ZERO, GF16::ONE]);
+    assert_eq!(frobenius.rank(), 0);
+    assert!(LinearizedPoly::<GF16>::identity().kernel().is_empty());
+    assert_eq!(LinearizedPoly::<GF16>::new(vec![GF16::ZERO; 3]), LinearizedPoly::zero());
+}
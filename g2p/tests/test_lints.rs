@@ -0,0 +1,28 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The generated code must not trip lints in strict crates.
+
+#![deny(warnings, missing_docs, unused, nonstandard_style, rust_2018_idioms)]
+
+use g2p::g2p;
+
+g2p!(GF16, 4);
+g2p!(GF256, 8, modulus: 0x11d, module: pub gf256, self_test: true);
+g2p!(GF65536, 16, tables: none);
+
+#[test]
+fn test_add_only() {
+    assert_eq!(GF16(1) + GF16(1), GF16(0));
+}
+
+// Lints caused by the user's choices can be suppressed
+g2p!(gf4, 2, allow_lints: [non_camel_case_types]);
+
+#[test]
+fn test_lowercase_name() {
+    assert_eq!(gf4(2) * gf4(2), gf4(3));
+}
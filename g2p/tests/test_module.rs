@@ -0,0 +1,32 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, GaloisField, G2Poly};
+
+// The same type name in two modules doesn't clash
+g2p!(Field, 4, module: pub small);
+g2p!(Field, 8, module: pub(crate) large, self_test: true);
+
+#[test]
+fn test_module_paths() {
+    use small::Field as GF16;
+
+    assert_eq!(GF16::SIZE, 16);
+    assert_eq!(large::Field::SIZE, 256);
+    assert_eq!(GF16(2) * GF16(8), GF16(3));
+    assert_eq!(<large::Field as GaloisField>::MODULUS, G2Poly(0x11d));
+}
+
+#[test]
+fn test_fields_in_function() {
+    g2p!(GF16, 4, module: gf16);
+    g2p!(GF256, 8, module: gf256);
+    use gf16::GF16;
+    use gf256::GF256;
+
+    assert_eq!(GF16::ONE / GF16(2), GF16(9));
+    assert_eq!(GF256::ONE / GF256(2), GF256(0x8e));
+}
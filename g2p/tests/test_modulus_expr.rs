@@ -0,0 +1,18 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, G2Poly};
+
+g2p!(GFSuffixed, 8, modulus: 0x1_1D_u64);
+g2p!(GFOr, 8, modulus: 0x100 | 0x1b);
+g2p!(GFShifted, modulus: (1 << 4) | (1 << 1) | 1);
+
+#[test]
+fn test_modulus_expressions() {
+    assert_eq!(GFSuffixed::MODULUS, G2Poly(0x11d));
+    assert_eq!(GFOr::MODULUS, G2Poly(0x11b));
+    assert_eq!(GFShifted::MODULUS, G2Poly(0b1_0011));
+}
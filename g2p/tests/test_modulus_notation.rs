@@ -0,0 +1,29 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, G2Poly};
+
+g2p!(GF256, 8, modulus: "x^8 + x^4 + x^3 + x^2 + 1");
+g2p!(GF256Exponents, 8, modulus: [8, 4, 3, 2, 0]);
+g2p!(GF256Hex, 8, modulus: 0x11d);
+
+#[test]
+fn test_polynomial_modulus() {
+    assert_eq!(GF256::MODULUS, G2Poly(0b1_0001_1101));
+    assert_eq!(GF256::MODULUS, "x^8 + x^4 + x^3 + x^2 + 1".parse().unwrap());
+}
+
+#[test]
+fn test_exponents_modulus() {
+    assert_eq!(GF256Exponents::MODULUS, G2Poly(0x11d));
+    assert_eq!(GF256Exponents::GENERATOR.0, GF256Hex::GENERATOR.0);
+    for a in 0..=255 {
+        assert_eq!((GF256Exponents(a) * GF256Exponents(0xca)).0, (GF256Hex(a) * GF256Hex(0xca)).0);
+        if a != 0 {
+            assert_eq!((GF256Exponents(1) / GF256Exponents(a)).0, (GF256Hex(1) / GF256Hex(a)).0);
+        }
+    }
+}
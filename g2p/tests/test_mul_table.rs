@@ -0,0 +1,37 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, G2Poly};
+use rand::Rng;
+
+g2p!(GF256, 8, modulus: 0b1_0001_1101);
+g2p!(GF4096, 12);
+g2p!(GF1M, 20);
+
+#[test]
+fn test_gf256_exhaustive() {
+    let m = GF256::MODULUS;
+    for a in 0..=255 {
+        for b in 0..=255 {
+            let expected = G2Poly(a.into()).mul_mod(G2Poly(b.into()), m);
+            assert_eq!((GF256(a) * GF256(b)).0 as u64, expected.0);
+        }
+    }
+}
+
+#[test]
+fn test_multi_byte_fields() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..10000 {
+        let (a, b): (u16, u16) = (rng.gen_range(0..4096), rng.gen_range(0..4096));
+        let expected = G2Poly(a.into()).mul_mod(G2Poly(b.into()), GF4096::MODULUS);
+        assert_eq!((GF4096(a) * GF4096(b)).0 as u64, expected.0);
+
+        let (a, b): (u32, u32) = (rng.gen_range(0..1 << 20), rng.gen_range(0..1 << 20));
+        let expected = G2Poly(a.into()).mul_mod(G2Poly(b.into()), GF1M::MODULUS);
+        assert_eq!((GF1M(a) * GF1M(b)).0 as u64, expected.0);
+    }
+}
@@ -0,0 +1,54 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, G2Poly};
+
+g2p!(GF256, 8, modulus: 0x11d);
+g2p!(name: GF256Named, degree: 8, modulus: 0x11d, generator: 0x02);
+g2p!(modulus: 0x11b, tables: none, name: GF256Aes);
+g2p!(GF256Gen, 8, modulus: 0x11b, generator: 0x03);
+g2p!(name: GF16Gen, degree: 4, generator: 0b1001, module: pub gf16);
+g2p!(GF256Size, size: 256, modulus: 0x11d);
+g2p!(name: GF1024Size, size: 1024);
+
+#[test]
+fn test_named_matches_positional() {
+    assert_eq!(GF256Named::MODULUS, GF256::MODULUS);
+    assert_eq!(GF256Named::GENERATOR.0, GF256::GENERATOR.0);
+    for a in 0..=255 {
+        for b in 0..=255 {
+            assert_eq!((GF256Named(a) * GF256Named(b)).0, (GF256(a) * GF256(b)).0);
+        }
+    }
+}
+
+#[test]
+fn test_degree_from_modulus() {
+    assert_eq!(GF256Aes::MODULUS, G2Poly(0x11b));
+    assert_eq!(GF256Aes::BITS, 8);
+    assert_eq!(GF256Aes(0x53) * GF256Aes(0xca), GF256Aes(1));
+}
+
+#[test]
+fn test_generator() {
+    assert_eq!(GF256Gen::GENERATOR, GF256Gen(3));
+    assert_eq!(gf16::GF16Gen::GENERATOR, gf16::GF16Gen(0b1001));
+    // The given generator reaches all non-zero elements
+    let mut seen = [false; 16];
+    for x in gf16::GF16Gen::GENERATOR.powers().take(15) {
+        seen[x.as_usize()] = true;
+    }
+    assert_eq!(seen.iter().filter(|&&s| s).count(), 15);
+}
+
+#[test]
+fn test_size() {
+    assert_eq!(GF256Size::BITS, 8);
+    assert_eq!(GF256Size::MODULUS, GF256::MODULUS);
+    assert_eq!((GF256Size(0x53) * GF256Size(0xca)).0, (GF256(0x53) * GF256(0xca)).0);
+    assert_eq!(GF1024Size::BITS, 10);
+    assert_eq!(<GF1024Size as g2p::GaloisField>::SIZE, 1024);
+}
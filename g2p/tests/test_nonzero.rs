@@ -0,0 +1,71 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, FieldError, GaloisField};
+use std::convert::TryFrom;
+
+g2p!(GF16, 4);
+g2p!(GF16Slow, 4, tables: none);
+g2p!(GF65536, 16);
+
+#[test]
+fn test_construction() {
+    assert_eq!(NonZeroGF16::new(GF16::ZERO), None);
+    // Only the bits of the field count
+    assert_eq!(NonZeroGF16::new(GF16(16)), None);
+    assert_eq!(NonZeroGF16::new(GF16(17)).map(NonZeroGF16::get), Some(GF16(1)));
+    assert_eq!(NonZeroGF16::new(GF16(5)).unwrap().get(), GF16(5));
+    assert_eq!(NonZeroGF16::new_unchecked(GF16(5)), NonZeroGF16::new(GF16(5)).unwrap());
+    assert_eq!(NonZeroGF16::try_from(GF16::ZERO), Err(FieldError::ZeroElement));
+    assert_eq!(FieldError::ZeroElement.to_string(), "element is zero");
+    assert_eq!(GF16::from(NonZeroGF16::try_from(GF16(3)).unwrap()), GF16(3));
+    assert_eq!(format!("{:?}", NonZeroGF16::new(GF16(3)).unwrap()), "3_NonZeroGF16");
+    assert_eq!(format!("{}", NonZeroGF16::new(GF16(3)).unwrap()), "3");
+}
+
+#[test]
+fn test_division() {
+    for a in 0..16 {
+        for b in 1..16 {
+            let nz = NonZeroGF16::new(GF16(b)).unwrap();
+            assert_eq!(GF16(a) / nz, GF16(a) / GF16(b));
+            let mut x = GF16(a);
+            x /= nz;
+            assert_eq!(x, GF16(a) / GF16(b));
+
+            let nz_slow = NonZeroGF16Slow::new(GF16Slow(b)).unwrap();
+            assert_eq!(GF16Slow(a) / nz_slow, GF16Slow(a) / GF16Slow(b));
+
+            if let Some(a) = NonZeroGF16::new(GF16(a)) {
+                assert_eq!((a / nz).get(), a.get() / nz.get());
+                assert_eq!((a * nz).get(), a.get() * nz.get());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_inverse() {
+    for v in 1..16 {
+        let nz = NonZeroGF16::new(GF16(v)).unwrap();
+        assert_eq!(nz.inverse().get() * nz.get(), GF16::ONE);
+    }
+    for &v in [1_u16, 2, 255, 256, 40000, 65535].iter() {
+        let nz = NonZeroGF65536::new(GF65536(v)).unwrap();
+        assert_eq!(nz.inverse().get() * nz.get(), GF65536::ONE);
+        let mut x = nz;
+        x *= nz;
+        x /= nz;
+        assert_eq!(x, nz);
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "NonZeroGF16::new_unchecked called with zero")]
+fn test_new_unchecked_zero() {
+    let _ = NonZeroGF16::new_unchecked(GF16::ZERO);
+}
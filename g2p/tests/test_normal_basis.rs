@@ -0,0 +1,68 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, GaloisField};
+
+g2p!(GF2, 1, impls: [normal_basis]);
+g2p!(GF16, 4, tables: none, impls: [normal_basis]);
+g2p!(GF256, 8, impls: [normal_basis]);
+g2p!(GF65536, 16, impls: [normal_basis]);
+
+macro_rules! check_exhaustive {
+    ($field:ident) => {
+        for v in 0..=$field::MASK {
+            let x = $field(v);
+            let repr = x.to_normal_basis();
+            assert!(repr <= $field::MASK);
+            assert_eq!($field::from_normal_basis(repr), x);
+            assert_eq!($field::to_normal_basis($field::from_normal_basis(v)), v);
+            assert_eq!((x * x).to_normal_basis(), $field::square_normal(repr), "Squaring {:?}", x);
+        }
+    };
+}
+
+#[test]
+fn test_gf2() {
+    check_exhaustive!(GF2);
+    assert_eq!(GF2::NORMAL_ELEMENT, GF2::ONE);
+}
+
+#[test]
+fn test_gf16() {
+    check_exhaustive!(GF16);
+}
+
+#[test]
+fn test_gf256() {
+    check_exhaustive!(GF256);
+}
+
+#[test]
+fn test_gf65536() {
+    check_exhaustive!(GF65536);
+}
+
+#[test]
+fn test_basis() {
+    // The normal element is the first basis vector, its conjugates the others
+    let mut b = GF256::NORMAL_ELEMENT;
+    for i in 0..8 {
+        assert_eq!(b.to_normal_basis(), 1 << i);
+        b = b * b;
+    }
+    // One is the sum of all basis vectors, as its trace is one in even degree
+    assert_eq!(GF256::ONE.to_normal_basis(), 0xff);
+    assert_eq!(GF256::ZERO.to_normal_basis(), 0);
+}
+
+#[test]
+fn test_linear() {
+    for a in 0..=255 {
+        for b in 0..=255 {
+            assert_eq!((GF256(a) + GF256(b)).to_normal_basis(), GF256(a).to_normal_basis() ^ GF256(b).to_normal_basis());
+        }
+    }
+}
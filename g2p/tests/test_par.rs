@@ -0,0 +1,49 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(feature = "rayon")]
+
+use g2p::{g2p, mul_add_slice, par_mul_add_slice, par_scale_slice, scale_slice, GaloisField};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+g2p!(GF256, 8);
+g2p!(GF65536, 16);
+
+/// Larger than many stripes, and not a multiple of the stripe length
+const LEN: usize = (1 << 20) + 12345;
+
+#[test]
+fn test_par_scale_slice_matches_sequential() {
+    let mut rng = StdRng::seed_from_u64(186);
+    let data: Vec<GF256> = (0..LEN).map(|_| GF256(rng.gen())).collect();
+    for &c in [GF256::ZERO, GF256::ONE, GF256(rng.gen())].iter() {
+        let mut sequential = data.clone();
+        scale_slice(c, &mut sequential);
+        let mut parallel = data.clone();
+        par_scale_slice(c, &mut parallel);
+        assert_eq!(parallel, sequential);
+    }
+}
+
+#[test]
+fn test_par_mul_add_slice_matches_sequential() {
+    let mut rng = StdRng::seed_from_u64(1860);
+    let src: Vec<GF65536> = (0..LEN).map(|_| GF65536(rng.gen())).collect();
+    let dst: Vec<GF65536> = (0..LEN).map(|_| GF65536(rng.gen())).collect();
+    for &c in [GF65536::ZERO, GF65536::ONE, GF65536(rng.gen())].iter() {
+        let mut sequential = dst.clone();
+        mul_add_slice(c, &src, &mut sequential);
+        let mut parallel = dst.clone();
+        par_mul_add_slice(c, &src, &mut parallel);
+        assert_eq!(parallel, sequential);
+    }
+}
+
+#[test]
+#[should_panic(expected = "mul_add_slice of slices with different lengths")]
+fn test_par_mul_add_slice_length_mismatch() {
+    par_mul_add_slice(GF256(2), &[GF256(1)], &mut [GF256(1), GF256(2)]);
+}
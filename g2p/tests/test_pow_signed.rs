@@ -0,0 +1,35 @@
+use g2p::{g2p, GaloisField};
+
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+
+#[test]
+fn test_pow_signed_inverse() {
+    for v in 1..=255 {
+        let x = GF256(v);
+        assert_eq!(x.pow_signed(-1), GF256::ONE / x);
+        assert_eq!(x.pow_signed(-(GF256::SIZE as i64 - 1)), GF256::ONE);
+        assert_eq!(x.pow_signed(-5) * x.pow(5), GF256::ONE);
+        assert_eq!(x.pow_signed(7), x.pow(7));
+    }
+}
+
+#[test]
+fn test_pow_signed_extreme_exponents() {
+    let g = GF16::GENERATOR;
+    assert_eq!(g.pow_signed(i64::MIN), g.pow(i64::MIN.rem_euclid(15) as usize));
+    assert_eq!(g.pow_signed(i64::MAX), g.pow((i64::MAX % 15) as usize));
+}
+
+#[test]
+fn test_pow_signed_zero() {
+    assert_eq!(GF16::ZERO.pow_signed(0), GF16::ONE);
+    assert_eq!(GF16::ZERO.pow_signed(15), GF16::ZERO);
+    assert_eq!(GF16::ZERO.pow_signed(i64::MAX), GF16::ZERO);
+}
+
+#[test]
+#[should_panic(expected = "attempted to raise 0 to the negative power -1")]
+fn test_pow_signed_zero_negative() {
+    let _ = GF16::ZERO.pow_signed(-1);
+}
@@ -0,0 +1,38 @@
+use g2p::{g2p, GaloisField};
+use rand::{Rng, SeedableRng};
+
+g2p!(GF256, 8);
+g2p!(GF4096, 12);
+
+#[test]
+fn test_generator_powers_enumerate_field() {
+    let mut seen = vec![false; GF4096::SIZE];
+    for x in GF4096::GENERATOR.powers().take(GF4096::SIZE - 1) {
+        assert!(!seen[x.as_usize()]);
+        seen[x.as_usize()] = true;
+    }
+    assert!(!seen[0]);
+    assert!(seen[1..].iter().all(|&s| s));
+}
+
+#[test]
+fn test_powers_of_zero() {
+    assert_eq!(GF256::ZERO.powers().take(4).collect::<Vec<_>>(), [1, 0, 0, 0]);
+    assert_eq!(GF256::ZERO.powers_from(0).next(), Some(GF256::ONE));
+    assert_eq!(GF256::ZERO.powers_from(255).next(), Some(GF256::ZERO));
+}
+
+#[test]
+fn test_powers_match_pow() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(142);
+    for _ in 0..100 {
+        let x = GF256(rng.gen());
+        let powers: Vec<GF256> = x.powers().take(600).collect();
+        for _ in 0..10 {
+            let i = rng.gen_range(0..600);
+            assert_eq!(powers[i], x.pow(i));
+            assert_eq!(x.powers_from(i as u64).next(), Some(x.pow(i)));
+        }
+        assert_eq!(x.powers_from(1 << 40).nth(3), Some(x.pow((1 << 40) + 3)));
+    }
+}
@@ -0,0 +1,69 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, solve_quadratic, GaloisField};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+
+fn check_gf256(a: GF256, b: GF256, c: GF256) {
+    let expected: Vec<GF256> = if a == GF256::ZERO && b == GF256::ZERO {
+        // The zero polynomial is not enumerated
+        Vec::new()
+    } else {
+        (0..=255).map(GF256).filter(|&x| a * x * x + b * x + c == GF256::ZERO).collect()
+    };
+    let mut roots = solve_quadratic(a, b, c).into_vec();
+    roots.sort_by_key(|x| x.0);
+    assert_eq!(roots, expected, "Roots of {:?} x^2 + {:?} x + {:?}", a, b, c);
+}
+
+#[test]
+fn test_degenerate() {
+    for &a in &[0, 1, 7, 200] {
+        for &b in &[0, 1, 53] {
+            for c in 0..=255 {
+                check_gf256(GF256(a), GF256(b), GF256(c));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_random() {
+    let mut rng = StdRng::seed_from_u64(166);
+    for _ in 0..10_000 {
+        check_gf256(GF256(rng.gen()), GF256(rng.gen()), GF256(rng.gen()));
+    }
+}
+
+#[test]
+fn test_exhaustive_gf16() {
+    for a in 0..16 {
+        for b in 0..16 {
+            for c in 0..16 {
+                let (a, b, c) = (GF16(a), GF16(b), GF16(c));
+                let roots = solve_quadratic(a, b, c);
+                for &x in &roots {
+                    assert_eq!(a * x * x + b * x + c, GF16::ZERO);
+                }
+                if a != GF16::ZERO || b != GF16::ZERO {
+                    let count = (0..16).filter(|&x| a * GF16(x) * GF16(x) + b * GF16(x) + c == GF16::ZERO).count();
+                    assert_eq!(roots.len(), count);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sqrt() {
+    for v in 0..=255 {
+        let x = GF256(v);
+        assert_eq!(x.sqrt() * x.sqrt(), x);
+    }
+}
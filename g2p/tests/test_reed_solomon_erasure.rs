@@ -0,0 +1,88 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(feature = "reed-solomon-erasure")]
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use reed_solomon_erasure::{Field, ReedSolomon};
+
+g2p::g2p!(GF256, 8, impls: [reed_solomon_erasure]);
+g2p::g2p!(GF65536, 16, impls: [reed_solomon_erasure]);
+
+fn round_trip<F>(data_shards: usize, parity_shards: usize, random: impl Fn(&mut StdRng) -> F::Elem)
+where
+    F: Field,
+{
+    let mut rng = StdRng::seed_from_u64(185);
+    let rs = ReedSolomon::<F>::new(data_shards, parity_shards).unwrap();
+    let mut shards: Vec<Vec<F::Elem>> = (0..data_shards + parity_shards)
+        .map(|_| (0..64).map(|_| random(&mut rng)).collect())
+        .collect();
+    rs.encode(&mut shards).unwrap();
+    assert!(rs.verify(&shards).unwrap());
+
+    let mut received: Vec<Option<Vec<F::Elem>>> = shards.iter().cloned().map(Some).collect();
+    for i in 0..parity_shards {
+        received[(3 * i + 1) % (data_shards + parity_shards)] = None;
+    }
+    rs.reconstruct(&mut received).unwrap();
+    let reconstructed: Vec<Vec<F::Elem>> = received.into_iter().map(Option::unwrap).collect();
+    assert_eq!(reconstructed, shards);
+}
+
+#[test]
+fn test_round_trip_gf256() {
+    round_trip::<GF256>(10, 4, |rng| rng.gen());
+}
+
+#[test]
+fn test_round_trip_gf65536() {
+    // More shards than GF(256) allows
+    round_trip::<GF65536>(260, 4, |rng| rng.gen());
+}
+
+#[test]
+fn test_slice_ops() {
+    let input: Vec<u8> = (0..=255).collect();
+    for c in [0, 1, 2, 0x53] {
+        let mut out = vec![0; 256];
+        GF256::mul_slice(c, &input, &mut out);
+        let expected: Vec<u8> = input.iter().map(|&x| (GF256(c) * GF256(x)).0).collect();
+        assert_eq!(out, expected);
+
+        let mut acc = input.clone();
+        GF256::mul_slice_add(c, &input, &mut acc);
+        let expected: Vec<u8> = input.iter().map(|&x| (GF256(x) + GF256(c) * GF256(x)).0).collect();
+        assert_eq!(acc, expected);
+    }
+}
+
+#[test]
+fn test_slice_ops_chunks() {
+    // Lengths around the chunk size of the kernels on base type slices
+    let mut rng = StdRng::seed_from_u64(187);
+    for len in [63, 64, 65, 1000] {
+        let input: Vec<u16> = (0..len).map(|_| rng.gen()).collect();
+        let acc: Vec<u16> = (0..len).map(|_| rng.gen()).collect();
+        for c in [0, 1, 0x8003] {
+            let mut out = vec![7; len];
+            GF65536::mul_slice(c, &input, &mut out);
+            let expected: Vec<u16> = input.iter().map(|&x| (GF65536(c) * GF65536(x)).0).collect();
+            assert_eq!(out, expected);
+
+            let mut out = acc.clone();
+            GF65536::mul_slice_add(c, &input, &mut out);
+            let expected: Vec<u16> = acc.iter().zip(&input).map(|(&a, &x)| (GF65536(a) + GF65536(c) * GF65536(x)).0).collect();
+            assert_eq!(out, expected);
+        }
+
+        let input: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+        let mut out = input.clone();
+        GF256::mul_slice_add(0x1d, &input, &mut out);
+        let expected: Vec<u8> = input.iter().map(|&x| (GF256(x) + GF256(0x1d) * GF256(x)).0).collect();
+        assert_eq!(out, expected);
+    }
+}
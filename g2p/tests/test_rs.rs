@@ -0,0 +1,155 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{decode_berlekamp_welch, g2p, rs_generator_matrix, GaloisField, Matrix, RsError};
+use rand::Rng;
+
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+
+/// All subsets of `0..n` with `k` elements, in lexicographic order
+fn subsets(n: usize, k: usize) -> Vec<Vec<usize>> {
+    if k == 0 {
+        return vec![vec![]];
+    }
+    let mut res = Vec::new();
+    for last in k - 1..n {
+        for mut s in subsets(last, k - 1) {
+            s.push(last);
+            res.push(s);
+        }
+    }
+    res
+}
+
+#[test]
+fn test_systematic() {
+    let g = rs_generator_matrix::<GF256>(3, 7).unwrap();
+    assert_eq!((g.rows(), g.cols()), (3, 7));
+    assert_eq!(g.select_columns(&[0, 1, 2]), Matrix::identity(3));
+}
+
+#[test]
+fn test_any_k_columns_invertible() {
+    for n in 1..=8 {
+        for k in 1..=n {
+            let g = rs_generator_matrix::<GF16>(k, n).unwrap();
+            for columns in subsets(n, k) {
+                assert!(g.select_columns(&columns).inverse().is_some(), "k = {}, n = {}, columns {:?}", k, n, columns);
+            }
+        }
+    }
+    // The largest code over GF(16)
+    let g = rs_generator_matrix::<GF16>(8, 16).unwrap();
+    for columns in subsets(16, 8).iter().step_by(37) {
+        assert!(g.select_columns(columns).inverse().is_some());
+    }
+}
+
+#[test]
+fn test_decode_after_erasures() {
+    let mut rng = rand::thread_rng();
+    let (k, n) = (4, 9);
+    let g = rs_generator_matrix::<GF256>(k, n).unwrap();
+    for kept in subsets(n, k) {
+        let message: Vec<GF256> = (0..k).map(|_| GF256(rng.gen())).collect();
+        let codeword = g.mul_row_vector(&message);
+        let received: Vec<GF256> = kept.iter().map(|&i| codeword[i]).collect();
+        let decoded = g.select_columns(&kept).transpose().solve(&received).unwrap();
+        assert_eq!(decoded, message);
+    }
+}
+
+#[test]
+fn test_invalid_parameters() {
+    assert_eq!(rs_generator_matrix::<GF16>(0, 4), Err(RsError::InvalidParameters { k: 0, n: 4 }));
+    assert_eq!(rs_generator_matrix::<GF16>(5, 4), Err(RsError::InvalidParameters { k: 5, n: 4 }));
+    assert_eq!(rs_generator_matrix::<GF16>(4, 17), Err(RsError::InvalidParameters { k: 4, n: 17 }));
+    assert!(rs_generator_matrix::<GF16>(16, 16).is_ok());
+}
+
+#[test]
+fn test_matrix_inverse() {
+    let mut rng = rand::thread_rng();
+    for n in 1..8 {
+        let a = Matrix::from_vec(n, n, (0..n * n).map(|_| GF256(rng.gen())).collect());
+        match a.inverse() {
+            Some(inv) => {
+                assert_eq!(&a * &inv, Matrix::identity(n));
+                assert_eq!(&inv * &a, Matrix::identity(n));
+            }
+            None => assert!(a.solve(&vec![GF256::ONE; n]).is_none()),
+        }
+    }
+    let singular = Matrix::from_vec(2, 2, vec![GF256(2), GF256(4), GF256(1), GF256(2)]);
+    assert_eq!(singular.inverse(), None);
+}
+
+fn evaluate(coefficients: &[GF256], x: GF256) -> GF256 {
+    coefficients.iter().rev().fold(GF256::ZERO, |acc, &c| acc * x + c)
+}
+
+/// Evaluations of a random message of length `k` at `n` distinct points
+fn random_codeword(rng: &mut impl Rng, k: usize, n: usize) -> (Vec<GF256>, Vec<(GF256, GF256)>) {
+    let message: Vec<GF256> = (0..k).map(|_| GF256(rng.gen())).collect();
+    let offset: u8 = rng.gen();
+    let points = (0..n as u8).map(|i| {
+        let x = GF256(i.wrapping_add(offset));
+        (x, evaluate(&message, x))
+    }).collect();
+    (message, points)
+}
+
+/// Add a nonzero error to `count` distinct random positions
+fn corrupt(rng: &mut impl Rng, points: &mut [(GF256, GF256)], count: usize) {
+    let mut positions: Vec<usize> = (0..points.len()).collect();
+    for i in 0..count {
+        let j = rng.gen_range(i..positions.len());
+        positions.swap(i, j);
+        points[positions[i]].1 += GF256(rng.gen_range(1..=255));
+    }
+}
+
+#[test]
+fn test_berlekamp_welch_corrects_errors() {
+    let mut rng = rand::thread_rng();
+    for &(k, n) in [(1, 1), (1, 4), (3, 7), (7, 15), (8, 15), (16, 20), (5, 5)].iter() {
+        for _ in 0..500 {
+            let (message, mut points) = random_codeword(&mut rng, k, n);
+            let errors = rng.gen_range(0..=(n - k) / 2);
+            corrupt(&mut rng, &mut points, errors);
+            assert_eq!(decode_berlekamp_welch(&points, k), Ok(message));
+        }
+    }
+}
+
+#[test]
+fn test_berlekamp_welch_too_many_errors() {
+    // Every constant differs from at least three of the values, but only two errors can be
+    // corrected
+    let points: Vec<_> = [1, 1, 2, 2, 3].iter().enumerate().map(|(x, &y)| (GF256(x as u8), GF256(y))).collect();
+    assert_eq!(decode_berlekamp_welch(&points, 1), Err(RsError::Uncorrectable));
+
+    // Beyond the limit, the result is either an error or a codeword close to the input
+    let mut rng = rand::thread_rng();
+    let (k, n) = (5, 11);
+    for _ in 0..1000 {
+        let (_, mut points) = random_codeword(&mut rng, k, n);
+        corrupt(&mut rng, &mut points, (n - k) / 2 + 1);
+        if let Ok(decoded) = decode_berlekamp_welch(&points, k) {
+            let distance = points.iter().filter(|&&(x, y)| evaluate(&decoded, x) != y).count();
+            assert!(distance <= (n - k) / 2);
+        }
+    }
+}
+
+#[test]
+fn test_berlekamp_welch_invalid_input() {
+    let points = [(GF256(1), GF256(1)), (GF256(2), GF256(1)), (GF256(1), GF256(1))];
+    assert_eq!(decode_berlekamp_welch(&points, 1), Err(RsError::DuplicatePoint(2)));
+    assert_eq!(decode_berlekamp_welch(&points, 0), Err(RsError::InvalidParameters { k: 0, n: 3 }));
+    assert_eq!(decode_berlekamp_welch(&points, 4), Err(RsError::InvalidParameters { k: 4, n: 3 }));
+}
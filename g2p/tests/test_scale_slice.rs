@@ -0,0 +1,63 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, mul_add_slice, scale_slice, GaloisField};
+use rand::Rng;
+
+g2p!(GF256, 8);
+g2p!(GF65536Slow, 16, tables: none);
+
+#[test]
+fn test_scale_slice_matches_mul() {
+    let mut rng = rand::thread_rng();
+    for &len in [0, 1, 5, 64, 1000].iter() {
+        let data: Vec<GF256> = (0..len).map(|_| GF256(rng.gen())).collect();
+        let constants = [GF256::ZERO, GF256::ONE, GF256(2), GF256(rng.gen()), GF256(255)];
+        for &c in constants.iter() {
+            let mut scaled = data.clone();
+            scale_slice(c, &mut scaled);
+            let expected: Vec<GF256> = data.iter().map(|&x| x * c).collect();
+            assert_eq!(scaled, expected);
+        }
+
+        let data: Vec<GF65536Slow> = (0..len).map(|_| GF65536Slow(rng.gen())).collect();
+        let c = GF65536Slow(rng.gen());
+        let mut scaled = data.clone();
+        scale_slice(c, &mut scaled);
+        assert!(scaled.iter().zip(&data).all(|(&s, &x)| s == x * c));
+    }
+}
+
+#[test]
+fn test_scale_slice_special_constants() {
+    let mut data = [GF256(3), GF256(200), GF256(0)];
+    scale_slice(GF256::ONE, &mut data);
+    assert_eq!(data, [GF256(3), GF256(200), GF256(0)]);
+    scale_slice(GF256::ZERO, &mut data);
+    assert_eq!(data, [GF256::ZERO; 3]);
+}
+
+#[test]
+fn test_mul_add_slice_matches_mul() {
+    let mut rng = rand::thread_rng();
+    for &len in [0, 1, 5, 64, 1000].iter() {
+        let src: Vec<GF256> = (0..len).map(|_| GF256(rng.gen())).collect();
+        let dst: Vec<GF256> = (0..len).map(|_| GF256(rng.gen())).collect();
+        let constants = [GF256::ZERO, GF256::ONE, GF256(2), GF256(rng.gen())];
+        for &c in constants.iter() {
+            let mut acc = dst.clone();
+            mul_add_slice(c, &src, &mut acc);
+            let expected: Vec<GF256> = dst.iter().zip(&src).map(|(&d, &s)| d + c * s).collect();
+            assert_eq!(acc, expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "mul_add_slice of slices with different lengths")]
+fn test_mul_add_slice_length_mismatch() {
+    mul_add_slice(GF256(2), &[GF256(1)], &mut [GF256(1), GF256(2)]);
+}
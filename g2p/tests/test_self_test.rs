@@ -0,0 +1,14 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The generated axiom tests run as part of this test binary.
+
+use g2p::g2p;
+
+g2p!(GF16, 4, self_test: true);
+g2p!(GF256, 8, modulus: 0x11d, self_test: true);
+g2p!(GF16384, 14, self_test: true);
+g2p!(GF256Loop, 8, tables: none, self_test: true);
@@ -0,0 +1,101 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(feature = "serde")]
+
+use g2p::g2p;
+
+g2p!(GF16, 4);
+g2p!(GF16Number, 4, serde_repr: number);
+g2p!(GF16String, 4, serde_repr: string);
+g2p!(GF1024, 10);
+g2p!(GF1024Number, 10, serde_repr: number);
+g2p!(GF1024String, 10, serde_repr: string);
+
+macro_rules! test_round_trip {
+    ($name:ident, $field:ident, $json:expr, $bincode_len:expr) => {
+        #[test]
+        fn $name() {
+            for i in 0..=$field::MASK {
+                let a = $field(i);
+
+                let json = serde_json::to_string(&a).unwrap();
+                assert_eq!(json, $json(i));
+                assert_eq!(serde_json::from_str::<$field>(&json).unwrap(), a);
+
+                let bytes = bincode::serialize(&a).unwrap();
+                assert_eq!(bytes.len(), $bincode_len(i));
+                assert_eq!(bincode::deserialize::<$field>(&bytes).unwrap(), a);
+            }
+        }
+    };
+}
+
+fn number<T: ToString>(i: T) -> String {
+    i.to_string()
+}
+
+fn string<T: ToString>(i: T) -> String {
+    format!("\"{}\"", i.to_string())
+}
+
+// Without `serde_repr` JSON uses strings and bincode the integer
+test_round_trip!(test_auto_gf16, GF16, string, |_| 1);
+test_round_trip!(test_auto_gf1024, GF1024, string, |_| 2);
+test_round_trip!(test_number_gf16, GF16Number, number, |_| 1);
+test_round_trip!(test_number_gf1024, GF1024Number, number, |_| 2);
+// bincode writes strings as a u64 length followed by the bytes
+test_round_trip!(test_string_gf16, GF16String, string, |i: u8| 8 + number(i).len());
+test_round_trip!(test_string_gf1024, GF1024String, string, |i: u16| 8 + number(i).len());
+
+#[test]
+fn test_string_bincode() {
+    let bytes = bincode::serialize(&GF1024String(1000)).unwrap();
+    assert_eq!(bytes, bincode::serialize("1000").unwrap());
+    assert_eq!(bincode::deserialize::<GF1024String>(&bytes).unwrap(), GF1024String(1000));
+}
+
+#[test]
+fn test_auto_accepts_numbers() {
+    // Human-readable formats accept integers too, as written by earlier versions
+    assert_eq!(serde_json::from_str::<GF16>("7").unwrap(), GF16(7));
+    assert_eq!(serde_json::from_str::<GF1024>("1000").unwrap(), GF1024(1000));
+}
+
+#[test]
+fn test_forced_repr_is_strict() {
+    assert!(serde_json::from_str::<GF16Number>("\"7\"").is_err());
+    assert!(serde_json::from_str::<GF16String>("7").is_err());
+}
+
+#[test]
+fn test_out_of_range() {
+    let err = serde_json::from_str::<GF16>("\"16\"").unwrap_err();
+    assert!(err.to_string().contains("16 is out of range for GF16"));
+    let err = serde_json::from_str::<GF16>("16").unwrap_err();
+    assert!(err.to_string().contains("16 is out of range for GF16"));
+    let err = serde_json::from_str::<GF16Number>("300").unwrap_err();
+    assert!(err.to_string().contains("300 is out of range for GF16Number"));
+    let err = serde_json::from_str::<GF1024String>("\"1024\"").unwrap_err();
+    assert!(err.to_string().contains("1024 is out of range for GF1024String"));
+
+    let bytes = bincode::serialize(&16_u8).unwrap();
+    let err = bincode::deserialize::<GF16>(&bytes).unwrap_err();
+    assert!(err.to_string().contains("16 is out of range for GF16"));
+    let bytes = bincode::serialize(&2000_u16).unwrap();
+    assert!(bincode::deserialize::<GF1024Number>(&bytes).is_err());
+    let bytes = bincode::serialize("1024").unwrap();
+    assert!(bincode::deserialize::<GF1024String>(&bytes).is_err());
+}
+
+#[test]
+fn test_invalid_strings() {
+    for s in ["\"\"", "\"x\"", "\"-1\"", "\"0x3\"", "\" 3\""] {
+        assert!(serde_json::from_str::<GF16>(s).is_err(), "{}", s);
+        assert!(serde_json::from_str::<GF16String>(s).is_err(), "{}", s);
+    }
+    assert!(serde_json::from_str::<GF16>("-1").is_err());
+}
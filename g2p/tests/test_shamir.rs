@@ -0,0 +1,94 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(feature = "shamir")]
+
+use g2p::shamir::{combine, split, ShareError};
+use rand::{rngs::StdRng, SeedableRng};
+
+fn borrowed(shares: &[(u8, Vec<u8>)]) -> Vec<(u8, &[u8])> {
+    shares.iter().map(|(i, s)| (*i, &s[..])).collect()
+}
+
+/// All subsets of `0..n` with `k` elements
+fn subsets(n: usize, k: usize) -> Vec<Vec<usize>> {
+    if k == 0 {
+        return vec![vec![]];
+    }
+    let mut res = Vec::new();
+    for last in k - 1..n {
+        for mut s in subsets(last, k - 1) {
+            s.push(last);
+            res.push(s);
+        }
+    }
+    res
+}
+
+#[test]
+fn test_round_trip() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let secret = b"correct horse battery staple";
+    for &(k, n) in [(1, 1), (1, 5), (2, 2), (2, 3), (3, 5), (5, 5), (10, 254)].iter() {
+        let shares = split(secret, k, n, &mut rng);
+        assert_eq!(shares.len(), n as usize);
+        assert!(shares.iter().all(|(_, s)| s.len() == secret.len()));
+        assert_eq!(combine(&borrowed(&shares)).unwrap(), secret);
+        assert_eq!(combine(&borrowed(&shares[..k as usize])).unwrap(), secret);
+    }
+    assert_eq!(combine(&borrowed(&split(b"", 2, 3, &mut rng))).unwrap(), b"");
+}
+
+#[test]
+fn test_any_k_subset() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let secret = [0, 1, 2, 255, 42];
+    for n in 1..=6 {
+        for k in 1..=n {
+            let shares = split(&secret, k as u8, n as u8, &mut rng);
+            for subset in subsets(n, k) {
+                let chosen: Vec<(u8, &[u8])> = subset.iter().map(|&i| (shares[i].0, &shares[i].1[..])).collect();
+                assert_eq!(combine(&chosen).unwrap(), secret);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_too_few_shares() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let secret = b"sixteen byte key";
+    let shares = split(secret, 3, 5, &mut rng);
+    let recovered = combine(&borrowed(&shares[..2])).unwrap();
+    assert_eq!(recovered.len(), secret.len());
+    assert_ne!(&recovered[..], &secret[..]);
+}
+
+#[test]
+fn test_invalid_shares() {
+    assert_eq!(combine(&[]), Err(ShareError::NoShares));
+    assert_eq!(combine(&[(0, &[1][..])]), Err(ShareError::ZeroIndex));
+    assert_eq!(combine(&[(1, &[1][..]), (2, &[1][..]), (1, &[1][..])]), Err(ShareError::DuplicateIndex(1)));
+    assert_eq!(combine(&[(1, &[1][..]), (2, &[1, 2][..])]), Err(ShareError::LengthMismatch(2)));
+}
+
+#[test]
+#[should_panic(expected = "invalid threshold k = 4 for n = 3 shares")]
+fn test_threshold_above_shares() {
+    split(b"secret", 4, 3, &mut StdRng::seed_from_u64(0));
+}
+
+#[test]
+#[should_panic(expected = "invalid threshold")]
+fn test_too_many_shares() {
+    split(b"secret", 2, 255, &mut StdRng::seed_from_u64(0));
+}
+
+#[test]
+#[should_panic(expected = "invalid threshold")]
+fn test_zero_threshold() {
+    split(b"secret", 0, 3, &mut StdRng::seed_from_u64(0));
+}
@@ -0,0 +1,112 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, scale_slice, simd};
+use rand::Rng;
+
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+
+/// Around and between multiples of the vector width
+const LENGTHS: [usize; 9] = [0, 1, 7, 15, 16, 17, 31, 33, 1003];
+
+fn random_gf256(len: usize) -> Vec<GF256> {
+    let mut rng = rand::thread_rng();
+    (0..len).map(|_| GF256(rng.gen())).collect()
+}
+
+fn random_gf16(len: usize) -> Vec<GF16> {
+    let mut rng = rand::thread_rng();
+    (0..len).map(|_| GF16(rng.gen::<u8>() & 0xf)).collect()
+}
+
+#[test]
+fn test_scale_slice() {
+    for len in LENGTHS {
+        for c in [0, 1, 2, 0x53, 0xff] {
+            let c = GF256(c);
+            let data = random_gf256(len);
+            let mut out = data.clone();
+            scale_slice(c, &mut out);
+            let expected: Vec<_> = data.iter().map(|&x| c * x).collect();
+            assert_eq!(out, expected, "c = {}, len = {}", c, len);
+        }
+    }
+}
+
+#[test]
+fn test_mul_add_slice() {
+    for len in LENGTHS {
+        for c in [0, 1, 2, 0x53, 0xff] {
+            let c = GF256(c);
+            let src = random_gf256(len);
+            let dst = random_gf256(len);
+            let mut out = dst.clone();
+            simd::mul_add_slice(c, &src, &mut out);
+            let expected: Vec<_> = dst.iter().zip(&src).map(|(&d, &s)| d + c * s).collect();
+            assert_eq!(out, expected, "c = {}, len = {}", c, len);
+        }
+    }
+}
+
+#[test]
+fn test_add_assign_slice() {
+    for len in LENGTHS {
+        let src = random_gf256(len);
+        let dst = random_gf256(len);
+        let mut out = dst.clone();
+        simd::add_assign_slice(&src, &mut out);
+        let expected: Vec<_> = dst.iter().zip(&src).map(|(&d, &s)| d + s).collect();
+        assert_eq!(out, expected, "len = {}", len);
+    }
+}
+
+#[test]
+fn test_small_field() {
+    for len in LENGTHS {
+        for c in 0..16 {
+            let c = GF16(c);
+            let src = random_gf16(len);
+            let dst = random_gf16(len);
+
+            let mut scaled = src.clone();
+            scale_slice(c, &mut scaled);
+            let expected: Vec<_> = src.iter().map(|&x| c * x).collect();
+            assert_eq!(scaled, expected, "c = {}, len = {}", c, len);
+
+            let mut out = dst.clone();
+            simd::mul_add_slice(c, &src, &mut out);
+            let expected: Vec<_> = dst.iter().zip(&src).map(|(&d, &s)| d + c * s).collect();
+            assert_eq!(out, expected, "c = {}, len = {}", c, len);
+        }
+    }
+}
+
+#[test]
+fn test_matches_scalar_kernels() {
+    let mut rng = rand::thread_rng();
+    let c = GF256(rng.gen());
+    let src = random_gf256(1003);
+    let dst = random_gf256(1003);
+
+    let mut a = dst.clone();
+    let mut b = dst;
+    simd::mul_add_slice(c, &src, &mut a);
+    g2p::mul_add_slice(c, &src, &mut b);
+    assert_eq!(a, b);
+
+    scale_slice(c, &mut a);
+    g2p::scale_slice(c, &mut b);
+    assert_eq!(a, b);
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn test_length_mismatch() {
+    let src = [GF256(1); 17];
+    let mut dst = [GF256(1); 16];
+    simd::mul_add_slice(GF256(2), &src, &mut dst);
+}
@@ -0,0 +1,39 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, GaloisField, G2Poly};
+
+g2p!(GF256, 8);
+
+#[inline(never)]
+fn mul_table_addr() -> *const u8 {
+    GF256_mod::MUL_TABLE.as_ptr() as *const u8
+}
+
+#[inline(never)]
+fn inv_table_addr() -> *const u8 {
+    GF256_mod::INV_TABLE.as_ptr()
+}
+
+#[test]
+fn test_tables_have_one_address() {
+    assert_eq!(mul_table_addr(), GF256_mod::MUL_TABLE.as_ptr() as *const u8);
+    assert_eq!(inv_table_addr(), GF256_mod::INV_TABLE.as_ptr());
+    assert_eq!(mul_table_addr(), mul_table_addr());
+}
+
+#[test]
+fn test_arithmetic_from_tables() {
+    for a in 0..=255 {
+        for b in 0..=255 {
+            let expected = G2Poly(a).mul_mod(G2Poly(b), GF256::MODULUS);
+            assert_eq!(u64::from((GF256(a as u8) * GF256(b as u8)).0), expected.0);
+        }
+        if a != 0 {
+            assert_eq!(GF256(a as u8) * (GF256::ONE / GF256(a as u8)), GF256::ONE);
+        }
+    }
+}
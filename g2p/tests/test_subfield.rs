@@ -0,0 +1,97 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::convert::TryFrom;
+
+use g2p::{g2p, FieldError, GaloisField};
+
+g2p!(GF2, 1);
+g2p!(GF4, 2);
+g2p!(GF16, 4);
+g2p!(GF256, 8, modulus: 0x11d, contains: GF16);
+g2p!(GF256Aes, 8, modulus: 0x11b, contains: GF4);
+// Fields of the same degree are isomorphic
+g2p!(GF256Iso, 8, modulus: 0x12b, contains: GF256);
+g2p!(GF65536, 16, contains: GF256, module: pub gf65536);
+g2p!(GF4G, 32, tables: none, contains: GF2);
+
+mod nested {
+    g2p::g2p!(GF4096, 12, contains: super::GF16);
+}
+
+/// Check that `From` preserves the operations and `TryFrom` inverts it on its image
+macro_rules! check_embedding {
+    ($sub:ident, $field:ty) => {{
+        let mut image = vec![false; <$field as GaloisField>::SIZE];
+        for a in 0..$sub::SIZE {
+            let a = $sub(a as _);
+            let x = <$field>::from(a);
+            assert!(!image[x.as_usize()], "{:?} is not injective", a);
+            image[x.as_usize()] = true;
+            assert_eq!($sub::try_from(x), Ok(a));
+            for b in 0..$sub::SIZE {
+                let b = $sub(b as _);
+                assert_eq!(<$field>::from(a + b), x + <$field>::from(b));
+                assert_eq!(<$field>::from(a * b), x * <$field>::from(b));
+            }
+        }
+        assert_eq!(<$field>::from($sub::ZERO), <$field as GaloisField>::ZERO);
+        assert_eq!(<$field>::from($sub::ONE), <$field as GaloisField>::ONE);
+        image
+    }};
+}
+
+#[test]
+fn test_gf16_in_gf256() {
+    let image = check_embedding!(GF16, GF256);
+    // The subfield consists of the elements with x^16 = x
+    for (v, &in_image) in image.iter().enumerate() {
+        let x = GF256(v as u8);
+        assert_eq!(x.pow(16) == x, in_image);
+        match GF16::try_from(x) {
+            Ok(a) => assert_eq!(GF256::from(a), x),
+            Err(err) => {
+                assert!(!in_image);
+                assert_eq!(err, FieldError::NotInSubfield);
+            }
+        }
+    }
+    assert_eq!(image.iter().filter(|&&b| b).count(), 16);
+    assert!(GF16::try_from(GF256::GENERATOR).is_err());
+}
+
+#[test]
+fn test_other_fields() {
+    let image = check_embedding!(GF4, GF256Aes);
+    assert_eq!(image.iter().filter(|&&b| b).count(), 4);
+    let image = check_embedding!(GF256, GF256Iso);
+    assert!(image.iter().all(|&b| b));
+    let image = check_embedding!(GF16, nested::GF4096);
+    assert_eq!(image.iter().filter(|&&b| b).count(), 16);
+}
+
+#[test]
+fn test_gf256_in_gf65536() {
+    // All pairs of GF(256) would take too long in debug builds, so check a sample against
+    // every element
+    for a in 0..=255 {
+        let x = gf65536::GF65536::from(GF256(a));
+        assert_eq!(GF256::try_from(x), Ok(GF256(a)));
+        for &b in &[0, 1, 2, 0x53, 0xca, 0xff] {
+            assert_eq!(gf65536::GF65536::from(GF256(a) * GF256(b)), x * gf65536::GF65536::from(GF256(b)));
+        }
+    }
+    assert!(GF256::try_from(gf65536::GF65536(0x100)).is_err());
+}
+
+#[test]
+fn test_gf2_in_gf4g() {
+    assert_eq!(GF4G::from(GF2(0)), GF4G::ZERO);
+    assert_eq!(GF4G::from(GF2(1)), GF4G::ONE);
+    assert_eq!(GF2::try_from(GF4G(1)), Ok(GF2(1)));
+    assert_eq!(GF2::try_from(GF4G(0x8000_0000)), Err(FieldError::NotInSubfield));
+    assert_eq!(FieldError::NotInSubfield.to_string(), "element is not in the subfield");
+}
@@ -0,0 +1,76 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, GaloisField, G2Poly};
+use rand::{Rng, SeedableRng};
+
+g2p!(GF16, 4);
+g2p!(GF16Loop, 4, tables: none);
+g2p!(GF256, 8, modulus: 0x11b);
+g2p!(GF256Loop, 8, modulus: 0x11b, tables: none);
+g2p!(GF1024, 10);
+g2p!(GF1024Loop, 10, tables: none);
+g2p!(GF2p32, 32, tables: none);
+
+#[test]
+fn test_gf16_matches_tables() {
+    for a in 0..16 {
+        for b in 0..16 {
+            assert_eq!((GF16(a) * GF16(b)).0, (GF16Loop(a) * GF16Loop(b)).0);
+            if b != 0 {
+                assert_eq!((GF16(a) / GF16(b)).0, (GF16Loop(a) / GF16Loop(b)).0);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_gf256_matches_tables() {
+    for a in 0..=255 {
+        for b in 0..=255 {
+            assert_eq!((GF256(a) * GF256(b)).0, (GF256Loop(a) * GF256Loop(b)).0);
+            if b != 0 {
+                assert_eq!((GF256(a) / GF256(b)).0, (GF256Loop(a) / GF256Loop(b)).0);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_gf1024_matches_tables() {
+    for a in 0..1024 {
+        for b in 0..1024 {
+            assert_eq!((GF1024(a) * GF1024(b)).0, (GF1024Loop(a) * GF1024Loop(b)).0);
+        }
+    }
+}
+
+#[test]
+fn test_unmasked_bits_ignored() {
+    for a in 0..16 {
+        assert_eq!(GF16Loop(a | 0xf0) * GF16Loop(0xf7), GF16Loop(a) * GF16Loop(7));
+    }
+    assert_eq!(GF1024Loop(0xfc03) * GF1024Loop(2), GF1024Loop(3) * GF1024Loop(2));
+}
+
+#[test]
+#[should_panic]
+fn test_div_by_zero() {
+    let _ = GF256Loop::ONE / GF256Loop::ZERO;
+}
+
+#[test]
+fn test_gf2p32() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0x119);
+    for _ in 0..1000 {
+        let a: u32 = rng.gen();
+        let b: u32 = rng.gen_range(1..=u32::MAX);
+        let expected = G2Poly(a as u64).mul_mod(G2Poly(b as u64), GF2p32::MODULUS);
+        assert_eq!(u64::from((GF2p32(a) * GF2p32(b)).0), expected.0);
+        assert_eq!(GF2p32(a) / GF2p32(b) * GF2p32(b), GF2p32(a));
+    }
+    assert_eq!(GF2p32::SIZE as u64, 1 << 32);
+}
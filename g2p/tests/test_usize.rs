@@ -0,0 +1,37 @@
+use std::convert::TryFrom;
+
+use g2p::{g2p, FieldError, GaloisField};
+
+g2p!(GF256, 8);
+g2p!(GF1024, 10);
+
+fn index<F: GaloisField>(table: &[u32], x: F) -> u32 {
+    table[x.as_usize()]
+}
+
+#[test]
+fn test_round_trip() {
+    for i in 0..GF256::SIZE {
+        let x = GF256::try_from(i).unwrap();
+        assert_eq!(x.as_usize(), i);
+        assert_eq!(x, i as u8);
+    }
+    for i in 0..GF1024::SIZE {
+        assert_eq!(GF1024::try_from(i).unwrap().as_usize(), i);
+    }
+}
+
+#[test]
+fn test_out_of_range() {
+    assert_eq!(GF256::try_from(GF256::SIZE), Err(FieldError::OutOfRange { value: 256, mask: 0xff }));
+    assert_eq!(GF1024::try_from(GF1024::SIZE), Err(FieldError::OutOfRange { value: 1024, mask: 0x3ff }));
+    assert_eq!(GF1024::try_from(GF1024::SIZE).unwrap_err().to_string(), "1024 is out of range for the mask 0x3ff");
+    assert!(GF1024::try_from(usize::MAX).is_err());
+}
+
+#[test]
+fn test_generic_index() {
+    let table: Vec<u32> = (0..1024).map(|i| i * 3).collect();
+    assert_eq!(index(&table, GF256(200)), 600);
+    assert_eq!(index(&table, GF1024(1000)), 3000);
+}
@@ -0,0 +1,30 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use g2p::{g2p, verify, GaloisField, GfElem};
+
+g2p!(GF16, 4);
+g2p!(GF256, 8, modulus: 0x11b);
+g2p!(GF4G, 32, tables: none);
+
+verify!(GF16);
+verify!(GF256);
+verify!(GF4G);
+verify!(GfElem<8, 0x11d>);
+
+mod nested {
+    g2p::g2p!(GF1024, 10, generator: 0b1001);
+    g2p::verify!(GF1024);
+}
+
+#[test]
+fn test_verified_fields() {
+    // The checks run at compile time, this only makes sure the fields are usable
+    assert_eq!(GF256(0x53) * GF256(0xca), GF256::ONE);
+    assert_eq!(GF16::GENERATOR.powers().take(15).last(), Some(GF16(9)));
+    assert_eq!(<nested::GF1024 as GaloisField>::SIZE, 1024);
+    assert_eq!(GF4G::BITS, 32);
+}
@@ -0,0 +1,31 @@
+use g2p::{g2p, GaloisField};
+
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+g2p!(GF1024, 10);
+
+fn width<F: GaloisField>() -> (u32, u32, usize) {
+    (F::CHAR, F::BITS, F::BYTES)
+}
+
+#[test]
+fn test_constants() {
+    assert_eq!((GF16::CHAR, GF16::BITS, GF16::BYTES), (2, 4, 1));
+    assert_eq!((GF256::CHAR, GF256::BITS, GF256::BYTES), (2, 8, 1));
+    assert_eq!((GF1024::CHAR, GF1024::BITS, GF1024::BYTES), (2, 10, 2));
+
+    assert_eq!(width::<GF16>(), (2, 4, 1));
+    assert_eq!(width::<GF256>(), (2, 8, 1));
+    assert_eq!(width::<GF1024>(), (2, 10, 2));
+}
+
+#[test]
+fn test_bits() {
+    assert_eq!(GF16(0).bits(), 0);
+    assert_eq!(GF16(1).bits(), 1);
+    assert_eq!(GF16(15).bits(), 4);
+    assert_eq!(GF256(0x80).bits(), 8);
+    assert_eq!(GF1024(0x3ff).bits(), 10);
+    assert_eq!(GF1024(0x100).bits(), 9);
+    assert_eq!(GaloisField::bits(GF1024(6)), 3);
+}
@@ -0,0 +1,38 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+g2p::g2p!(GFAes, 8, modulus: 0x11b);
+g2p::g2p!(GF256, 8, modulus: 0x11d);
+
+#[test]
+fn test_xtime_aes() {
+    for a in 0..=255 {
+        let a = GFAes(a);
+        assert_eq!(a.xtime(), a * GFAes(2));
+        assert_eq!(a.mul2(), a * GFAes(2));
+        assert_eq!(a.mul3(), a * GFAes(3));
+    }
+    // From FIPS 197, section 4.2.1
+    assert_eq!(GFAes(0x57).xtime(), GFAes(0xae));
+    assert_eq!(GFAes(0xae).xtime(), GFAes(0x47));
+}
+
+#[test]
+fn test_xtime_other_modulus() {
+    for a in 0..=255 {
+        let a = GF256(a);
+        assert_eq!(a.xtime(), a * GF256(2));
+        assert_eq!(a.mul2(), a * GF256(2));
+        assert_eq!(a.mul3(), a * GF256(3));
+    }
+    assert_eq!(GF256(0x80).xtime(), GF256(0x1d));
+}
+
+#[test]
+fn test_xtime_const() {
+    const THREE_TIMES: GFAes = GFAes(0x80).mul3();
+    assert_eq!(THREE_TIMES, GFAes(0x9b));
+}
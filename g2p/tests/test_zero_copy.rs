@@ -0,0 +1,52 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![forbid(unsafe_code)]
+
+use g2p::{g2p, DecodeError};
+
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+g2p!(GF65536, 16);
+
+#[test]
+fn test_bytes_round_trip_in_place() {
+    let mut bytes: Vec<u8> = Vec::with_capacity(1000);
+    bytes.extend((0..=255).cycle().take(700));
+    let ptr = bytes.as_ptr() as usize;
+
+    let elements = GF256::vec_from_bytes(bytes);
+    assert_eq!(elements.as_ptr() as usize, ptr);
+    assert_eq!((elements.len(), elements.capacity()), (700, 1000));
+    assert!(elements.iter().enumerate().all(|(i, &e)| e == GF256((i % 256) as u8)));
+
+    let bytes = GF256::vec_into_bytes(elements);
+    assert_eq!(bytes.as_ptr() as usize, ptr);
+    assert_eq!((bytes.len(), bytes.capacity()), (700, 1000));
+    assert!(bytes.iter().enumerate().all(|(i, &b)| usize::from(b) == i % 256));
+}
+
+#[test]
+fn test_u16_round_trip_in_place() {
+    let values: Vec<u16> = (0..=u16::MAX).step_by(7).collect();
+    let copy = values.clone();
+    let (ptr, capacity) = (copy.as_ptr() as usize, copy.capacity());
+    let elements = GF65536::vec_from_u16(copy);
+    assert_eq!(elements.as_ptr() as usize, ptr);
+    assert_eq!((elements.len(), elements.capacity()), (values.len(), capacity));
+    assert!(elements.iter().zip(&values).all(|(e, &v)| e.0 == v));
+    let back = GF65536::vec_into_u16(elements);
+    assert_eq!(back.as_ptr() as usize, ptr);
+    assert_eq!(back.capacity(), capacity);
+    assert_eq!(back, values);
+}
+
+#[test]
+fn test_narrow_field_is_checked() {
+    // GF16 doesn't use all bits of a byte, so it only has the checked, copying `read_slice`
+    assert_eq!(GF16::read_slice(&[1, 15]), Ok(vec![GF16(1), GF16(15)]));
+    assert_eq!(GF16::read_slice(&[1, 16]), Err(DecodeError::OutOfRange(1)));
+}
@@ -0,0 +1,4 @@
+g2p::g2p!(GF8, 3);
+g2p::g2p!(GF256, 8, contains: GF8);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use g2p::GfElem;
+
+// x^8 + 1 = (x + 1)^8
+type Reducible = GfElem<8, 0x101>;
+
+const ONE: Reducible = Reducible::new(1);
+
+fn main() {
+    let _ = ONE;
+}
@@ -0,0 +1,3 @@
+g2p::g2p!(GF16, 4, inline: sometimes);
+
+fn main() {}
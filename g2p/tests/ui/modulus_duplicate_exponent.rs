@@ -0,0 +1,3 @@
+g2p::g2p!(GF256, 8, modulus: [8, 4, 3, 3, 0]);
+
+fn main() {}
@@ -0,0 +1,5 @@
+const AES_POLY: u64 = 0x11b;
+
+g2p::g2p!(GF256, 8, modulus: AES_POLY);
+
+fn main() {}
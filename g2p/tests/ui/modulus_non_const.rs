@@ -0,0 +1,7 @@
+fn modulus() -> u64 {
+    0x11b
+}
+
+g2p::g2p!(GF256, 8, modulus: modulus());
+
+fn main() {}
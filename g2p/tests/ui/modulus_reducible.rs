@@ -0,0 +1,3 @@
+g2p::g2p!(GF256, 8, modulus: 0x101);
+
+fn main() {}
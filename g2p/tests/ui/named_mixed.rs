@@ -0,0 +1,6 @@
+g2p::g2p!(GF16, degree: 4);
+g2p::g2p!(name: GF256, 8);
+g2p::g2p!(name: GF4, degree: 2, name: GF4);
+g2p::g2p!(GF256, 8, modulus: 0x11b, generator: 2);
+
+fn main() {}
@@ -0,0 +1,3 @@
+g2p::g2p!(GF256, 256);
+
+fn main() {}
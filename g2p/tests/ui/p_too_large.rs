@@ -0,0 +1,3 @@
+g2p::g2p!(GFHuge, 99);
+
+fn main() {}
@@ -0,0 +1,3 @@
+g2p::g2p!(GF16M, 24);
+
+fn main() {}
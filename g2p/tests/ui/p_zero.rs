@@ -0,0 +1,3 @@
+g2p::g2p!(GF1, 0);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use g2p::G2Poly;
+
+// x^8 + x^4 + x^3 + x^2 = x^2 (x^6 + x^2 + x + 1) is reducible, and 2 is not a generator mod 0x11b
+g2p::verify!(@check modulus: G2Poly(0x11c), generator: 2, mask: 0xff, bits: 8, size: 256,);
+g2p::verify!(@check modulus: G2Poly(0x11b), generator: 2, mask: 0xff, bits: 8, size: 256,);
+g2p::verify!(@check modulus: G2Poly(0x11d), generator: 2, mask: 0x1ff, bits: 8, size: 256,);
+
+fn main() {}
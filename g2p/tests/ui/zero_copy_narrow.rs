@@ -0,0 +1,5 @@
+g2p::g2p!(GF16, 4);
+
+fn main() {
+    let _ = GF16::vec_from_bytes(vec![1, 2, 3]);
+}
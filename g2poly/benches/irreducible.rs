@@ -0,0 +1,25 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+use g2poly::G2Poly;
+
+/// Find the smallest irreducible polynomial of the given degree, like the `g2p!` macro does
+fn find_modulus(degree: u64) -> G2Poly {
+    ((1 << degree) + 1..(2 << degree))
+        .map(G2Poly)
+        .find(|p| p.is_irreducible())
+        .expect("There are irreducible polynomials of any degree")
+}
+
+fn all_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("modulus_search");
+    for &degree in [8, 16, 32, 63].iter() {
+        group.bench_function(
+            BenchmarkId::new("is_irreducible", degree),
+            |bencher| bencher.iter(|| find_modulus(degree)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, all_benches);
+criterion_main!(benches);
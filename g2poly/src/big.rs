@@ -0,0 +1,507 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::{vec, vec::Vec};
+use core::{
+    ops,
+    fmt,
+    cmp,
+};
+
+use crate::G2Poly;
+
+/// Polynomial over GF(2) of arbitrary degree
+///
+/// Uses the same bit representation as [`G2Poly`], but the bits are stored in a `Vec<u64>`.
+/// Word `i` holds the coefficients `c_(64*i)` up to `c_(64*i + 63)`. The representation is
+/// always normalized, so the highest word is non-zero and the zero polynomial has no words at all.
+///
+/// All arithmetic operators are implemented on references as well as on owned values, so
+/// polynomials do not have to be cloned for every operation.
+///
+/// ```rust
+/// # use g2poly::{BigPoly, G2Poly};
+/// let a = BigPoly::from(G2Poly(0b111));
+/// let b = BigPoly::from_exponents(&[200, 1, 0]);
+/// assert_eq!(format!("{}", &a * &b), "x^202 + x^201 + x^200 + x^3 + 1");
+/// assert_eq!((&a * &b) % &a, BigPoly::ZERO);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Default)]
+pub struct BigPoly {
+    words: Vec<u64>,
+}
+
+impl BigPoly {
+    /// The constant `0` polynomial.
+    pub const ZERO: Self = BigPoly { words: Vec::new() };
+
+    /// Create a polynomial from its bit representation
+    ///
+    /// Trailing zero words are removed, so any slice of words is accepted.
+    ///
+    /// ```rust
+    /// # use g2poly::BigPoly;
+    /// let p = BigPoly::from_words(vec![0b11, 0b1, 0, 0]);
+    /// assert_eq!(p.degree(), Some(64));
+    /// assert_eq!(p.as_words(), &[0b11, 0b1]);
+    /// ```
+    pub fn from_words(words: Vec<u64>) -> Self {
+        let mut p = BigPoly { words };
+        p.normalize();
+        p
+    }
+
+    /// Create a polynomial with the given exponents set
+    ///
+    /// Exponents that are given multiple times cancel out, as `x^k + x^k == 0`.
+    ///
+    /// ```rust
+    /// # use g2poly::{BigPoly, G2Poly};
+    /// assert_eq!(BigPoly::from_exponents(&[4, 1, 0]), BigPoly::from(G2Poly(0b10011)));
+    /// ```
+    pub fn from_exponents(exponents: &[u64]) -> Self {
+        let mut words = Vec::new();
+        for &e in exponents {
+            let word = (e / 64) as usize;
+            if words.len() <= word {
+                words.resize(word + 1, 0);
+            }
+            words[word] ^= 1 << (e % 64);
+        }
+        BigPoly::from_words(words)
+    }
+
+    /// The polynomial `x^n`
+    pub fn monomial(n: u64) -> Self {
+        BigPoly::from_exponents(&[n])
+    }
+
+    /// Access the bit representation
+    ///
+    /// The returned words are normalized, i.e. the last word is never 0.
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Returns `true` for the zero polynomial
+    pub fn is_zero(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Get the degree of the polynomial
+    ///
+    /// Returns `None` for the 0 polynomial, same as [`G2Poly::degree`].
+    ///
+    /// ```rust
+    /// # use g2poly::BigPoly;
+    /// assert_eq!(BigPoly::ZERO.degree(), None);
+    /// assert_eq!(BigPoly::monomial(233).degree(), Some(233));
+    /// ```
+    pub fn degree(&self) -> Option<u64> {
+        let last = *self.words.last()?;
+        Some(64 * (self.words.len() as u64 - 1) + 63 - last.leading_zeros() as u64)
+    }
+
+    /// Convert to G2Poly if possible
+    ///
+    /// In case the value would not fit into `G2Poly`, return `None`
+    ///
+    /// ```rust
+    /// # use g2poly::{BigPoly, G2Poly};
+    /// assert_eq!(BigPoly::monomial(63).try_to_poly(), Some(G2Poly(1 << 63)));
+    /// assert_eq!(BigPoly::monomial(64).try_to_poly(), None);
+    /// ```
+    pub fn try_to_poly(&self) -> Option<G2Poly> {
+        match self.words.len() {
+            0 => Some(G2Poly::ZERO),
+            1 => Some(G2Poly(self.words[0])),
+            _ => None,
+        }
+    }
+
+    /// Calculate the square of the polynomial
+    ///
+    /// Squaring is linear over GF(2): it just spreads the coefficients apart, so this is a lot
+    /// cheaper than a general multiplication.
+    pub fn square(&self) -> BigPoly {
+        let mut words = Vec::with_capacity(2 * self.words.len());
+        for &w in &self.words {
+            let sq = (G2Poly(w) * G2Poly(w)).0;
+            words.push(sq as u64);
+            words.push((sq >> 64) as u64);
+        }
+        BigPoly::from_words(words)
+    }
+
+    /// Calculate quotient and remainder of polynomial division
+    ///
+    /// For `a.div_rem(b)` this returns `(q, r)` such that `a = q * b + r` with |r| < |b|.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is the zero polynomial.
+    ///
+    /// ```rust
+    /// # use g2poly::BigPoly;
+    /// let a = BigPoly::from_exponents(&[130, 64, 3]);
+    /// let b = BigPoly::from_exponents(&[70, 0]);
+    /// let (q, r) = a.div_rem(&b);
+    /// assert_eq!(&q * &b + r, a);
+    /// ```
+    pub fn div_rem(&self, rhs: &BigPoly) -> (BigPoly, BigPoly) {
+        let divisor_degree = rhs.degree().expect("Division by the zero polynomial");
+
+        let mut rem = self.clone();
+        let mut quotient = Vec::new();
+        while let Some(rem_degree) = rem.degree() {
+            if rem_degree < divisor_degree {
+                break;
+            }
+            let shift = rem_degree - divisor_degree;
+            let word = (shift / 64) as usize;
+            if quotient.len() <= word {
+                quotient.resize(word + 1, 0);
+            }
+            quotient[word] |= 1 << (shift % 64);
+            xor_shifted(&mut rem.words, &rhs.words, shift);
+            rem.normalize();
+        }
+
+        (BigPoly::from_words(quotient), rem)
+    }
+
+    /// Calculate the greatest common divisor of `self` and `other`
+    ///
+    /// This is the arbitrary-degree equivalent of [`gcd`](crate::gcd).
+    ///
+    /// ```rust
+    /// # use g2poly::{BigPoly, G2Poly};
+    /// let a = BigPoly::from(G2Poly(0b11011));
+    /// let b = BigPoly::from(G2Poly(0b100001));
+    /// assert_eq!(a.gcd(&b), BigPoly::from(G2Poly(0b11)));
+    /// ```
+    pub fn gcd(&self, other: &BigPoly) -> BigPoly {
+        let (mut a, mut b) = (self.clone(), other.clone());
+
+        while !b.is_zero() {
+            let new_b = &a % &b;
+            a = b;
+            b = new_b;
+        }
+        a
+    }
+
+    /// Determine if the given polynomial is irreducible.
+    ///
+    /// Like [`G2Poly::is_irreducible`] this uses Rabin's test. The powers `x^(2^i) mod f` are
+    /// computed by repeated squaring and reduction, so for a polynomial of degree `n` the test
+    /// costs `n` squarings and reductions plus one gcd per prime factor of `n`. Each of those
+    /// is quadratic in the number of words, making the whole test roughly `O(n^3 / 64)` bit
+    /// operations. In practice a degree ~1000 polynomial is checked in well under a second even
+    /// in debug builds.
+    ///
+    /// ```rust
+    /// # use g2poly::BigPoly;
+    /// // The reduction polynomial of the NIST B-233 and K-233 curves
+    /// assert!(BigPoly::from_exponents(&[233, 74, 0]).is_irreducible());
+    /// assert!(!BigPoly::from_exponents(&[233, 0]).is_irreducible());
+    /// ```
+    pub fn is_irreducible(&self) -> bool {
+        let n = match self.degree() {
+            Some(0) | None => return false,
+            Some(n) => n,
+        };
+
+        let x = BigPoly::monomial(1) % self;
+        let prime_factors = prime_factors(n);
+
+        // After iteration i, x_pow == x^(2^i) mod self
+        let mut x_pow = x.clone();
+        for i in 1..=n {
+            x_pow = x_pow.square() % self;
+
+            if prime_factors.iter().any(|&r| n / r == i) {
+                let h = &x_pow - &x;
+                if self.gcd(&h).degree() != Some(0) {
+                    return false;
+                }
+            }
+        }
+
+        x_pow == x
+    }
+
+    fn normalize(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+}
+
+/// XOR `src * x^shift` into `dst`, growing it as needed
+fn xor_shifted(dst: &mut Vec<u64>, src: &[u64], shift: u64) {
+    let word_shift = (shift / 64) as usize;
+    let bit_shift = shift % 64;
+
+    let needed = src.len() + word_shift + 1;
+    if dst.len() < needed {
+        dst.resize(needed, 0);
+    }
+
+    for (i, &w) in src.iter().enumerate() {
+        dst[i + word_shift] ^= w << bit_shift;
+        if bit_shift != 0 {
+            dst[i + word_shift + 1] ^= w >> (64 - bit_shift);
+        }
+    }
+}
+
+/// Distinct prime factors of `n`, found by trial division
+fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    let mut d = 2;
+    while d * d <= n {
+        if n % d == 0 {
+            factors.push(d);
+            while n % d == 0 {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+impl From<G2Poly> for BigPoly {
+    fn from(p: G2Poly) -> Self {
+        BigPoly::from_words(vec![p.0])
+    }
+}
+
+impl fmt::Debug for BigPoly {
+    fn fmt<'a>(&self, f: &mut fmt::Formatter<'a>) -> fmt::Result {
+        write!(f, "BigPoly {{ ")?;
+        match self.words.split_last() {
+            None => write!(f, "0")?,
+            Some((last, rest)) => {
+                write!(f, "{:b}", last)?;
+                for w in rest.iter().rev() {
+                    write!(f, "{:064b}", w)?;
+                }
+            }
+        }
+        write!(f, " }}")
+    }
+}
+
+impl fmt::Display for BigPoly {
+    /// Print the polynomial in `x^k` notation, same as [`G2Poly`]
+    fn fmt<'a>(&self, f: &mut fmt::Formatter<'a>) -> fmt::Result {
+        crate::fmt_terms(f, self.degree(), |p| self.words[(p / 64) as usize] & (1 << (p % 64)) != 0)
+    }
+}
+
+impl ops::Add<&BigPoly> for &BigPoly {
+    type Output = BigPoly;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: &BigPoly) -> BigPoly {
+        let (long, short) = if self.words.len() >= rhs.words.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+
+        let mut words = long.words.clone();
+        for (w, s) in words.iter_mut().zip(&short.words) {
+            *w ^= s;
+        }
+        BigPoly::from_words(words)
+    }
+}
+
+impl ops::Sub<&BigPoly> for &BigPoly {
+    type Output = BigPoly;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: &BigPoly) -> BigPoly {
+        self + rhs
+    }
+}
+
+impl ops::Mul<&BigPoly> for &BigPoly {
+    type Output = BigPoly;
+
+    /// Multiply two polynomials
+    ///
+    /// The words are multiplied pairwise using the carry-less `G2Poly` multiplication, then the
+    /// 128 bit partial products are added into place.
+    fn mul(self, rhs: &BigPoly) -> BigPoly {
+        if self.is_zero() || rhs.is_zero() {
+            return BigPoly::ZERO;
+        }
+
+        let mut words = vec![0; self.words.len() + rhs.words.len()];
+        for (i, &l) in self.words.iter().enumerate() {
+            if l == 0 {
+                continue;
+            }
+            for (j, &r) in rhs.words.iter().enumerate() {
+                let prod = (G2Poly(l) * G2Poly(r)).0;
+                words[i + j] ^= prod as u64;
+                words[i + j + 1] ^= (prod >> 64) as u64;
+            }
+        }
+        BigPoly::from_words(words)
+    }
+}
+
+impl ops::Div<&BigPoly> for &BigPoly {
+    type Output = BigPoly;
+
+    fn div(self, rhs: &BigPoly) -> BigPoly {
+        self.div_rem(rhs).0
+    }
+}
+
+impl ops::Rem<&BigPoly> for &BigPoly {
+    type Output = BigPoly;
+
+    fn rem(self, rhs: &BigPoly) -> BigPoly {
+        self.div_rem(rhs).1
+    }
+}
+
+macro_rules! forward_owned_binop {
+    ($($tr:ident :: $method:ident),*) => {
+        $(
+            impl ops::$tr<BigPoly> for BigPoly {
+                type Output = BigPoly;
+
+                fn $method(self, rhs: BigPoly) -> BigPoly {
+                    ops::$tr::$method(&self, &rhs)
+                }
+            }
+
+            impl ops::$tr<&BigPoly> for BigPoly {
+                type Output = BigPoly;
+
+                fn $method(self, rhs: &BigPoly) -> BigPoly {
+                    ops::$tr::$method(&self, rhs)
+                }
+            }
+
+            impl ops::$tr<BigPoly> for &BigPoly {
+                type Output = BigPoly;
+
+                fn $method(self, rhs: BigPoly) -> BigPoly {
+                    ops::$tr::$method(self, &rhs)
+                }
+            }
+        )*
+    };
+}
+
+forward_owned_binop!(Add::add, Sub::sub, Mul::mul, Div::div, Rem::rem);
+
+impl PartialOrd for BigPoly {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigPoly {
+    /// Orders by degree first, then by coefficients from the highest power downwards
+    ///
+    /// This is the same order as for [`G2Poly`]. Since the representation is normalized, a
+    /// higher degree means more words, so comparing the word count first orders by degree.
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.words.len().cmp(&other.words.len())
+            .then_with(|| self.words.iter().rev().cmp(other.words.iter().rev()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agrees_with_g2poly() {
+        let polys = [0_u64, 1, 2, 0b111, 0b1011, 0b10011, 0x11d, 0x1234, 0xffff_ffff, 0x8000_0000_0000_0001];
+
+        for &a in polys.iter() {
+            for &b in polys.iter() {
+                let (ga, gb) = (G2Poly(a), G2Poly(b));
+                let (ba, bb) = (BigPoly::from(ga), BigPoly::from(gb));
+
+                assert_eq!((&ba + &bb).try_to_poly(), Some(ga + gb));
+                assert_eq!((&ba - &bb).try_to_poly(), Some(ga - gb));
+                assert_eq!(&ba * &bb, BigPoly::from_words(vec![(ga * gb).0 as u64, ((ga * gb).0 >> 64) as u64]));
+                if b != 0 {
+                    assert_eq!((&ba % &bb).try_to_poly(), Some(ga % gb));
+                    assert_eq!((&ba / &bb).try_to_poly(), Some(ga / gb));
+                    assert_eq!(ba.gcd(&bb).try_to_poly(), Some(crate::gcd(ga, gb)));
+                }
+                assert_eq!(ba.cmp(&bb), ga.cmp(&gb));
+            }
+            assert_eq!(BigPoly::from(G2Poly(a)).degree(), G2Poly(a).degree());
+        }
+    }
+
+    #[test]
+    fn test_irreducible_agrees_with_g2poly() {
+        for i in 0..(1 << 11) {
+            let p = G2Poly(i);
+            assert_eq!(BigPoly::from(p).is_irreducible(), p.is_irreducible(), "{}", p);
+        }
+    }
+
+    #[test]
+    fn test_large_irreducible() {
+        // NIST B-233 / K-233 field polynomial
+        assert!(BigPoly::from_exponents(&[233, 74, 0]).is_irreducible());
+        // NIST B-409 / K-409 field polynomial
+        assert!(BigPoly::from_exponents(&[409, 87, 0]).is_irreducible());
+        // NIST B-571 / K-571 field polynomial
+        assert!(BigPoly::from_exponents(&[571, 10, 5, 2, 0]).is_irreducible());
+
+        // x^233 + x^74 + x + 1 is divisible by (x + 1)
+        assert!(!BigPoly::from_exponents(&[233, 74, 1, 0]).is_irreducible());
+        // Product of two irreducible polynomials
+        let a = BigPoly::from_exponents(&[127, 1, 0]);
+        let b = BigPoly::from_exponents(&[89, 38, 0]);
+        assert!(a.is_irreducible());
+        assert!(b.is_irreducible());
+        assert!(!(&a * &b).is_irreducible());
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let a = BigPoly::from_exponents(&[300, 200, 65, 64, 1]);
+        let b = BigPoly::from_exponents(&[129, 3, 0]);
+        let (q, r) = a.div_rem(&b);
+
+        assert!(r.degree() < b.degree());
+        assert_eq!(&q * &b + &r, a);
+    }
+
+    #[test]
+    fn test_square() {
+        let a = BigPoly::from_exponents(&[300, 200, 65, 64, 1, 0]);
+        assert_eq!(a.square(), &a * &a);
+        assert_eq!(a.square(), BigPoly::from_exponents(&[600, 400, 130, 128, 2, 0]));
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format!("{}", BigPoly::ZERO), "0");
+        assert_eq!(format!("{:?}", BigPoly::ZERO), "BigPoly { 0 }");
+        assert_eq!(format!("{}", BigPoly::from_exponents(&[64, 1, 0])), "x^64 + x + 1");
+        assert_eq!(format!("{:?}", BigPoly::from_exponents(&[64, 1])), format!("BigPoly {{ 1{:064b} }}", 2));
+    }
+}
@@ -0,0 +1,101 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::G2Poly;
+
+/// Conway polynomials over GF(2) for degree 1 up to 40, indexed by `degree - 1`
+///
+/// These agree with the tables published by Frank Lübeck.
+const CONWAY_POLYNOMIALS: [u64; 40] = [
+    0x3,
+    0x7,
+    0xb,
+    0x13,
+    0x25,
+    0x5b,
+    0x83,
+    0x11d,
+    0x211,
+    0x46f,
+    0x805,
+    0x10eb,
+    0x201b,
+    0x40a9,
+    0x8035,
+    0x1002d,
+    0x20009,
+    0x41403,
+    0x80027,
+    0x1006f3,
+    0x200065,
+    0x401f61,
+    0x800021,
+    0x101e6a9,
+    0x2000145,
+    0x40045d3,
+    0x80016ad,
+    0x100020e5,
+    0x20000005,
+    0x400328af,
+    0x80000009,
+    0x100008299,
+    0x200003d49,
+    0x4000199f7,
+    0x800000ca5,
+    0x1000da6163,
+    0x200000003f,
+    0x4000004727,
+    0x8000009ee5,
+    0x10000a5b12b,
+];
+
+/// Look up the Conway polynomial of the given degree
+///
+/// Conway polynomials are the standard choice of modulus in computer algebra systems such as
+/// GAP and Sage. The Conway polynomial of degree `n` is the smallest primitive polynomial of
+/// degree `n` that is compatible with the Conway polynomials of all degrees dividing `n`. Using
+/// them makes it possible to exchange field elements with those systems.
+///
+/// Returns `None` if the degree is not in the built-in table, which covers degrees 1 to 40.
+///
+/// # Example
+/// ```rust
+/// # use g2poly::{G2Poly, conway};
+/// assert_eq!(conway(8), Some(G2Poly(0b1_0001_1101)));
+/// assert_eq!(conway(0), None);
+/// ```
+pub fn conway(degree: u64) -> Option<G2Poly> {
+    let index = degree.checked_sub(1)? as usize;
+    CONWAY_POLYNOMIALS.get(index).map(|&m| G2Poly(m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_published_values() {
+        assert_eq!(conway(4), Some(G2Poly(0b1_0011)));
+        assert_eq!(conway(8), Some(G2Poly(0b1_0001_1101)));
+        assert_eq!(conway(16), Some(G2Poly(0b1_0000_0000_0010_1101)));
+
+        for &degree in [4, 8, 16].iter() {
+            let m = conway(degree).unwrap();
+            assert!(m.is_irreducible());
+            assert!(G2Poly::X.is_generator(m));
+        }
+    }
+
+    #[test]
+    fn test_table_degrees() {
+        for degree in 1..=40 {
+            let m = conway(degree).expect("Degree in table");
+            assert_eq!(m.degree(), Some(degree));
+            assert!(m.is_primitive());
+        }
+        assert_eq!(conway(41), None);
+    }
+}
@@ -0,0 +1,145 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::factors;
+
+/// Largest degree the counts are calculated for
+const MAX_DEGREE: u64 = 64;
+
+/// The Möbius function, which is zero for `n` with a square factor and otherwise `-1` to the
+/// power of the number of prime factors
+const fn moebius(mut n: u64) -> i128 {
+    let mut result = 1;
+    let mut p = 2;
+    while p * p <= n {
+        if n % p == 0 {
+            n /= p;
+            if n % p == 0 {
+                return 0;
+            }
+            result = -result;
+        }
+        p += 1;
+    }
+    if n > 1 {
+        result = -result;
+    }
+    result
+}
+
+/// Count the irreducible polynomials of the given degree
+///
+/// Uses the necklace-counting formula `1/n * sum over d | n of mu(d) * 2^(n/d)`, with `mu` the
+/// Möbius function. The result is exact for all degrees up to 64. There are no irreducible
+/// polynomials of degree 0, as constants are not irreducible.
+///
+/// # Panics
+/// Panics if `degree` is greater than 64.
+///
+/// # Example
+/// ```rust
+/// # use g2poly::count_irreducible;
+/// // x^2 + x + 1
+/// assert_eq!(count_irreducible(2), 1);
+/// assert_eq!(count_irreducible(8), 30);
+/// ```
+pub const fn count_irreducible(degree: u64) -> u64 {
+    assert!(degree <= MAX_DEGREE, "Degree must be at most 64");
+    if degree == 0 {
+        return 0;
+    }
+
+    let mut sum: i128 = 0;
+    let mut d = 1;
+    while d <= degree {
+        if degree % d == 0 {
+            sum += moebius(d) * (1 << (degree / d));
+        }
+        d += 1;
+    }
+    (sum / degree as i128) as u64
+}
+
+/// Count the primitive polynomials of the given degree
+///
+/// Each generator of the multiplicative group of GF(2^n) is the root of exactly one primitive
+/// polynomial, which has `n` such roots. So there are `phi(2^n - 1) / n` primitive polynomials,
+/// with `phi` Euler's totient function, calculated from the prime factors of `2^n - 1`. The
+/// result is exact for all degrees up to 64.
+///
+/// # Panics
+/// Panics if `degree` is greater than 64.
+///
+/// # Example
+/// ```rust
+/// # use g2poly::count_primitive;
+/// assert_eq!(count_primitive(4), 2);
+/// assert_eq!(count_primitive(8), 16);
+/// ```
+pub const fn count_primitive(degree: u64) -> u64 {
+    assert!(degree <= MAX_DEGREE, "Degree must be at most 64");
+    if degree == 0 {
+        return 0;
+    }
+
+    let mut totient = u64::MAX >> (64 - degree);
+    let primes = factors::mersenne_prime_factors(degree);
+    let mut i = 0;
+    while i < primes.len() {
+        totient = totient / primes[i] * (primes[i] - 1);
+        i += 1;
+    }
+    totient / degree
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::G2Poly;
+
+    #[test]
+    fn test_moebius() {
+        let expected = [1, -1, -1, 0, -1, 1, -1, 0, 0, 1, -1, 0, -1, 1, 1, 0];
+        for (n, &mu) in (1..).zip(expected.iter()) {
+            assert_eq!(moebius(n), mu, "mu({})", n);
+        }
+    }
+
+    #[test]
+    fn test_counts_match_enumeration() {
+        for degree in 1..=12 {
+            let polys = (1 << degree..1 << (degree + 1)).map(G2Poly);
+            let irreducible = polys.clone().filter(|p| p.is_irreducible()).count() as u64;
+            let primitive = polys.filter(|p| p.is_primitive()).count() as u64;
+            assert_eq!(count_irreducible(degree), irreducible, "degree {}", degree);
+            assert_eq!(count_primitive(degree), primitive, "degree {}", degree);
+        }
+    }
+
+    #[test]
+    fn test_large_degrees() {
+        assert_eq!(count_irreducible(0), 0);
+        assert_eq!(count_primitive(0), 0);
+        // 2^n - 1 is prime, so all irreducible polynomials are primitive
+        for &n in [2, 3, 5, 7, 13, 17, 19, 31, 61].iter() {
+            assert_eq!(count_primitive(n), count_irreducible(n));
+        }
+        assert_eq!(count_irreducible(63), 146_402_730_743_693_304);
+        assert_eq!(count_primitive(63), 122_428_597_145_960_448);
+        assert_eq!(count_irreducible(64), 288_230_376_084_602_880);
+        assert_eq!(count_primitive(64), 143_890_337_947_975_680);
+        for n in 1..=64 {
+            assert!(count_primitive(n) <= count_irreducible(n));
+            assert!(count_irreducible(n) <= u64::MAX / n);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Degree must be at most 64")]
+    fn test_degree_too_large() {
+        count_irreducible(65);
+    }
+}
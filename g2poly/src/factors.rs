@@ -0,0 +1,104 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// Distinct prime factors of `2^n - 1` for `n <= 64`, indexed by `n`
+///
+/// The multiplicative group of GF(2^n) has order `2^n - 1`, so these are needed to check the
+/// order of an element without trying all powers. Degree 64 is only used for counting.
+const MERSENNE_PRIME_FACTORS: [&[u64]; 65] = [
+    &[],
+    &[],
+    &[3],
+    &[7],
+    &[3, 5],
+    &[31],
+    &[3, 7],
+    &[127],
+    &[3, 5, 17],
+    &[7, 73],
+    &[3, 11, 31],
+    &[23, 89],
+    &[3, 5, 7, 13],
+    &[8191],
+    &[3, 43, 127],
+    &[7, 31, 151],
+    &[3, 5, 17, 257],
+    &[131071],
+    &[3, 7, 19, 73],
+    &[524287],
+    &[3, 5, 11, 31, 41],
+    &[7, 127, 337],
+    &[3, 23, 89, 683],
+    &[47, 178481],
+    &[3, 5, 7, 13, 17, 241],
+    &[31, 601, 1801],
+    &[3, 2731, 8191],
+    &[7, 73, 262657],
+    &[3, 5, 29, 43, 113, 127],
+    &[233, 1103, 2089],
+    &[3, 7, 11, 31, 151, 331],
+    &[2147483647],
+    &[3, 5, 17, 257, 65537],
+    &[7, 23, 89, 599479],
+    &[3, 43691, 131071],
+    &[31, 71, 127, 122921],
+    &[3, 5, 7, 13, 19, 37, 73, 109],
+    &[223, 616318177],
+    &[3, 174763, 524287],
+    &[7, 79, 8191, 121369],
+    &[3, 5, 11, 17, 31, 41, 61681],
+    &[13367, 164511353],
+    &[3, 7, 43, 127, 337, 5419],
+    &[431, 9719, 2099863],
+    &[3, 5, 23, 89, 397, 683, 2113],
+    &[7, 31, 73, 151, 631, 23311],
+    &[3, 47, 178481, 2796203],
+    &[2351, 4513, 13264529],
+    &[3, 5, 7, 13, 17, 97, 241, 257, 673],
+    &[127, 4432676798593],
+    &[3, 11, 31, 251, 601, 1801, 4051],
+    &[7, 103, 2143, 11119, 131071],
+    &[3, 5, 53, 157, 1613, 2731, 8191],
+    &[6361, 69431, 20394401],
+    &[3, 7, 19, 73, 87211, 262657],
+    &[23, 31, 89, 881, 3191, 201961],
+    &[3, 5, 17, 29, 43, 113, 127, 15790321],
+    &[7, 32377, 524287, 1212847],
+    &[3, 59, 233, 1103, 2089, 3033169],
+    &[179951, 3203431780337],
+    &[3, 5, 7, 11, 13, 31, 41, 61, 151, 331, 1321],
+    &[2305843009213693951],
+    &[3, 715827883, 2147483647],
+    &[7, 73, 127, 337, 92737, 649657],
+    &[3, 5, 17, 257, 641, 65537, 6700417],
+];
+
+/// Get the distinct prime factors of `2^n - 1`, in increasing order
+///
+/// # Panics
+/// Panics if `n > 64`.
+pub(crate) const fn mersenne_prime_factors(n: u64) -> &'static [u64] {
+    MERSENNE_PRIME_FACTORS[n as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_factors_multiply_out() {
+        for n in 1..=64 {
+            let mut rest = u64::MAX >> (64 - n);
+            for &p in mersenne_prime_factors(n) {
+                assert_eq!(rest % p, 0, "{} does not divide 2^{} - 1", p, n);
+                while rest % p == 0 {
+                    rest /= p;
+                }
+            }
+            assert_eq!(rest, 1, "Missing factors for 2^{} - 1", n);
+        }
+    }
+}
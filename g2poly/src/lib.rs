@@ -19,7 +19,7 @@
 //! use g2poly;
 //!
 //! let a = g2poly::G2Poly(0b10011);
-//! assert_eq!(format!("{}", a), "G2Poly { x^4 + x + 1 }");
+//! assert_eq!(format!("{}", a), "x^4 + x + 1");
 //! let b = g2poly::G2Poly(0b1);
 //! assert_eq!(a + b, g2poly::G2Poly(0b10010));
 //!
@@ -30,13 +30,45 @@
 //! let s = a * a % g2poly::G2Poly(0b1000000);
 //! assert_eq!(s, g2poly::G2Poly(0b101));
 //! ```
+//!
+//! For polynomials of higher degree, [`BigPoly`] offers the same operations backed by a
+//! `Vec<u64>`.
+//!
+//! Most of the API is `const fn`, so properties of a modulus can be checked at compile time.
+//! Since the operators can't be used in constant expressions, [`G2Poly::const_mul`],
+//! [`G2Poly::const_div`] and [`G2Poly::const_rem`] are provided in their place.
+//!
+//! ```rust
+//! use g2poly::G2Poly;
+//!
+//! const MODULUS: G2Poly = G2Poly(0x11d);
+//! const _: () = assert!(MODULUS.is_primitive());
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 use core::{
     ops,
     fmt,
-    cmp,
 };
 
+#[cfg(feature = "alloc")]
+mod big;
+mod conway;
+mod count;
+mod factors;
+mod parse;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[cfg(feature = "alloc")]
+pub use big::BigPoly;
+pub use conway::conway;
+pub use count::{count_irreducible, count_primitive};
+pub use parse::ParsePolyError;
 
 /// Main type exported by this library
 ///
@@ -45,7 +77,8 @@ use core::{
 ///
 /// ```rust
 /// # use g2poly::G2Poly;
-/// assert_eq!(format!("{}", G2Poly(0b101)), "G2Poly { x^2 + 1 }");
+/// assert_eq!(format!("{}", G2Poly(0b101)), "x^2 + 1");
+/// assert_eq!(format!("{:#}", G2Poly(0b101)), "0x5");
 /// ```
 ///
 /// 3 main operations [`+`](#impl-Add<G2Poly>), [`-`](#impl-Sub<G2Poly>) and
@@ -55,6 +88,20 @@ use core::{
 ///
 /// Division is left out as there is generally not needed for common use cases. This may change in a
 /// later release.
+///
+/// # Ordering
+/// Polynomials are ordered by degree first, then by their coefficients from the highest power
+/// downwards, with the zero polynomial being the smallest. Since a higher degree means a higher
+/// bit is set, this is exactly the order of the inner `u64`, which is how it is implemented.
+/// Sorting a list of polynomials therefore groups them by degree:
+///
+/// ```rust
+/// # use g2poly::G2Poly;
+/// let mut polys = vec![G2Poly(0b1_0011), G2Poly(0b111), G2Poly(0), G2Poly(0b1011), G2Poly(0b10)];
+/// polys.sort();
+/// let degrees: Vec<_> = polys.iter().map(|p| p.degree()).collect();
+/// assert_eq!(degrees, vec![None, Some(1), Some(2), Some(3), Some(4)]);
+/// ```
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
 pub struct G2Poly(pub u64);
 
@@ -90,8 +137,11 @@ impl G2PolyProd {
     /// // Next line would panics!
     /// // (G2Poly(0x40_00_00_00_00_00_00_00) * G2Poly(4)).to_poly();
     /// ```
-    pub fn to_poly(self) -> G2Poly {
-        self.try_to_poly().expect("Tried to convert product bigger than G2Poly max")
+    pub const fn to_poly(self) -> G2Poly {
+        match self.try_to_poly() {
+            Some(p) => p,
+            None => panic!("Tried to convert product bigger than G2Poly max"),
+        }
     }
 
     /// Convert to G2Poly if possible
@@ -104,13 +154,38 @@ impl G2PolyProd {
     /// assert_eq!((G2Poly(0x40_00_00_00_00_00_00_00) * G2Poly(2)).try_to_poly(), Some(G2Poly(0x80_00_00_00_00_00_00_00)));
     /// assert_eq!((G2Poly(0x40_00_00_00_00_00_00_00) * G2Poly(4)).try_to_poly(), None);
     /// ```
-    pub fn try_to_poly(self) -> Option<G2Poly> {
-        if self.0 <= u64::max_value() as u128 {
+    pub const fn try_to_poly(self) -> Option<G2Poly> {
+        if self.0 <= u64::MAX as u128 {
             Some(G2Poly(self.0 as u64))
         } else {
             None
         }
     }
+
+    /// Calculate the polynomial remainder, usable in const contexts
+    ///
+    /// This is the same as [`%`](#impl-Rem<G2Poly>-for-G2PolyProd).
+    ///
+    /// # Panics
+    /// Panics if `rhs` is the zero polynomial.
+    pub const fn const_rem(self, rhs: G2Poly) -> G2Poly {
+        let module = rhs.0 as u128;
+        let mod_degree_p1 = 128 - module.leading_zeros();
+        assert!(mod_degree_p1 > 0);
+
+        let mut rem = self.0;
+        let mut rem_degree_p1 = 128 - rem.leading_zeros();
+
+
+        while mod_degree_p1 <= rem_degree_p1 {
+            let shift_len = rem_degree_p1 - mod_degree_p1;
+            rem ^= module << shift_len;
+            rem_degree_p1 = 128 - rem.leading_zeros();
+        }
+
+        // NB: rem_degree < mod_degree implies that rem < mod so it fits in u64
+        G2Poly(rem as u64)
+    }
 }
 
 impl fmt::Debug for G2Poly {
@@ -126,56 +201,70 @@ impl fmt::Debug for G2PolyProd {
 }
 
 impl fmt::Display for G2Poly {
+    /// Print the polynomial in `x^k` notation
+    ///
+    /// With the alternate flag (`{:#}`), the bit representation is printed as hex instead.
     fn fmt<'a>(&self, f: &mut fmt::Formatter<'a>) -> fmt::Result {
-        if self.0 == 0 {
-            return write!(f, "G2Poly {{ 0 }}");
-        }
-
-        write!(f, "G2Poly {{ ")?;
-        let start = 63 - self.0.leading_zeros();
-        let mut check = 1 << start;
-        let mut append = false;
-        for p in (0..=start).rev() {
-            if check & self.0 > 0 {
-                if append {
-                    write!(f, " + ")?;
-                }
-
-                if p == 0 {
-                    write!(f, "1")?;
-                } else if p == 1 {
-                    write!(f, "x")?;
-                } else {
-                    write!(f, "x^{}", p)?;
-                }
-                append = true;
-            }
-            check >>= 1;
+        if f.alternate() {
+            return write!(f, "{:#x}", self.0);
         }
-        write!(f, " }}")
+
+        fmt_terms(f, self.degree(), |p| self.0 & (1 << p) != 0)
     }
 }
 
-impl ops::Mul for G2Poly {
-    type Output = G2PolyProd;
+impl fmt::LowerHex for G2Poly {
+    fn fmt<'a>(&self, f: &mut fmt::Formatter<'a>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
 
-    fn mul(self, rhs: G2Poly) -> G2PolyProd {
-        let mut result = 0;
+impl fmt::UpperHex for G2Poly {
+    fn fmt<'a>(&self, f: &mut fmt::Formatter<'a>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
 
-        let smaller = cmp::min(self.0, rhs.0);
-        let mut bigger = cmp::max(self.0, rhs.0) as u128;
+impl fmt::Binary for G2Poly {
+    fn fmt<'a>(&self, f: &mut fmt::Formatter<'a>) -> fmt::Result {
+        fmt::Binary::fmt(&self.0, f)
+    }
+}
 
-        let end = 64 - smaller.leading_zeros();
-        let mut bitpos = 1;
-        for _ in 0..end {
-            if bitpos & smaller > 0 {
-                result ^= bigger;
+/// Write the terms of a polynomial of the given degree, like `x^4 + x + 1`
+///
+/// The zero polynomial (with degree `None`) is written as `0`.
+fn fmt_terms<F: Fn(u64) -> bool>(f: &mut fmt::Formatter, degree: Option<u64>, is_set: F) -> fmt::Result {
+    let start = match degree {
+        None => return write!(f, "0"),
+        Some(d) => d,
+    };
+
+    let mut append = false;
+    for p in (0..=start).rev() {
+        if is_set(p) {
+            if append {
+                write!(f, " + ")?;
             }
-            bigger <<= 1;
-            bitpos <<= 1;
+
+            if p == 0 {
+                write!(f, "1")?;
+            } else if p == 1 {
+                write!(f, "x")?;
+            } else {
+                write!(f, "x^{}", p)?;
+            }
+            append = true;
         }
+    }
+    Ok(())
+}
 
-        G2PolyProd(result)
+impl ops::Mul for G2Poly {
+    type Output = G2PolyProd;
+
+    fn mul(self, rhs: G2Poly) -> G2PolyProd {
+        self.const_mul(rhs)
     }
 }
 
@@ -183,7 +272,7 @@ impl ops::Rem for G2Poly {
     type Output = G2Poly;
 
     fn rem(self, rhs: G2Poly) -> G2Poly {
-        G2PolyProd(self.0 as u128) % rhs
+        self.const_rem(rhs)
     }
 }
 
@@ -204,21 +293,7 @@ impl ops::Div for G2Poly {
     /// assert_eq!(G2Poly(0b101_01010), a / b);
     /// ```
     fn div(self, rhs: G2Poly) -> G2Poly {
-        let divisor = rhs.0;
-        let divisor_degree_p1 = 64 - divisor.leading_zeros();
-        assert!(divisor_degree_p1 > 0);
-
-        let mut quotient = 0;
-        let mut rem = self.0;
-        let mut rem_degree_p1 = 64 - self.0.leading_zeros();
-
-        while divisor_degree_p1 <= rem_degree_p1 {
-            let shift_len = rem_degree_p1 - divisor_degree_p1;
-            quotient |= 1 << shift_len;
-            rem ^= divisor << shift_len;
-            rem_degree_p1 = 64 - rem.leading_zeros();
-        }
-        G2Poly(quotient)
+        self.const_div(rhs)
     }
 }
 
@@ -257,22 +332,7 @@ impl ops::Rem<G2Poly> for G2PolyProd {
     /// assert_eq!(G2Poly(0b0101_0001_0101_0100), a * a % m);
     /// ```
     fn rem(self, rhs: G2Poly) -> G2Poly {
-        let module = rhs.0 as u128;
-        let mod_degree_p1 = 128 - module.leading_zeros();
-        assert!(mod_degree_p1 > 0);
-
-        let mut rem = self.0;
-        let mut rem_degree_p1 = 128 - rem.leading_zeros();
-
-
-        while mod_degree_p1 <= rem_degree_p1 {
-            let shift_len = rem_degree_p1 - mod_degree_p1;
-            rem ^= module << shift_len;
-            rem_degree_p1 = 128 - rem.leading_zeros();
-        }
-
-        // NB: rem_degree < mod_degree implies that rem < mod so it fits in u64
-        G2Poly(rem as u64)
+        self.const_rem(rhs)
     }
 }
 
@@ -289,17 +349,27 @@ impl ops::Rem<G2Poly> for G2PolyProd {
 /// assert_eq!(gcd(a, b), G2Poly(0b11));
 /// assert_eq!(gcd(b, a), G2Poly(0b11));
 /// ```
-pub fn gcd(a: G2Poly, b: G2Poly) -> G2Poly {
-    let (mut a, mut b) = (cmp::max(a, b), cmp::min(a, b));
+pub const fn gcd(a: G2Poly, b: G2Poly) -> G2Poly {
+    let (mut a, mut b) = if a.0 >= b.0 { (a, b) } else { (b, a) };
 
-    while b != G2Poly(0) {
-        let new_b = a % b;
+    while b.0 != 0 {
+        let new_b = a.const_rem(b);
         a = b;
         b = new_b;
     }
     a
 }
 
+/// The greatest common divisor of two integers, for the orders in [`G2Poly::order_mod`]
+const fn int_gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
 /// Calculate the greatest common divisor with Bézout coefficients
 ///
 /// Uses the extended euclidean algorithm to calculate the greatest common divisor of two
@@ -316,7 +386,7 @@ pub fn gcd(a: G2Poly, b: G2Poly) -> G2Poly {
 /// assert_eq!(gcd, G2Poly(0b11));
 /// assert_eq!((a * x).to_poly() + (b * y).to_poly(), G2Poly(0b11));
 /// ```
-pub fn extended_gcd(a: G2Poly, b: G2Poly) -> (G2Poly, G2Poly, G2Poly) {
+pub const fn extended_gcd(a: G2Poly, b: G2Poly) -> (G2Poly, G2Poly, G2Poly) {
     let mut s = G2Poly(0);
     let mut old_s = G2Poly(1);
     let mut t = G2Poly(1);
@@ -324,17 +394,17 @@ pub fn extended_gcd(a: G2Poly, b: G2Poly) -> (G2Poly, G2Poly, G2Poly) {
     let mut r = b;
     let mut old_r = a;
 
-    while r != G2Poly(0) {
-        let quotient = old_r / r;
-        let tmp = old_r - (quotient * r).to_poly();
+    while r.0 != 0 {
+        let quotient = old_r.const_div(r);
+        let tmp = G2Poly(old_r.0 ^ quotient.const_mul(r).to_poly().0);
         old_r = r;
         r = tmp;
 
-        let tmp = old_s - (quotient * s).to_poly();
+        let tmp = G2Poly(old_s.0 ^ quotient.const_mul(s).to_poly().0);
         old_s = s;
         s = tmp;
 
-        let tmp = old_t - (quotient * t).to_poly();
+        let tmp = G2Poly(old_t.0 ^ quotient.const_mul(t).to_poly().0);
         old_t = t;
         t = tmp;
     }
@@ -342,6 +412,96 @@ pub fn extended_gcd(a: G2Poly, b: G2Poly) -> (G2Poly, G2Poly, G2Poly) {
     (old_r, old_s, old_t)
 }
 
+/// Iterate over all generators of the multiplicative group modulo an irreducible polynomial
+///
+/// For a modulus of degree `p` there are `phi(2^p - 1)` generators. Instead of testing every
+/// residue with [`G2Poly::is_generator`], this finds the smallest generator `g` and yields the
+/// powers `g^k` for all `k` coprime to `2^p - 1`, in order of `k`. The elements are reduced, but
+/// not sorted.
+///
+/// # Panics
+/// Panics if `modulus` is not irreducible.
+///
+/// # Example
+/// ```rust
+/// # use g2poly::{G2Poly, generators_mod};
+/// let m = G2Poly(0b1_0011);
+/// assert_eq!(generators_mod(m).count(), 8);
+/// assert!(generators_mod(m).all(|g| g.is_generator(m)));
+/// ```
+pub fn generators_mod(modulus: G2Poly) -> impl Iterator<Item = G2Poly> {
+    assert!(modulus.is_irreducible(), "Modulus is not irreducible");
+
+    let n = modulus.degree().expect("Irreducible polynomial has a degree");
+    let order = (1 << n) - 1;
+    let primes = factors::mersenne_prime_factors(n);
+    let generator = (1..)
+        .map(G2Poly)
+        .find(|g| g.order_mod(modulus) == Some(order))
+        .expect("The multiplicative group is cyclic");
+
+    let mut power = G2Poly::UNIT;
+    (1..=order).filter_map(move |k| {
+        power = power.mul_mod(generator, modulus);
+        if primes.iter().all(|&r| k % r != 0) {
+            Some(power)
+        } else {
+            None
+        }
+    })
+}
+
+/// Fast reduction modulo a fixed polynomial
+///
+/// Stores `x^k mod m` for all `deg(m) <= k < 2 * deg(m)`. Reducing a product of two reduced
+/// values is then a matter of adding up the table entries for the high bits.
+struct Reducer {
+    degree: u64,
+    low_mask: u64,
+    high_powers: [G2Poly; 64],
+}
+
+impl Reducer {
+    const fn new(modulus: G2Poly) -> Self {
+        let degree = match modulus.degree() {
+            Some(d) => d,
+            None => panic!("Modulus is not 0"),
+        };
+        let low_mask = (1 << degree) - 1;
+        let mut high_powers = [G2Poly::ZERO; 64];
+
+        // x^degree mod m is m without its leading term
+        let mut power = G2Poly(modulus.0 & low_mask);
+        let mut k = 0;
+        while k < degree as usize {
+            high_powers[k] = power;
+            power = G2Poly(power.0 << 1);
+            if power.0 & (1 << degree) != 0 {
+                power = G2Poly((power.0 ^ modulus.0) & low_mask);
+            }
+            k += 1;
+        }
+
+        Reducer {
+            degree,
+            low_mask,
+            high_powers,
+        }
+    }
+
+    /// Calculate `a^2 mod m` for an already reduced `a`
+    const fn square(&self, a: G2Poly) -> G2Poly {
+        let square = a.const_mul(a).0;
+        let mut result = square as u64 & self.low_mask;
+        let mut high = (square >> self.degree) as u64;
+        while high != 0 {
+            let k = high.trailing_zeros();
+            result ^= self.high_powers[k as usize].0;
+            high &= high - 1;
+        }
+        G2Poly(result)
+    }
+}
 
 impl G2Poly {
     /// The constant `1` polynomial.
@@ -358,10 +518,118 @@ impl G2Poly {
     /// Useful for quickly generating `x^n` values.
     pub const X: Self = G2Poly(2);
 
+    /// Multiply two polynomials, usable in const contexts
+    ///
+    /// This is the same as [`*`](#impl-Mul-for-G2Poly).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use g2poly::{G2Poly, G2PolyProd};
+    /// const SQUARE: G2PolyProd = G2Poly(0b11).const_mul(G2Poly(0b11));
+    /// assert_eq!(SQUARE, G2PolyProd(0b101));
+    /// ```
+    pub const fn const_mul(self, rhs: G2Poly) -> G2PolyProd {
+        let mut result = 0;
+
+        let (smaller, bigger) = if self.0 < rhs.0 { (self.0, rhs.0) } else { (rhs.0, self.0) };
+        let mut bigger = bigger as u128;
+
+        let end = 64 - smaller.leading_zeros();
+        let mut bitpos = 1;
+        let mut i = 0;
+        while i < end {
+            if bitpos & smaller > 0 {
+                result ^= bigger;
+            }
+            bigger <<= 1;
+            bitpos <<= 1;
+            i += 1;
+        }
+
+        G2PolyProd(result)
+    }
+
+    /// Calculate the polynomial remainder, usable in const contexts
+    ///
+    /// This is the same as [`%`](#impl-Rem-for-G2Poly).
+    ///
+    /// # Panics
+    /// Panics if `rhs` is the zero polynomial.
+    pub const fn const_rem(self, rhs: G2Poly) -> G2Poly {
+        G2PolyProd(self.0 as u128).const_rem(rhs)
+    }
+
+    /// Calculate the polynomial quotient, usable in const contexts
+    ///
+    /// This is the same as [`/`](#impl-Div-for-G2Poly).
+    ///
+    /// # Panics
+    /// Panics if `rhs` is the zero polynomial.
+    pub const fn const_div(self, rhs: G2Poly) -> G2Poly {
+        let divisor = rhs.0;
+        let divisor_degree_p1 = 64 - divisor.leading_zeros();
+        assert!(divisor_degree_p1 > 0);
+
+        let mut quotient = 0;
+        let mut rem = self.0;
+        let mut rem_degree_p1 = 64 - self.0.leading_zeros();
+
+        while divisor_degree_p1 <= rem_degree_p1 {
+            let shift_len = rem_degree_p1 - divisor_degree_p1;
+            quotient |= 1 << shift_len;
+            rem ^= divisor << shift_len;
+            rem_degree_p1 = 64 - rem.leading_zeros();
+        }
+        G2Poly(quotient)
+    }
+
+    /// Calculate `self * rhs mod m`
+    ///
+    /// The reduction is interleaved with the shift-and-xor multiplication, so no intermediate
+    /// value exceeds 64 bits, even if both operands are of degree 63.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is the zero polynomial.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use g2poly::G2Poly;
+    /// let m = G2Poly(0b1_0001_1011);
+    /// assert_eq!(G2Poly(0x53).mul_mod(G2Poly(0xca), m), G2Poly(1));
+    /// ```
+    pub const fn mul_mod(self, rhs: G2Poly, modulus: G2Poly) -> G2Poly {
+        let top = match modulus.degree() {
+            Some(d) => 1 << d,
+            None => panic!("Modulus is not 0"),
+        };
+        let a = self.const_rem(modulus).0;
+        let b = rhs.const_rem(modulus).0;
+
+        let mut result: u64 = 0;
+        let mut bit = 64 - b.leading_zeros();
+        while bit > 0 {
+            bit -= 1;
+            // result has a smaller degree than the modulus, so this can't overflow
+            result <<= 1;
+            if result & top != 0 {
+                result ^= modulus.0;
+            }
+            if (b >> bit) & 1 != 0 {
+                result ^= a;
+            }
+        }
+        G2Poly(result)
+    }
+
     /// Quickly calculate p^n mod m
     ///
     /// Uses [square-and-multiply](https://en.wikipedia.org/wiki/Exponentiation_by_squaring) to
-    /// quickly exponentiate a polynomial.
+    /// quickly exponentiate a polynomial. Every step goes through [`mul_mod`](Self::mul_mod), so
+    /// the intermediate values never overflow, even for a modulus of degree 63. The result is
+    /// always reduced: `p^0` is `1 mod m` and a zero base gives zero for every positive power.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is the zero polynomial.
     ///
     /// # Example
     /// ```rust
@@ -369,32 +637,33 @@ impl G2Poly {
     /// let p = G2Poly(0b1011);
     /// assert_eq!(p.pow_mod(127, G2Poly(0b1101)), G2Poly(0b110));
     /// ```
-    pub fn pow_mod(self, power: u64, modulus: G2Poly) -> G2Poly {
-        let mut init = G2Poly::UNIT;
-
-        // max starts with only the highest bit set
-        let mut max = 0x80_00_00_00_00_00_00_00;
-        assert_eq!(max << 1, 0);
-
-        while max > 0 {
-            let square = init * init;
-            init = square % modulus;
-            if power & max > 0 {
-                let mult = init * self;
-                init = mult % modulus;
+    pub const fn pow_mod(self, power: u64, modulus: G2Poly) -> G2Poly {
+        let mut init = G2Poly::UNIT.const_rem(modulus);
+
+        // Skip the leading zero bits of the exponent, squaring one is a no-op
+        let mut bit = 64 - power.leading_zeros();
+        while bit > 0 {
+            bit -= 1;
+            init = init.mul_mod(init, modulus);
+            if (power >> bit) & 1 != 0 {
+                init = init.mul_mod(self, modulus);
             }
-            max >>= 1;
         }
         init
     }
 
-
     /// Determine if the given polynomial is irreducible.
     ///
     /// Irreducible polynomials not be expressed as the product of other irreducible polynomials
     /// (except `1` and itself). This uses [Rabin's tests](https://en.wikipedia.org/wiki/Factorization_of_polynomials_over_finite_fields#Rabin's_test_of_irreducibility)
     /// to check if the given polynomial is irreducible.
     ///
+    /// For a polynomial `f` of degree `n`, the values `x^(2^i) mod f` are computed for
+    /// `i = 1..=n` by repeated squaring. The polynomial is irreducible if `x^(2^n) == x mod f` and
+    /// `gcd(x^(2^(n/r)) - x, f) == 1` for every prime divisor `r` of `n`. The reduction after each
+    /// squaring uses a table of `x^k mod f` for `n <= k < 2n`, so the whole test needs just `n`
+    /// squarings plus one gcd per prime divisor. Constant polynomials are not irreducible.
+    ///
     /// # Example
     /// ```rust
     /// # use g2poly::G2Poly;
@@ -403,44 +672,47 @@ impl G2Poly {
     /// let p = G2Poly(0b111);
     /// assert!(p.is_irreducible());
     /// ```
-    pub fn is_irreducible(self) -> bool {
-        const PRIMES_LE_63: [u64; 11] = [
-            2,
-            3,
-            5,
-            7,
-            11,
-            13,
-            17,
-            19,
-            23,
-            29,
-            31,
-        ];
-
-        // Zero is not irreducible
-        if self == G2Poly::ZERO {
-            return false;
+    pub const fn is_irreducible(self) -> bool {
+        let n = match self.degree() {
+            Some(0) | None => return false,
+            Some(n) => n,
+        };
+
+        // A number <= 63 has at most 3 distinct prime divisors, as 2 * 3 * 5 * 7 > 63
+        let mut prime_divisors = [0; 3];
+        let mut rest = n;
+        let mut count = 0;
+        let mut r = 2;
+        while r <= n {
+            if rest % r == 0 {
+                prime_divisors[count] = r;
+                count += 1;
+                while rest % r == 0 {
+                    rest /= r;
+                }
+            }
+            r += 1;
         }
 
-        // Degrees
-        let n = self.degree().expect("Already checked for zero");
-        let distinct_prime_coprod = PRIMES_LE_63.iter()
-            .filter(|&&p| p <= n)
-            .filter(|&&p| n % p == 0)
-            .map(|&p| n / p);
-        for p in distinct_prime_coprod {
-            let q_to_the_p = 1 << p;
-            let h = G2Poly::X.pow_mod(q_to_the_p, self) - (G2Poly(2) % self);
-
-            if gcd(self, h) != G2Poly(1) {
-                return false;
+        let reducer = Reducer::new(self);
+        let x = G2Poly::X.const_rem(self);
+        let mut x_pow = x;
+        let mut i = 1;
+        while i <= n {
+            // x_pow == x^(2^i) mod self
+            x_pow = reducer.square(x_pow);
+
+            let mut j = 0;
+            while j < count {
+                if n / prime_divisors[j] == i && gcd(self, G2Poly(x_pow.0 ^ x.0)).0 != 1 {
+                    return false;
+                }
+                j += 1;
             }
+            i += 1;
         }
 
-        let g = G2Poly::X.pow_mod(1 << n, self) - G2Poly(2) % self;
-
-        g == G2Poly::ZERO
+        x_pow.0 == x.0
     }
 
     /// Get the degree of the polynomial
@@ -455,8 +727,100 @@ impl G2Poly {
     /// let s = G2Poly(0b101);
     /// assert_eq!(s.degree(), Some(2));
     /// ```
-    pub fn degree(self) -> Option<u64> {
-        63_u32.checked_sub(self.0.leading_zeros()).map(|n| n as u64)
+    pub const fn degree(self) -> Option<u64> {
+        match 63_u32.checked_sub(self.0.leading_zeros()) {
+            Some(n) => Some(n as u64),
+            None => None,
+        }
+    }
+
+    /// Calculate the multiplicative order of `self` modulo `modulus`
+    ///
+    /// This is the smallest `k > 0` with `self^k == 1 mod modulus`. Returns `None` if `self`
+    /// shares a factor with the modulus, including when it is zero mod `modulus`, as it is not
+    /// invertible then.
+    ///
+    /// The modulus does not need to be irreducible. A distinct-degree factorization finds the
+    /// degrees `d` of its irreducible factors, so that the odd part of the order divides the lcm
+    /// of all `2^d - 1`. It is then reduced using the prime factors of `2^d - 1` from the built-in
+    /// table. The power of two part, which only occurs for repeated factors, is found by
+    /// squaring. No powers are tried one by one, so this is fast for any degree.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is the zero polynomial.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use g2poly::G2Poly;
+    /// assert_eq!(G2Poly::X.order_mod(G2Poly(0b1_0001_1101)), Some(255));
+    /// // The AES polynomial is irreducible, but not primitive
+    /// assert_eq!(G2Poly::X.order_mod(G2Poly(0b1_0001_1011)), Some(51));
+    /// // x + 1 divides x^2 + 1
+    /// assert_eq!(G2Poly(0b11).order_mod(G2Poly(0b101)), None);
+    /// ```
+    pub const fn order_mod(self, modulus: G2Poly) -> Option<u64> {
+        if modulus.0 == 0 {
+            panic!("Modulus is not 0");
+        }
+        let one = G2Poly::UNIT.const_rem(modulus);
+        let a = self.const_rem(modulus);
+        if gcd(a, modulus).0 != 1 {
+            return None;
+        }
+
+        // Distinct-degree factorization, bit d of `degrees` is set if there is a factor of degree d
+        let x = G2Poly::X.const_rem(modulus);
+        let mut x_pow = x;
+        let mut rest = modulus;
+        let mut degrees: u64 = 0;
+        let mut odd: u64 = 1;
+        let mut d = 1;
+        while rest.0 > 1 {
+            // x_pow == x^(2^d) mod modulus, and x^(2^d) - x is the product of all irreducible
+            // polynomials with degree dividing d
+            x_pow = x_pow.mul_mod(x_pow, modulus);
+            let mut g = gcd(rest, G2Poly(x_pow.0 ^ x.0));
+            if g.0 != 1 {
+                degrees |= 1 << d;
+                // The lcm of 2^d - 1 with d summing up to at most 63 fits
+                let mersenne = (1 << d) - 1;
+                odd = odd / int_gcd(odd, mersenne) * mersenne;
+                // Remove repeated factors too, so they are not found again for multiples of d
+                while g.0 != 1 {
+                    rest = rest.const_div(g);
+                    g = gcd(rest, g);
+                }
+            }
+            d += 1;
+        }
+
+        // a^odd has the power of two part of the order as its order
+        let mut b = a.pow_mod(odd, modulus);
+        let mut two_power = 0;
+        while b.0 != one.0 {
+            b = b.mul_mod(b, modulus);
+            two_power += 1;
+        }
+
+        // The order of c is the odd part of the order, which divides `odd`
+        let c = a.pow_mod(1 << two_power, modulus);
+        let mut order = odd;
+        let mut d = 1;
+        while d < 64 {
+            if degrees & (1 << d) != 0 {
+                let factors = factors::mersenne_prime_factors(d);
+                let mut i = 0;
+                while i < factors.len() {
+                    let r = factors[i];
+                    while order % r == 0 && c.pow_mod(order / r, modulus).0 == one.0 {
+                        order /= r;
+                    }
+                    i += 1;
+                }
+            }
+            d += 1;
+        }
+        Some(order << two_power)
     }
 
     /// Checks if a polynomial generates the multiplicative group mod m.
@@ -466,7 +830,8 @@ impl G2Poly {
     /// polynomial of degree p. The multiplicative group is cyclic, so there is an element `a` so
     /// that all elements != can be expressed as a^n for some n < 2^p - 1.
     ///
-    /// This checks if the given polynomial is such a generator element mod m.
+    /// This checks if the given polynomial is such a generator element mod m, i.e. if its
+    /// [order](Self::order_mod) is `2^p - 1`.
     ///
     /// # Example
     /// ```rust
@@ -475,28 +840,105 @@ impl G2Poly {
     /// // The element `x` generates the whole group.
     /// assert!(G2Poly::X.is_generator(m));
     /// ```
-    pub fn is_generator(self, module: G2Poly) -> bool {
+    pub const fn is_generator(self, module: G2Poly) -> bool {
         assert!(module.is_irreducible());
 
-        let order = module.degree().expect("Module is not 0");
-        let p_minus_1 = (1 << order) - 1;
-
-        let mut g_pow = self;
-        for _ in 1..p_minus_1 {
-            if g_pow == G2Poly::UNIT {
-                return false;
-            }
+        let n = match module.degree() {
+            Some(d) => d,
+            None => panic!("Module is not 0"),
+        };
+        matches!(self.order_mod(module), Some(order) if order == (1 << n) - 1)
+    }
 
-            g_pow = (g_pow * self) % module;
+    /// Checks if the polynomial is primitive.
+    ///
+    /// A primitive polynomial `m` of degree `p` is an irreducible polynomial for which `x`
+    /// generates the multiplicative group of GF(2^p) = GF(2)\[x\] / m, i.e. `x` has order `2^p - 1`.
+    /// This is the same as `G2Poly::X.is_generator(m)`, except that it returns `false` instead
+    /// of panicking for reducible `m`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use g2poly::G2Poly;
+    /// assert!(G2Poly(0b1_0001_1101).is_primitive());
+    /// // The AES polynomial is irreducible, but x only has order 51
+    /// assert!(G2Poly(0b1_0001_1011).is_irreducible());
+    /// assert!(!G2Poly(0b1_0001_1011).is_primitive());
+    /// ```
+    pub const fn is_primitive(self) -> bool {
+        if !self.is_irreducible() {
+            return false;
         }
 
-        true
+        G2Poly::X.is_generator(self)
+    }
+
+    /// Sample a random irreducible polynomial of the given degree
+    ///
+    /// Polynomials are drawn uniformly from all monic polynomials of degree `degree` until an
+    /// irreducible one is found, so the result is uniformly distributed among the irreducible
+    /// polynomials of that degree. Roughly one in `degree` polynomials is irreducible, so the
+    /// expected number of tries is about `degree`.
+    ///
+    /// # Panics
+    /// Panics if `degree` is 0 or greater than 63.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use g2poly::G2Poly;
+    /// let p = G2Poly::random_irreducible(16, &mut rand::thread_rng());
+    /// assert_eq!(p.degree(), Some(16));
+    /// assert!(p.is_irreducible());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_irreducible<R: rand::Rng + ?Sized>(degree: u64, rng: &mut R) -> G2Poly {
+        G2Poly::random_monic_until(degree, rng, G2Poly::is_irreducible)
+    }
+
+    /// Sample a random primitive polynomial of the given degree
+    ///
+    /// A primitive polynomial is an irreducible polynomial for which `x` generates the
+    /// multiplicative group. Like [`random_irreducible`](G2Poly::random_irreducible), this draws
+    /// uniformly from all monic polynomials until a primitive one is found. The fraction of
+    /// primitive polynomials is `φ(2^degree - 1) / (degree * 2^degree)`, which means the expected
+    /// number of tries is a small multiple of `degree`.
+    ///
+    /// # Panics
+    /// Panics if `degree` is 0 or greater than 63.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use g2poly::G2Poly;
+    /// let p = G2Poly::random_primitive(8, &mut rand::thread_rng());
+    /// assert_eq!(p.degree(), Some(8));
+    /// assert!(p.is_primitive());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_primitive<R: rand::Rng + ?Sized>(degree: u64, rng: &mut R) -> G2Poly {
+        G2Poly::random_monic_until(degree, rng, G2Poly::is_primitive)
+    }
+
+    #[cfg(feature = "rand")]
+    fn random_monic_until<R, F>(degree: u64, rng: &mut R, predicate: F) -> G2Poly
+        where R: rand::Rng + ?Sized,
+              F: Fn(G2Poly) -> bool,
+    {
+        assert!(degree > 0 && degree < 64, "Degree must be in 1..=63, got {}", degree);
+
+        let lower_mask = (1 << degree) - 1;
+        loop {
+            let candidate = G2Poly((rng.gen::<u64>() & lower_mask) | (1 << degree));
+            if predicate(candidate) {
+                return candidate;
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::cmp;
 
     #[test]
     fn test_debug_format() {
@@ -518,10 +960,62 @@ mod tests {
         let c = 1;
         let d = 49;
 
-        assert_eq!(format!("{}", G2Poly(a)), "G2Poly { 0 }");
-        assert_eq!(format!("{}", G2Poly(b)), "G2Poly { x^2 + x }");
-        assert_eq!(format!("{}", G2Poly(c)), "G2Poly { 1 }");
-        assert_eq!(format!("{}", G2Poly(d)), "G2Poly { x^5 + x^4 + 1 }");
+        assert_eq!(format!("{}", G2Poly(a)), "0");
+        assert_eq!(format!("{}", G2Poly(b)), "x^2 + x");
+        assert_eq!(format!("{}", G2Poly(c)), "1");
+        assert_eq!(format!("{}", G2Poly(d)), "x^5 + x^4 + 1");
+        assert_eq!(format!("{}", G2Poly(0x11d)), "x^8 + x^4 + x^3 + x^2 + 1");
+        assert_eq!(format!("{}", G2Poly(0b10)), "x");
+        assert_eq!(format!("{}", G2Poly(1 << 63)), "x^63");
+    }
+
+    #[test]
+    fn test_hex_format() {
+        assert_eq!(format!("{:#}", G2Poly(0x11d)), "0x11d");
+        assert_eq!(format!("{:#}", G2Poly(0)), "0x0");
+        assert_eq!(format!("{:#x}", G2Poly(0x11d)), "0x11d");
+        assert_eq!(format!("{:X}", G2Poly(0x11d)), "11D");
+        assert_eq!(format!("{:#b}", G2Poly(0b1011)), "0b1011");
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut polys = vec![
+            G2Poly(0b1_0001_1101),
+            G2Poly(0b111),
+            G2Poly(0b1_0001_1011),
+            G2Poly::UNIT,
+            G2Poly(0b100),
+            G2Poly::ZERO,
+            G2Poly(0b1_0001_1011),
+            G2Poly::X,
+            G2Poly(1 << 63),
+        ];
+        polys.sort();
+
+        for w in polys.windows(2) {
+            assert!(w[0].degree() <= w[1].degree());
+            if w[0].degree() == w[1].degree() {
+                assert!(w[0].0 <= w[1].0);
+            }
+        }
+        assert_eq!(polys.first(), Some(&G2Poly::ZERO));
+        assert_eq!(polys.last(), Some(&G2Poly(1 << 63)));
+
+        // Consistency with Eq
+        for a in polys.iter() {
+            for b in polys.iter() {
+                assert_eq!(a.cmp(b) == cmp::Ordering::Equal, a == b);
+                assert_eq!(a.cmp(b), (a.degree(), a.0).cmp(&(b.degree(), b.0)));
+            }
+        }
+
+        let set: std::collections::BTreeSet<G2Poly> = polys.iter().copied().collect();
+        assert_eq!(set.len(), polys.len() - 1);
+        assert_eq!(
+            set.range(G2Poly(0b100)..G2Poly(0b1000)).copied().collect::<Vec<_>>(),
+            vec![G2Poly(0b100), G2Poly(0b111)],
+        );
     }
 
     #[test]
@@ -566,6 +1060,149 @@ mod tests {
         assert!(!y.is_irreducible());
     }
 
+    /// The original implementation of `is_irreducible`, based on `pow_mod`
+    fn is_irreducible_reference(p: G2Poly) -> bool {
+        const PRIMES_LE_63: [u64; 11] = [
+            2,
+            3,
+            5,
+            7,
+            11,
+            13,
+            17,
+            19,
+            23,
+            29,
+            31,
+        ];
+
+        // Zero is not irreducible
+        if p == G2Poly::ZERO {
+            return false;
+        }
+
+        // Degrees
+        let n = p.degree().expect("Already checked for zero");
+        let distinct_prime_coprod = PRIMES_LE_63.iter()
+            .filter(|&&r| r <= n)
+            .filter(|&&r| n % r == 0)
+            .map(|&r| n / r);
+        for r in distinct_prime_coprod {
+            let q_to_the_r = 1 << r;
+            let h = G2Poly::X.pow_mod(q_to_the_r, p) - (G2Poly(2) % p);
+
+            if gcd(p, h) != G2Poly(1) {
+                return false;
+            }
+        }
+
+        let g = G2Poly::X.pow_mod(1 << n, p) - G2Poly(2) % p;
+
+        g == G2Poly::ZERO
+    }
+
+    #[test]
+    fn test_irreducible_agrees_with_reference() {
+        // The reference implementation considers 1 to be irreducible, so start at degree 1
+        for i in 2..(1 << 13) {
+            let p = G2Poly(i);
+            assert_eq!(p.is_irreducible(), is_irreducible_reference(p), "{}", p);
+        }
+        assert!(!G2Poly::UNIT.is_irreducible());
+        assert!(!G2Poly::ZERO.is_irreducible());
+    }
+
+    #[test]
+    fn test_large_irreducible() {
+        let irreducible = [
+            // x^63 + x + 1
+            G2Poly(0x8000_0000_0000_0003),
+            // x^61 + x^5 + x^2 + x + 1
+            G2Poly(0x2000_0000_0000_0027),
+            // CRC-32
+            G2Poly(0x1_04c1_1db7),
+            crate::conway(32).unwrap(),
+            crate::conway(40).unwrap(),
+        ];
+        for &p in irreducible.iter() {
+            assert!(p.is_irreducible(), "{}", p);
+        }
+
+        let reducible = [
+            // CRC-32C (Castagnoli) is divisible by x + 1
+            G2Poly(0x1_1edc_6f41),
+            // x^62 + x^2 + 1 == (x^31 + x + 1)^2
+            G2Poly(0x4000_0000_0000_0005),
+            // Product of irreducible polynomials of degree 31 and 32
+            (crate::conway(31).unwrap() * crate::conway(32).unwrap()).to_poly(),
+            // Product of x^3 + x + 1 and an irreducible polynomial of degree 60
+            (G2Poly(0b1011) * G2Poly(0x1000_0000_0000_0003)).to_poly(),
+        ];
+        for &p in reducible.iter() {
+            assert!(!p.is_irreducible(), "{}", p);
+        }
+    }
+
+    #[test]
+    fn test_primitive_check() {
+        // x^4 + x + 1 is primitive, x^4 + x^3 + x^2 + x + 1 is irreducible, but x has order 5
+        assert!(G2Poly(0b1_0011).is_primitive());
+        assert!(G2Poly(0b1_1111).is_irreducible());
+        assert!(!G2Poly(0b1_1111).is_primitive());
+
+        // The Reed-Solomon / QR-code polynomial is primitive, the AES polynomial is not
+        assert!(G2Poly(0b1_0001_1101).is_primitive());
+        assert!(!G2Poly(0b1_0001_1011).is_primitive());
+
+        assert!(G2Poly(0b11).is_primitive());
+        assert!(!G2Poly::X.is_primitive());
+        assert!(!G2Poly::UNIT.is_primitive());
+        assert!(!G2Poly::ZERO.is_primitive());
+        assert!(G2Poly(0x8000_0000_0000_0003).is_primitive());
+    }
+
+    #[test]
+    fn test_primitive_agrees_with_brute_force() {
+        for i in 2..(1 << 11) {
+            let m = G2Poly(i);
+            if !m.is_irreducible() {
+                assert!(!m.is_primitive());
+                continue;
+            }
+
+            let n = m.degree().unwrap();
+            let x = G2Poly::X % m;
+            let mut order = 1;
+            let mut x_pow = x;
+            while x_pow != G2Poly::UNIT && x_pow != G2Poly::ZERO {
+                x_pow = x_pow * x % m;
+                order += 1;
+            }
+            let brute_force = x_pow == G2Poly::UNIT && order == (1 << n) - 1;
+            assert_eq!(m.is_primitive(), brute_force, "{}", m);
+        }
+    }
+
+    #[test]
+    fn test_generator_agrees_with_brute_force() {
+        for &m in [0b11, 0b111, 0b1011, 0b1_1001, 0b10_0101, 0x11b, 0x11d].iter() {
+            let m = G2Poly(m);
+            let n = m.degree().unwrap();
+            for g in 0..(1 << n) {
+                let g = G2Poly(g);
+                let mut order = 1;
+                let mut g_pow = g;
+                while g_pow != G2Poly::UNIT && g_pow != G2Poly::ZERO {
+                    g_pow = g_pow * g % m;
+                    order += 1;
+                }
+                let brute_force = g_pow == G2Poly::UNIT && order == (1 << n) - 1;
+                assert_eq!(g.is_generator(m), brute_force, "{} mod {}", g, m);
+            }
+        }
+        assert!(!G2Poly(0x11d).is_generator(G2Poly(0x11d)));
+    }
+
     #[test]
     fn test_generator_check() {
         // Rijndael's field
@@ -601,4 +1238,231 @@ mod tests {
         assert_eq!(G2Poly(1), gcd);
         assert_eq!(G2Poly(1), a * x % m);
     }
+
+    const _: () = assert!(G2Poly(0x11d).is_irreducible());
+    const _: () = assert!(G2Poly(0x11d).is_primitive());
+    const _: () = assert!(!G2Poly(0x1_1edc_6f41).is_irreducible());
+    const _: () = assert!(G2Poly(0x8000_0000_0000_0003).is_irreducible());
+    const _: () = assert!(G2Poly(0b10).is_generator(G2Poly(0b1_0011)));
+    const _: () = assert!(G2Poly(0b11).const_mul(G2Poly(0b11)).0 == 0b101);
+    const _: () = assert!(G2Poly(0b1_0000_0011).const_rem(G2Poly(0b1_0011)).0 == 0b110);
+    const _: () = assert!(G2Poly(0b1_0001).const_div(G2Poly(0b11)).0 == 0b1111);
+    const _: () = assert!(gcd(G2Poly(0b1111), G2Poly(0b11)).0 == 0b11);
+
+    #[test]
+    fn test_mul_mod() {
+        use rand::{Rng, SeedableRng};
+
+        let reference = |a: G2Poly, b: G2Poly, m: G2Poly| (a % m * (b % m)) % m;
+
+        for &m in [0b111, 0b1011, 0b1_0011, 0x11b, 0x11d, 0x1002d].iter() {
+            let m = G2Poly(m);
+            for a in 0..0x100 {
+                for b in 0..0x40 {
+                    let (a, b) = (G2Poly(a), G2Poly(b));
+                    assert_eq!(a.mul_mod(b, m), reference(a, b, m));
+                }
+            }
+        }
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x113);
+        let moduli = [0x8000_0000_0000_0003, 0x2000_0000_0000_0027, 0x1000_0000_0000_001b];
+        for &m in moduli.iter() {
+            let m = G2Poly(m);
+            for _ in 0..1000 {
+                let a = G2Poly(rng.gen::<u64>() | 1 << 62);
+                let b = G2Poly(rng.gen::<u64>() | 1 << 60);
+                assert_eq!(a.mul_mod(b, m), reference(a, b, m));
+            }
+        }
+        assert_eq!(G2Poly(5).mul_mod(G2Poly(7), G2Poly::UNIT), G2Poly::ZERO);
+    }
+
+    #[test]
+    fn test_pow_mod() {
+        use rand::{Rng, SeedableRng};
+
+        // x^(2^n) = x mod f for irreducible f of degree n
+        for &m in [0b111, 0b1011, 0b1_0011, 0x11b, 0x11d, 0x1002d, 0x8000_0000_0000_0003].iter() {
+            let m = G2Poly(m);
+            let n = m.degree().unwrap();
+            assert!(m.is_irreducible());
+            assert_eq!(G2Poly::X.pow_mod(1 << n, m), G2Poly::X);
+            let mut x = G2Poly::X;
+            for _ in 0..n {
+                x = x.mul_mod(x, m);
+            }
+            assert_eq!(x, G2Poly::X % m);
+        }
+
+        for &m in [0b1011, 0x11b, 0x1002d].iter() {
+            let m = G2Poly(m);
+            for a in 0..0x40 {
+                let a = G2Poly(a);
+                let mut expected = G2Poly::UNIT;
+                for k in 0..40 {
+                    assert_eq!(a.pow_mod(k, m), expected);
+                    expected = expected.mul_mod(a, m);
+                }
+            }
+        }
+
+        // Operands of degree 62 and 63 would overflow a plain product before the reduction
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x195);
+        let m = G2Poly(0x8000_0000_0000_0003);
+        for _ in 0..100 {
+            let a = G2Poly(rng.gen::<u64>() | 1 << 62);
+            let k = rng.gen_range(1..1000);
+            let (k1, k2) = (k / 3, k - k / 3);
+            assert_eq!(a.pow_mod(k, m), a.pow_mod(k1, m).mul_mod(a.pow_mod(k2, m), m));
+            assert_eq!(a.pow_mod(1, m), a % m);
+        }
+
+        assert_eq!(G2Poly(0x53).pow_mod(0, G2Poly(0x11b)), G2Poly::UNIT);
+        assert_eq!(G2Poly::ZERO.pow_mod(0, G2Poly(0x11b)), G2Poly::UNIT);
+        assert_eq!(G2Poly::ZERO.pow_mod(5, G2Poly(0x11b)), G2Poly::ZERO);
+        assert_eq!(G2Poly(0x53).pow_mod(0, G2Poly::UNIT), G2Poly::ZERO);
+        assert_eq!(G2Poly(0x53).pow_mod(u64::MAX, G2Poly(0x11b)), G2Poly(0x53).pow_mod(u64::MAX % 255, G2Poly(0x11b)));
+    }
+
+    #[test]
+    fn test_order_mod() {
+        assert_eq!(G2Poly::X.order_mod(G2Poly(0x11d)), Some(255));
+        assert_eq!(G2Poly::X.order_mod(G2Poly(0x11b)), Some(51));
+        assert_eq!(G2Poly(3).order_mod(G2Poly(0x11b)), Some(255));
+        assert_eq!(G2Poly::X.order_mod(G2Poly(0x8000_0000_0000_0003)), Some((1 << 63) - 1));
+        assert_eq!(G2Poly::UNIT.order_mod(G2Poly(0x11b)), Some(1));
+        // Only the residue matters
+        assert_eq!(G2Poly(0x11b ^ 2).order_mod(G2Poly(0x11b)), Some(51));
+
+        assert_eq!(G2Poly::ZERO.order_mod(G2Poly(0x11b)), None);
+        assert_eq!(G2Poly(0x11b).order_mod(G2Poly(0x11b)), None);
+        // x^8 + 1 = (x + 1)^8
+        assert_eq!(G2Poly(0b11).order_mod(G2Poly(0x101)), None);
+        assert_eq!(G2Poly::X.order_mod(G2Poly(0x101)), Some(8));
+        assert_eq!(G2Poly::X.order_mod(G2Poly(0b110)), None);
+        // Everything is one in the zero ring
+        assert_eq!(G2Poly(5).order_mod(G2Poly::UNIT), Some(1));
+    }
+
+    #[test]
+    fn test_order_mod_brute_force() {
+        for m in 2..0x200 {
+            let m = G2Poly(m);
+            for a in 0..1 << m.degree().unwrap() {
+                let a = G2Poly(a);
+                let expected = if gcd(a, m).0 != 1 {
+                    None
+                } else {
+                    let mut k = 1;
+                    let mut power = a;
+                    while power != G2Poly::UNIT {
+                        power = power.mul_mod(a, m);
+                        k += 1;
+                    }
+                    Some(k)
+                };
+                assert_eq!(a.order_mod(m), expected, "{:?} mod {:?}", a, m);
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_generator_matches_order() {
+        for &m in [0b111, 0b1011, 0x11b, 0x11d, 0x1002d].iter() {
+            let m = G2Poly(m);
+            let group_order = (1 << m.degree().unwrap()) - 1;
+            for a in 1..m.0.min(0x400) {
+                let a = G2Poly(a);
+                assert_eq!(a.is_generator(m), a.order_mod(m) == Some(group_order));
+            }
+        }
+    }
+
+    #[test]
+    fn test_generators_mod() {
+        // phi(15) = 8 and phi(255) = 128
+        for &(m, count) in [(0b1_0011, 8), (0b1_1001, 8), (0b1_1111, 8), (0x11b, 128), (0x11d, 128)].iter() {
+            let m = G2Poly(m);
+            let generators: Vec<G2Poly> = generators_mod(m).collect();
+            assert_eq!(generators.len(), count);
+            assert!(generators.iter().all(|g| g.is_generator(m)));
+
+            let mut sorted = generators.clone();
+            sorted.sort_by_key(|g| g.0);
+            sorted.dedup();
+            assert_eq!(sorted.len(), count);
+            let expected: Vec<G2Poly> = (1..1 << m.degree().unwrap()).map(G2Poly).filter(|g| g.is_generator(m)).collect();
+            assert_eq!(sorted, expected);
+        }
+
+        // The group of GF(2) is trivial, and 2^31 - 1 is prime, so everything but 0 and 1 generates
+        assert_eq!(generators_mod(G2Poly(0b11)).collect::<Vec<_>>(), [G2Poly::UNIT]);
+        assert_eq!(generators_mod(G2Poly(0x8000_0009)).take(1000).count(), 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Modulus is not irreducible")]
+    fn test_generators_mod_reducible() {
+        let _ = generators_mod(G2Poly(0b101));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pow_mod_zero_modulus() {
+        let _ = G2Poly(5).pow_mod(3, G2Poly::ZERO);
+    }
+
+    #[test]
+    fn test_const_ops_match_operators() {
+        for a in 0..0x100 {
+            for b in 1..0x40 {
+                let (a, b) = (G2Poly(a), G2Poly(b));
+                assert_eq!(a.const_mul(b), a * b);
+                assert_eq!(a.const_div(b), a / b);
+                assert_eq!(a.const_rem(b), a % b);
+                assert_eq!((a * b).const_rem(b), (a * b) % b);
+            }
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_irreducible() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x1234);
+        for degree in 1..64 {
+            let p = G2Poly::random_irreducible(degree, &mut rng);
+            assert_eq!(p.degree(), Some(degree));
+            assert!(p.is_irreducible());
+        }
+
+        let a = G2Poly::random_irreducible(32, &mut rand::rngs::StdRng::seed_from_u64(1));
+        let b = G2Poly::random_irreducible(32, &mut rand::rngs::StdRng::seed_from_u64(2));
+        assert_ne!(a, b);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_primitive() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x1234);
+        for degree in 1..=16 {
+            let p = G2Poly::random_primitive(degree, &mut rng);
+            assert_eq!(p.degree(), Some(degree));
+            assert!(p.is_irreducible());
+            assert!(G2Poly::X.is_generator(p));
+        }
+        for degree in 17..64 {
+            let p = G2Poly::random_primitive(degree, &mut rng);
+            assert_eq!(p.degree(), Some(degree));
+            assert!(p.is_primitive());
+        }
+
+        let a = G2Poly::random_primitive(32, &mut rand::rngs::StdRng::seed_from_u64(1));
+        let b = G2Poly::random_primitive(32, &mut rand::rngs::StdRng::seed_from_u64(2));
+        assert_ne!(a, b);
+    }
 }
@@ -0,0 +1,266 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::{
+    fmt,
+    str,
+};
+
+use crate::G2Poly;
+
+/// Error returned when parsing a [`G2Poly`] fails
+///
+/// Records the byte offset in the input at which parsing failed.
+///
+/// ```rust
+/// # use g2poly::G2Poly;
+/// let err = "x^8 + x^".parse::<G2Poly>().unwrap_err();
+/// assert_eq!(err.position(), 8);
+/// assert_eq!(err.to_string(), "invalid polynomial at byte 8: expected an exponent");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParsePolyError {
+    position: usize,
+    reason: &'static str,
+}
+
+impl ParsePolyError {
+    /// Byte offset in the input where the error was detected
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParsePolyError {
+    fn fmt<'a>(&self, f: &mut fmt::Formatter<'a>) -> fmt::Result {
+        write!(f, "invalid polynomial at byte {}: {}", self.position, self.reason)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePolyError {}
+
+impl str::FromStr for G2Poly {
+    type Err = ParsePolyError;
+
+    /// Parse a polynomial in `x^k` notation
+    ///
+    /// This accepts the output of the [`Display`](fmt::Display) implementation, both the
+    /// `x^k` notation and the alternate hex form. Apart from that, the parser is lenient:
+    /// * whitespace is allowed between all tokens,
+    /// * terms may have a coefficient of `0` or `1`, optionally followed by `*`,
+    /// * terms may appear in any order, and repeated terms cancel out as `x^k + x^k == 0`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use g2poly::G2Poly;
+    /// assert_eq!("x^8 + x^4 + x^3 + x^2 + 1".parse(), Ok(G2Poly(0x11d)));
+    /// assert_eq!("1*x^2+x +x+ 1".parse(), Ok(G2Poly(0b101)));
+    /// assert_eq!("0x11d".parse(), Ok(G2Poly(0x11d)));
+    /// assert!("x^64".parse::<G2Poly>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser { input: s.as_bytes(), pos: 0 }.parse()
+    }
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn parse(mut self) -> Result<G2Poly, ParsePolyError> {
+        self.skip_whitespace();
+        if let Some(radix) = self.radix_prefix() {
+            return self.parse_integer(radix);
+        }
+
+        let mut poly = 0;
+        loop {
+            self.skip_whitespace();
+            poly ^= self.parse_term()?;
+            self.skip_whitespace();
+            match self.peek() {
+                None => return Ok(G2Poly(poly)),
+                Some(b'+') => self.pos += 1,
+                Some(_) => return Err(self.error("expected '+'")),
+            }
+        }
+    }
+
+    /// Parse a single term like `1`, `x`, `x^3` or `1 * x^3`
+    fn parse_term(&mut self) -> Result<u64, ParsePolyError> {
+        match self.peek() {
+            Some(b'0'..=b'9') => {
+                let start = self.pos;
+                let coefficient = match self.parse_number() {
+                    Some(c @ 0..=1) => c,
+                    _ => return Err(ParsePolyError { position: start, reason: "coefficient must be 0 or 1" }),
+                };
+                self.skip_whitespace();
+                if self.peek() == Some(b'*') {
+                    self.pos += 1;
+                    self.skip_whitespace();
+                    let monomial = self.parse_monomial()?;
+                    Ok(if coefficient == 1 { monomial } else { 0 })
+                } else {
+                    Ok(coefficient)
+                }
+            }
+            Some(b'x') => self.parse_monomial(),
+            _ => Err(self.error("expected a term")),
+        }
+    }
+
+    /// Parse `x` or `x^k`
+    fn parse_monomial(&mut self) -> Result<u64, ParsePolyError> {
+        if self.peek() != Some(b'x') {
+            return Err(self.error("expected 'x'"));
+        }
+        self.pos += 1;
+
+        let checkpoint = self.pos;
+        self.skip_whitespace();
+        if self.peek() != Some(b'^') {
+            self.pos = checkpoint;
+            return Ok(1 << 1);
+        }
+        self.pos += 1;
+        self.skip_whitespace();
+
+        let start = self.pos;
+        if !matches!(self.peek(), Some(b'0'..=b'9')) {
+            return Err(self.error("expected an exponent"));
+        }
+        match self.parse_number() {
+            Some(e) if e < 64 => Ok(1 << e),
+            _ => Err(ParsePolyError { position: start, reason: "exponent must be less than 64" }),
+        }
+    }
+
+    /// Parse a decimal number, returning `None` on overflow
+    fn parse_number(&mut self) -> Option<u64> {
+        let mut value: Option<u64> = Some(0);
+        while let Some(c @ b'0'..=b'9') = self.peek() {
+            value = value
+                .and_then(|v| v.checked_mul(10))
+                .and_then(|v| v.checked_add(u64::from(c - b'0')));
+            self.pos += 1;
+        }
+        value
+    }
+
+    /// Consume a `0x` or `0b` prefix
+    fn radix_prefix(&mut self) -> Option<u32> {
+        let radix = match self.input.get(self.pos..self.pos + 2) {
+            Some(b"0x") => 16,
+            Some(b"0b") => 2,
+            _ => return None,
+        };
+        self.pos += 2;
+        Some(radix)
+    }
+
+    /// Parse the bit representation as an integer, allowing `_` as separator
+    fn parse_integer(mut self, radix: u32) -> Result<G2Poly, ParsePolyError> {
+        let mut value: u64 = 0;
+        let mut digits = 0;
+        while let Some(c) = self.peek() {
+            if c.is_ascii_whitespace() {
+                break;
+            }
+            self.pos += 1;
+            if c == b'_' {
+                continue;
+            }
+            let digit = (c as char).to_digit(radix).ok_or_else(|| ParsePolyError {
+                position: self.pos - 1,
+                reason: "invalid digit",
+            })?;
+            value = value
+                .checked_mul(u64::from(radix))
+                .and_then(|v| v.checked_add(u64::from(digit)))
+                .ok_or_else(|| ParsePolyError { position: self.pos - 1, reason: "degree must be less than 64" })?;
+            digits += 1;
+        }
+
+        if digits == 0 {
+            return Err(self.error("expected digits"));
+        }
+        self.skip_whitespace();
+        if self.peek().is_some() {
+            return Err(self.error("unexpected trailing input"));
+        }
+        Ok(G2Poly(value))
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn error(&self, reason: &'static str) -> ParsePolyError {
+        ParsePolyError { position: self.pos, reason }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_valid() {
+        assert_eq!("0".parse(), Ok(G2Poly(0)));
+        assert_eq!("1".parse(), Ok(G2Poly(1)));
+        assert_eq!("x".parse(), Ok(G2Poly(0b10)));
+        assert_eq!("x^8+x^4+x^3+x+1".parse(), Ok(G2Poly(0x11b)));
+        assert_eq!("  x^8 +x ^ 4+ x^3   +x+1 ".parse(), Ok(G2Poly(0x11b)));
+        assert_eq!("x^63".parse(), Ok(G2Poly(1 << 63)));
+        assert_eq!("1 * x^3 + 0*x^2 + 1*x + 1".parse(), Ok(G2Poly(0b1011)));
+        assert_eq!("1 + x + x^2".parse(), Ok(G2Poly(0b111)));
+        assert_eq!("x^0".parse(), Ok(G2Poly(1)));
+        assert_eq!("x^2 + x + x^2".parse(), Ok(G2Poly(0b10)));
+        assert_eq!("x + x".parse(), Ok(G2Poly(0)));
+        assert_eq!("0b1_0011".parse(), Ok(G2Poly(0b1_0011)));
+        assert_eq!(" 0x11D ".parse(), Ok(G2Poly(0x11d)));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let err = |s: &str| s.parse::<G2Poly>().unwrap_err().position();
+
+        assert_eq!(err(""), 0);
+        assert_eq!(err("   "), 3);
+        assert_eq!(err("x^64"), 2);
+        assert_eq!(err("x + x^100"), 6);
+        assert_eq!(err("x^99999999999999999999999"), 2);
+        assert_eq!(err("x^"), 2);
+        assert_eq!(err("x +"), 3);
+        assert_eq!(err("x x"), 2);
+        assert_eq!(err("2x"), 0);
+        assert_eq!(err("x^2 + y"), 6);
+        assert_eq!(err("1 *"), 3);
+        assert_eq!(err("0x"), 2);
+        assert_eq!(err("0x11g"), 4);
+        assert_eq!(err("0x1_0000_0000_0000_0000"), 22);
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let values = [0, 1, 2, 3, 0b101, 0x11d, 0x1002d, 0x8000_0000_0000_0003, u64::MAX];
+        for &v in values.iter() {
+            let p = G2Poly(v);
+            assert_eq!(format!("{}", p).parse(), Ok(p));
+            assert_eq!(format!("{:#}", p).parse(), Ok(p));
+        }
+    }
+}
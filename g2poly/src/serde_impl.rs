@@ -0,0 +1,94 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Serde support for [`G2Poly`]
+//!
+//! Human-readable formats like JSON get the polynomial in `x^k` notation, as produced by the
+//! `Display` implementation. Binary formats get the plain `u64` bit representation.
+
+use core::fmt;
+
+use serde::{
+    de,
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+use crate::G2Poly;
+
+impl Serialize for G2Poly {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_u64(self.0)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for G2Poly {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(PolyVisitor)
+        } else {
+            deserializer.deserialize_u64(PolyVisitor)
+        }
+    }
+}
+
+struct PolyVisitor;
+
+impl<'de> de::Visitor<'de> for PolyVisitor {
+    type Value = G2Poly;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a polynomial over GF(2) of degree < 64")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<G2Poly, E> {
+        Ok(G2Poly(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<G2Poly, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_round_trip() {
+        let p = G2Poly(0b1_0001_1101);
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(json, r#""x^8 + x^4 + x^3 + x^2 + 1""#);
+        assert_eq!(serde_json::from_str::<G2Poly>(&json).unwrap(), p);
+
+        let zero = serde_json::to_string(&G2Poly::ZERO).unwrap();
+        assert_eq!(zero, r#""0""#);
+        assert_eq!(serde_json::from_str::<G2Poly>(&zero).unwrap(), G2Poly::ZERO);
+
+        assert_eq!(serde_json::from_str::<G2Poly>(r#""0x11d""#).unwrap(), p);
+    }
+
+    #[test]
+    fn test_json_invalid() {
+        let err = serde_json::from_str::<G2Poly>(r#""x^64 + 1""#).unwrap_err();
+        assert!(err.to_string().contains("invalid polynomial at byte 2"), "{}", err);
+        assert!(serde_json::from_str::<G2Poly>("285").is_err());
+    }
+
+    #[test]
+    fn test_bincode_round_trip() {
+        let p = G2Poly(0x8000_0000_0000_0003);
+        let bytes = bincode::serialize(&p).unwrap();
+        assert_eq!(bytes, 0x8000_0000_0000_0003_u64.to_le_bytes());
+        assert_eq!(bincode::deserialize::<G2Poly>(&bytes).unwrap(), p);
+    }
+}
@@ -0,0 +1,54 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use g2p_inline_bench::{GF256NoInline, GF256};
+use rand::RngCore;
+
+/// Calculate the parity symbols of a systematic code, the hot loop of a Reed–Solomon encoder
+macro_rules! encode {
+    ($field:ident, $data:expr, $coefficients:expr, $parity:expr) => {
+        for p in $parity.iter_mut() {
+            *p = $field(0);
+        }
+        for (i, &d) in $data.iter().enumerate() {
+            let d = $field(d);
+            for (p, row) in $parity.iter_mut().zip($coefficients.chunks_exact($data.len())) {
+                *p += d * $field(row[i]);
+            }
+        }
+    };
+}
+
+fn cross_crate(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let mut group = c.benchmark_group("cross_crate_encode");
+    for &k in &[32, 223] {
+        let mut data = vec![0; k];
+        let mut coefficients = vec![0; 32 * k];
+        rng.fill_bytes(&mut data);
+        rng.fill_bytes(&mut coefficients);
+
+        group.bench_function(BenchmarkId::new("inline", k), |bencher| {
+            let mut parity = [GF256(0); 32];
+            bencher.iter(|| {
+                encode!(GF256, black_box(&data), &coefficients, parity);
+                parity[0]
+            })
+        });
+        group.bench_function(BenchmarkId::new("inline_never", k), |bencher| {
+            let mut parity = [GF256NoInline(0); 32];
+            bencher.iter(|| {
+                encode!(GF256NoInline, black_box(&data), &coefficients, parity);
+                parity[0]
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, cross_crate);
+criterion_main!(benches);
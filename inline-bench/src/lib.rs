@@ -0,0 +1,19 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Fields for the cross-crate benchmark
+//!
+//! The benchmark is a separate crate, so without LTO it can only inline the operators of these
+//! fields if they are marked `#[inline]`. Run it with
+//!
+//! ```text
+//! cargo bench -p g2p-inline-bench
+//! ```
+
+use g2p::g2p;
+
+g2p!(GF256, 8);
+g2p!(GF256NoInline, 8, inline: never);
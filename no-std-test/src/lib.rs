@@ -0,0 +1,72 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Build check for `no_std` targets
+//!
+//! Instantiates fields and uses all operators in a `#![no_std]` crate, so any path to `std` in
+//! g2p or the generated code fails the build. Build it for a target without `std` with
+//!
+//! ```text
+//! cargo build -p g2p-no-std-test --target thumbv6m-none-eabi
+//! ```
+
+#![no_std]
+
+use core::convert::TryFrom;
+
+use g2p::{g2p, GaloisField};
+
+g2p!(GF16, 4);
+g2p!(GF256, 8);
+g2p!(GF256Slow, 8, tables: none);
+
+#[cfg(target_os = "none")]
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo<'_>) -> ! {
+    loop {}
+}
+
+/// Use every operator and the most common methods of a field
+fn exercise<F: GaloisField + From<u8> + core::fmt::Debug>(a: u8, b: u8) -> F {
+    let (x, y) = (F::from(a), F::from(b));
+    let mut acc = x + y - x * y;
+    acc += x;
+    acc -= y;
+    acc *= x;
+    if y != F::ZERO {
+        acc = x / y * acc;
+        acc /= y;
+    }
+    acc = -acc;
+    acc = acc + [x, y].iter().copied().sum::<F>() + [x, y].iter().copied().product::<F>();
+    acc.pow(5) + acc.pow_signed(if acc == F::ZERO { 1 } else { -3 })
+}
+
+/// Exercise GF(16) with two elements, given as integers
+pub fn exercise_gf16(a: u8, b: u8) -> u8 {
+    let x = exercise::<GF16>(a & GF16::MASK, b & GF16::MASK);
+    let y = x.checked_div(GF16(b & GF16::MASK)).unwrap_or(GF16::ZERO).inverse_itoh_tsujii();
+    (x + y).value()
+}
+
+/// Exercise GF(256) with two elements, given as integers
+pub fn exercise_gf256(a: u8, b: u8) -> u8 {
+    let x = exercise::<GF256>(a, b);
+    let y = GF256::from_le_bytes(x.to_be_bytes()).unwrap_or(GF256::ONE);
+    let z = GF256::try_from(usize::from(b)).map(|z| z.square()).unwrap_or(GF256::ZERO);
+    let w = match GF256(a).try_div(GF256(b)) {
+        Ok(w) => w,
+        Err(_) => GF256::ONE,
+    };
+    (x * y + z + w + GF256::from(a == b)).value()
+}
+
+/// Exercise the table-free backend with two elements, given as integers
+pub fn exercise_gf256_slow(a: u8, b: u8) -> u8 {
+    let x = exercise::<GF256Slow>(a, b);
+    let y = x.powers().nth(usize::from(b)).unwrap_or(GF256Slow::ONE);
+    (x + y + x.conjugates().sum::<GF256Slow>()).value()
+}